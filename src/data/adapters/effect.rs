@@ -2,8 +2,8 @@ use std::fmt::Debug;
 
 use crate::{
     data::traits::{
-        Attachable, Combinable, Disjoinable, Exceptional, Optionable, Recoverable, Response,
-        ResultConvertable, UnerringConvertable,
+        Annotatable, Attachable, Combinable, Disjoinable, Exceptional, Ignorable, Optionable,
+        Recoverable, Response, ResultConvertable, UnerringConvertable,
     },
     stream::traits::Stream,
 };
@@ -154,6 +154,14 @@ impl<Err> Recoverable for Effect<Err> {
     }
 }
 
+impl<Err> Ignorable for Effect<Err> {
+    type Output = Self;
+
+    fn ignore_response(self) -> Self::Output {
+        self
+    }
+}
+
 impl<Val, Err> Attachable<Val> for Effect<Err> {
     type Output = Result<Val, Err>;
 
@@ -175,8 +183,7 @@ impl<Err> ResultConvertable for Effect<Err> {
     type Error = Err;
     type WithVal<Col> = Effect<Err>;
 
-    fn ok(value: Self::Value) -> Self {
-        let _ = value;
+    fn ok((): Self::Value) -> Self {
         Effect::ok()
     }
 
@@ -189,6 +196,17 @@ impl<Err> ResultConvertable for Effect<Err> {
     }
 }
 
+impl<Err> Annotatable for Effect<Err> {
+    type WithErr<Err1> = Effect<Err1>;
+
+    fn annotate_response<Fun, Err1>(self, f: Fun) -> Self::WithErr<Err1>
+    where
+        Fun: FnOnce(Self::Error) -> Err1,
+    {
+        self.map_err(f)
+    }
+}
+
 impl<Err> UnerringConvertable for Effect<Err> {
     type Infallible = ();
 }
@@ -1,8 +1,8 @@
 use super::sure::Sure;
 use crate::{
     data::traits::{
-        Combinable, Data, Disjoinable, Ignorable, Optionable, Recoverable, Response,
-        ResultConvertable, UnerringConvertable,
+        Annotatable, Attachable, Combinable, Data, Disjoinable, Ignorable, Optionable, Recoverable,
+        Response, ResultConvertable, UnerringConvertable,
     },
     stream::traits::Stream,
 };
@@ -111,6 +111,14 @@ impl<Val> Ignorable for Option<Val> {
     }
 }
 
+impl<T, Val> Attachable<Val> for Option<T> {
+    type Output = Option<Val>;
+
+    fn attach_to_response(self, value: Val) -> Self::Output {
+        self.map(|_| value)
+    }
+}
+
 impl<Val> Optionable for Option<Val> {
     type Output = Sure<Option<Val>>;
 
@@ -128,8 +136,7 @@ impl<Val> ResultConvertable for Option<Val> {
         Some(value)
     }
 
-    fn err(error: Self::Error) -> Self {
-        let _ = error;
+    fn err((): Self::Error) -> Self {
         None
     }
 
@@ -138,6 +145,17 @@ impl<Val> ResultConvertable for Option<Val> {
     }
 }
 
+impl<Val> Annotatable for Option<Val> {
+    type WithErr<Err> = Self;
+
+    fn annotate_response<Fun, Err>(self, f: Fun) -> Self::WithErr<Err>
+    where
+        Fun: FnOnce(Self::Error) -> Err,
+    {
+        self
+    }
+}
+
 impl<Val> UnerringConvertable for Option<Val> {
     type Infallible = Sure<Self::Value>;
 }
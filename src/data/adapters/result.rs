@@ -2,8 +2,8 @@ use std::fmt::Debug;
 
 use crate::{
     data::traits::{
-        Combinable, Data, Disjoinable, Exceptional, Ignorable, Optionable, Recoverable, Response,
-        ResultConvertable, UnerringConvertable,
+        Annotatable, Combinable, Data, Disjoinable, Exceptional, Ignorable, Optionable,
+        Recoverable, Response, ResultConvertable, UnerringConvertable,
     },
     stream::traits::Stream,
 };
@@ -169,6 +169,17 @@ impl<Val, Err> ResultConvertable for Result<Val, Err> {
     }
 }
 
+impl<Val, Err> Annotatable for Result<Val, Err> {
+    type WithErr<Err1> = Result<Val, Err1>;
+
+    fn annotate_response<Fun, Err1>(self, f: Fun) -> Self::WithErr<Err1>
+    where
+        Fun: FnOnce(Self::Error) -> Err1,
+    {
+        self.map_err(f)
+    }
+}
+
 impl<Val, Err> UnerringConvertable for Result<Val, Err> {
     type Infallible = Sure<Self::Value>;
 }
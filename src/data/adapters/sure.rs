@@ -1,6 +1,8 @@
 use std::convert::Infallible;
 
-use crate::data::traits::{Combinable, Data, Ignorable, Pure, Response, ResultConvertable};
+use crate::data::traits::{
+    Annotatable, Combinable, Data, Ignorable, Pure, Response, ResultConvertable,
+};
 
 use super::effect::Effect;
 
@@ -136,3 +138,14 @@ impl<Val> ResultConvertable for Sure<Val> {
         Ok(self.value())
     }
 }
+
+impl<Val> Annotatable for Sure<Val> {
+    type WithErr<Err> = Self;
+
+    fn annotate_response<Fun, Err>(self, f: Fun) -> Self::WithErr<Err>
+    where
+        Fun: FnOnce(Self::Error) -> Err,
+    {
+        self
+    }
+}
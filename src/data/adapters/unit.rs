@@ -1,6 +1,8 @@
 use std::convert::Infallible;
 
-use crate::data::traits::{Attachable, Combinable, Pure, Response, ResultConvertable};
+use crate::data::traits::{
+    Annotatable, Attachable, Combinable, Ignorable, Pure, Response, ResultConvertable,
+};
 
 use super::sure::Sure;
 
@@ -26,6 +28,12 @@ where
     }
 }
 
+impl Ignorable for () {
+    type Output = ();
+
+    fn ignore_response(self) -> Self::Output {}
+}
+
 impl<Val> Attachable<Val> for () {
     type Output = Sure<Val>;
 
@@ -39,9 +47,7 @@ impl ResultConvertable for () {
     type Error = Infallible;
     type WithVal<Val> = ();
 
-    fn ok(value: Self::Value) -> Self {
-        let _ = value;
-    }
+    fn ok((): Self::Value) -> Self {}
 
     fn err(error: Self::Error) -> Self {
         let _ = error;
@@ -51,3 +57,13 @@ impl ResultConvertable for () {
         Ok(())
     }
 }
+
+impl Annotatable for () {
+    type WithErr<Err> = ();
+
+    fn annotate_response<Fun, Err>(self, f: Fun) -> Self::WithErr<Err>
+    where
+        Fun: FnOnce(Self::Error) -> Err,
+    {
+    }
+}
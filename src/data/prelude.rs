@@ -1,5 +1,5 @@
 pub(crate) use super::adapters::{effect::Effect, sure::Sure};
 pub(crate) use super::traits::{
-    Attachable, Combinable, Data, Disjoinable, Exceptional, Ignorable, Optionable, Pure,
-    Recoverable, Response, ResultConvertable, UnerringConvertable,
+    Annotatable, Attachable, Combinable, Data, Disjoinable, Exceptional, Ignorable, Optionable,
+    Pure, Recoverable, Response, ResultConvertable, UnerringConvertable,
 };
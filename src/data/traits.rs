@@ -96,6 +96,16 @@ pub trait ResultConvertable: Response {
     fn into_result(self) -> Result<Self::Value, Self::Error>;
 }
 
+// Maps the error of responses that carry one. Responses that cannot fail, or
+// fail without an error, are passed through unchanged.
+pub trait Annotatable: ResultConvertable {
+    type WithErr<Err>: ResultConvertable<Value = Self::Value> + Ignorable;
+
+    fn annotate_response<Fun, Err>(self, f: Fun) -> Self::WithErr<Err>
+    where
+        Fun: FnOnce(Self::Error) -> Err;
+}
+
 pub trait UnerringConvertable: ResultConvertable {
     type Infallible: Pure<Value = Self::Value>;
 }
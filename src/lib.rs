@@ -15,23 +15,56 @@ pub mod parser {
     pub mod adapters {
         pub mod and;
         pub mod repeat;
+        pub mod sequence;
         //pub mod conversion;
         pub mod attach;
+        pub mod capture;
+        pub mod choice;
+        pub mod dispatch;
+        pub mod fold;
+        pub mod framed;
         pub mod ignore;
+        pub mod longest;
+        pub mod lookaround;
         pub mod map;
         pub mod map_err;
         pub mod non_terminal;
         pub mod opt;
         pub mod or;
+        pub mod permutation;
+        pub mod then_with;
         pub mod try_map;
+        pub mod tuple;
     }
     pub(crate) mod prelude;
-    pub mod sources;
+    pub mod sources {
+        pub mod charset;
+        pub mod comment;
+        pub mod ident;
+        pub mod literals;
+        pub mod number;
+        pub(crate) mod properties;
+        pub mod regex;
+        pub mod satisfy;
+        pub mod string;
+        #[cfg(feature = "unicode")]
+        pub(crate) mod tables {
+            pub(crate) mod general_category;
+            pub(crate) mod script;
+        }
+        #[cfg(feature = "unicode")]
+        pub mod unicode;
+    }
     pub mod traits;
     pub(crate) mod util;
 }
 pub mod stream {
     pub mod adapters;
+    pub mod bounded;
+    pub mod capture;
+    pub mod observed;
+    pub mod source_map;
+    pub mod splice;
     pub mod traits;
 }
 pub mod prelude {
@@ -0,0 +1,162 @@
+use std::fmt::{self, Display};
+
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::capture::CaptureState;
+
+pub struct Capture<Par> {
+    name: &'static str,
+    parser: Par,
+}
+
+// Remembers the input `parser` consumed under `name`, for `backref`.
+pub fn capture<Par>(name: &'static str, parser: Par) -> Capture<Par> {
+    Capture { name, parser }
+}
+
+fn record<Str, Out>(input: &mut Str, name: &'static str, start: usize, output: Out) -> Out
+where
+    Str: CaptureState,
+    Out: ResultConvertable,
+{
+    match output.into_result() {
+        Ok(value) => {
+            let end = input.offset();
+            input.capture(name, start, end);
+            Out::ok(value)
+        }
+        Err(error) => Out::err(error),
+    }
+}
+
+impl<Str, Par> Parser<Str> for Capture<Par>
+where
+    Str: CaptureState,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let output = self.parser.parse_stream(input);
+        record(input, self.name, start, output)
+    }
+}
+
+impl<Str, Par> ParserMut<Str> for Capture<Par>
+where
+    Str: CaptureState,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let output = self.parser.parse_stream_mut(input);
+        record(input, self.name, start, output)
+    }
+}
+
+impl<Str, Par> ParserOnce<Str> for Capture<Par>
+where
+    Str: CaptureState,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Output = Par::Output;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let output = self.parser.parse_stream_once(input);
+        record(input, self.name, start, output)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum BackrefErrorKind {
+    // Nothing was captured under the name on the current path.
+    Unbound,
+    Mismatch,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BackrefError<Spn> {
+    pub kind: BackrefErrorKind,
+    pub name: &'static str,
+    pub span: Spn,
+}
+
+impl<Spn> Display for BackrefError<Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            BackrefErrorKind::Unbound => write!(f, "nothing captured as `{}`", self.name),
+            BackrefErrorKind::Mismatch => {
+                write!(f, "expected the input captured as `{}`", self.name)
+            }
+        }
+    }
+}
+
+pub struct Backref {
+    name: &'static str,
+}
+
+// Matches the same items as the latest capture under `name`, and yields
+// their span.
+pub fn backref(name: &'static str) -> Backref {
+    Backref { name }
+}
+
+impl<Str> Parser<Str> for Backref
+where
+    Str: CaptureState,
+    Str::Item: PartialEq,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let offset = input.offset();
+        let error = |input: &Str, kind| BackrefError {
+            kind,
+            name: self.name,
+            span: input.span(offset, offset),
+        };
+
+        let (mut at, end) = match input.captured(self.name) {
+            Some(range) => range,
+            None => return Err(error(input, BackrefErrorKind::Unbound)),
+        };
+        // Items can span several offsets, so both positions move by the
+        // width of the item just matched.
+        let checkpoint = input.checkpoint();
+        while at < end {
+            let found = input.next();
+            if found.is_none() || input.nth(at) != found {
+                input.rewind(checkpoint);
+                return Err(error(input, BackrefErrorKind::Mismatch));
+            }
+            let before = input.offset();
+            input.skip();
+            at += input.offset() - before;
+        }
+        Ok(input.span(offset, input.offset()))
+    }
+}
+
+impl<Str> ParserMut<Str> for Backref
+where
+    Str: CaptureState,
+    Str::Item: PartialEq,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str> ParserOnce<Str> for Backref
+where
+    Str: CaptureState,
+    Str::Item: PartialEq,
+{
+    type Output = Result<Str::Span, BackrefError<Str::Span>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
@@ -0,0 +1,294 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+#[cfg(feature = "either")]
+use super::map::FnMap;
+
+pub struct Choice<Alt> {
+    alternatives: Alt,
+}
+
+// Ordered choice over a tuple, array or `Vec` of parsers. The first success
+// wins; otherwise every error is returned side by side instead of nested.
+pub fn choice<Alt>(alternatives: Alt) -> Choice<Alt> {
+    Choice { alternatives }
+}
+
+// As with `or`, the input is rewound before each alternative after the first,
+// and the last failure is left where it stopped.
+fn alternate<Str, Alt, Fun, Out>(
+    input: &mut Str,
+    alternatives: impl IntoIterator<Item = Alt>,
+    mut parse: Fun,
+) -> Result<Out::Value, Vec<Out::Error>>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Fun: FnMut(Alt, &mut Str) -> Out,
+    Out: ResultConvertable,
+{
+    let checkpoint = input.checkpoint();
+    let mut errors = vec![];
+    for alternative in alternatives {
+        if !errors.is_empty() {
+            input.rewind(checkpoint.clone());
+        }
+        match parse(alternative, input).into_result() {
+            Ok(value) => return Ok(value),
+            Err(error) => errors.push(error),
+        }
+    }
+    Err(errors)
+}
+
+fn into_array<Err, const N: usize>(errors: Vec<Err>) -> [Err; N] {
+    errors
+        .try_into()
+        .unwrap_or_else(|_| unreachable!("every alternative failed once"))
+}
+
+impl<Str, Par, const N: usize> Parser<Str> for Choice<[Par; N]>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        alternate(input, &self.alternatives, |par, input| {
+            par.parse_stream(input)
+        })
+        .map_err(into_array)
+    }
+}
+
+impl<Str, Par, const N: usize> ParserMut<Str> for Choice<[Par; N]>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        alternate(input, &mut self.alternatives, |par, input| {
+            par.parse_stream_mut(input)
+        })
+        .map_err(into_array)
+    }
+}
+
+impl<Str, Par, const N: usize> ParserOnce<Str> for Choice<[Par; N]>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Output = Result<
+        <Par::Output as ResultConvertable>::Value,
+        [<Par::Output as ResultConvertable>::Error; N],
+    >;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        alternate(input, self.alternatives, |par, input| {
+            par.parse_stream_once(input)
+        })
+        .map_err(into_array)
+    }
+}
+
+impl<Str, Par> Parser<Str> for Choice<Vec<Par>>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        alternate(input, &self.alternatives, |par, input| {
+            par.parse_stream(input)
+        })
+    }
+}
+
+impl<Str, Par> ParserMut<Str> for Choice<Vec<Par>>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        alternate(input, &mut self.alternatives, |par, input| {
+            par.parse_stream_mut(input)
+        })
+    }
+}
+
+impl<Str, Par> ParserOnce<Str> for Choice<Vec<Par>>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Output = Result<
+        <Par::Output as ResultConvertable>::Value,
+        Vec<<Par::Output as ResultConvertable>::Error>,
+    >;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        alternate(input, self.alternatives, |par, input| {
+            par.parse_stream_once(input)
+        })
+    }
+}
+
+macro_rules! one_of {
+    ($OneOf:ident [$($P:ident),+]) => {
+        $OneOf<$(val![$P]),+>
+    };
+}
+
+// Heterogeneous alternatives must agree on their value, unless `one_of` has
+// wrapped each of them in its own variant first.
+macro_rules! alternatives {
+    ($OneOf:ident; $($P:ident $p:ident $V:ident),+) => {
+        alternatives!(@impl $OneOf [$($P),+]; $($P $p $V),+);
+    };
+    (@impl $OneOf:ident $all:tt; $P0:ident $p0:ident $V0:ident $(, $P:ident $p:ident $V:ident)+) => {
+        #[cfg(feature = "either")]
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        pub enum $OneOf<$V0, $($V),+> {
+            $V0($V0),
+            $($V($V),)+
+        }
+
+        #[cfg(feature = "either")]
+        impl<$P0, $($P),+> Choice<($P0, $($P),+)> {
+            #[allow(clippy::type_complexity)]
+            pub fn one_of<Str>(self) -> Choice<(
+                FnMap<$P0, val![$P0], one_of!($OneOf $all)>,
+                $(FnMap<$P, val![$P], one_of!($OneOf $all)>,)+
+            )>
+            where
+                Str: Stream,
+                $P0: ParserOnce<Str>,
+                $P0::Output: Data,
+                $($P: ParserOnce<Str>, $P::Output: Data,)+
+            {
+                let ($p0, $($p),+) = self.alternatives;
+                choice((
+                    $p0.map(<one_of!($OneOf $all)>::$V0 as fn(_) -> _),
+                    $($p.map(<one_of!($OneOf $all)>::$V as fn(_) -> _),)+
+                ))
+            }
+        }
+
+        impl<Str, Val, $P0, $($P),+> Parser<Str> for Choice<($P0, $($P),+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone,
+            $P0: Parser<Str>,
+            $P0::Output: ResultConvertable<Value = Val>,
+            $($P: Parser<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            fn parse_stream(&self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = &self.alternatives;
+                let checkpoint = input.checkpoint();
+                Err((
+                    match $p0.parse_stream(input).into_result() {
+                        Ok(value) => return Ok(value),
+                        Err(error) => error,
+                    },
+                    $({
+                        input.rewind(checkpoint.clone());
+                        match $p.parse_stream(input).into_result() {
+                            Ok(value) => return Ok(value),
+                            Err(error) => error,
+                        }
+                    },)+
+                ))
+            }
+        }
+
+        impl<Str, Val, $P0, $($P),+> ParserMut<Str> for Choice<($P0, $($P),+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone,
+            $P0: ParserMut<Str>,
+            $P0::Output: ResultConvertable<Value = Val>,
+            $($P: ParserMut<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = &mut self.alternatives;
+                let checkpoint = input.checkpoint();
+                Err((
+                    match $p0.parse_stream_mut(input).into_result() {
+                        Ok(value) => return Ok(value),
+                        Err(error) => error,
+                    },
+                    $({
+                        input.rewind(checkpoint.clone());
+                        match $p.parse_stream_mut(input).into_result() {
+                            Ok(value) => return Ok(value),
+                            Err(error) => error,
+                        }
+                    },)+
+                ))
+            }
+        }
+
+        impl<Str, Val, $P0, $($P),+> ParserOnce<Str> for Choice<($P0, $($P),+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone,
+            $P0: ParserOnce<Str>,
+            $P0::Output: ResultConvertable<Value = Val>,
+            $($P: ParserOnce<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            type Output = Result<
+                Val,
+                (
+                    <$P0::Output as ResultConvertable>::Error,
+                    $(<$P::Output as ResultConvertable>::Error,)+
+                ),
+            >;
+
+            fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = self.alternatives;
+                let checkpoint = input.checkpoint();
+                Err((
+                    match $p0.parse_stream_once(input).into_result() {
+                        Ok(value) => return Ok(value),
+                        Err(error) => error,
+                    },
+                    $({
+                        input.rewind(checkpoint.clone());
+                        match $p.parse_stream_once(input).into_result() {
+                            Ok(value) => return Ok(value),
+                            Err(error) => error,
+                        }
+                    },)+
+                ))
+            }
+        }
+    };
+}
+
+alternatives!(OneOf2; P0 p0 A, P1 p1 B);
+alternatives!(OneOf3; P0 p0 A, P1 p1 B, P2 p2 C);
+alternatives!(OneOf4; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D);
+alternatives!(OneOf5; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E);
+alternatives!(OneOf6; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F);
+alternatives!(OneOf7; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G);
+alternatives!(OneOf8; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H);
+alternatives!(OneOf9; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I);
+alternatives!(OneOf10; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J);
+alternatives!(OneOf11; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K);
+alternatives!(OneOf12; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K, P11 p11 L);
+alternatives!(OneOf13; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K, P11 p11 L, P12 p12 M);
+alternatives!(OneOf14; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K, P11 p11 L, P12 p12 M, P13 p13 N);
+alternatives!(OneOf15; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K, P11 p11 L, P12 p12 M, P13 p13 N, P14 p14 O);
+alternatives!(OneOf16; P0 p0 A, P1 p1 B, P2 p2 C, P3 p3 D, P4 p4 E, P5 p5 F, P6 p6 G, P7 p7 H, P8 p8 I, P9 p9 J, P10 p10 K, P11 p11 L, P12 p12 M, P13 p13 N, P14 p14 O, P15 p15 P);
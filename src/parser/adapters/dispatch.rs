@@ -0,0 +1,463 @@
+use std::fmt::{self, Debug, Display};
+use std::ops::RangeInclusive;
+
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DispatchError<Item, Err, Spn> {
+    // No key covers the next item, which is `None` at the end of input.
+    Unexpected {
+        expected: Vec<RangeInclusive<Item>>,
+        found: Option<Item>,
+        span: Spn,
+    },
+    Branch(Err),
+}
+
+impl<Item, Err, Spn> Display for DispatchError<Item, Err, Spn>
+where
+    Item: Debug + PartialEq,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let DispatchError::Unexpected {
+            expected, found, ..
+        } = self
+        else {
+            return f.write_str("invalid input for the chosen branch");
+        };
+        f.write_str("expected one of ")?;
+        for (index, key) in expected.iter().enumerate() {
+            if index > 0 {
+                f.write_str(", ")?;
+            }
+            match key.start() == key.end() {
+                true => write!(f, "{:?}", key.start())?,
+                false => write!(f, "{:?}..={:?}", key.start(), key.end())?,
+            }
+        }
+        match found {
+            Some(item) => write!(f, ", found {item:?}"),
+            None => f.write_str(", found the end of input"),
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DispatchKeyError<Item> {
+    // A key whose start is past its end.
+    Empty(RangeInclusive<Item>),
+    Overlapping(RangeInclusive<Item>, RangeInclusive<Item>),
+    // A key or the default points past the last branch.
+    Missing(usize),
+}
+
+impl<Item> Display for DispatchKeyError<Item>
+where
+    Item: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DispatchKeyError::Empty(key) => write!(f, "empty dispatch key {key:?}"),
+            DispatchKeyError::Overlapping(key0, key1) => {
+                write!(f, "overlapping dispatch keys {key0:?} and {key1:?}")
+            }
+            DispatchKeyError::Missing(index) => write!(f, "no dispatch branch {index}"),
+        }
+    }
+}
+
+// The parsers a `Dispatch` picks from, by index. Besides a `Vec` of one
+// parser type, tuples let every branch have its own type, as long as they
+// all respond with the same value and error.
+pub trait BranchCount {
+    fn count(&self) -> usize;
+}
+
+pub trait BranchesOnce<Str>: BranchCount
+where
+    Str: Stream,
+{
+    type Value;
+    type Error;
+
+    fn parse_branch_once(self, index: usize, input: &mut Str) -> Result<Self::Value, Self::Error>;
+}
+
+pub trait BranchesMut<Str>: BranchesOnce<Str>
+where
+    Str: Stream,
+{
+    fn parse_branch_mut(
+        &mut self,
+        index: usize,
+        input: &mut Str,
+    ) -> Result<Self::Value, Self::Error>;
+}
+
+pub trait Branches<Str>: BranchesMut<Str>
+where
+    Str: Stream,
+{
+    fn parse_branch(&self, index: usize, input: &mut Str) -> Result<Self::Value, Self::Error>;
+}
+
+impl<Par> BranchCount for Vec<Par> {
+    fn count(&self) -> usize {
+        self.len()
+    }
+}
+
+impl<Str, Par> BranchesOnce<Str> for Vec<Par>
+where
+    Str: Stream,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Value = <Par::Output as ResultConvertable>::Value;
+    type Error = <Par::Output as ResultConvertable>::Error;
+
+    fn parse_branch_once(
+        mut self,
+        index: usize,
+        input: &mut Str,
+    ) -> Result<Self::Value, Self::Error> {
+        self.swap_remove(index)
+            .parse_stream_once(input)
+            .into_result()
+    }
+}
+
+impl<Str, Par> BranchesMut<Str> for Vec<Par>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_branch_mut(
+        &mut self,
+        index: usize,
+        input: &mut Str,
+    ) -> Result<Self::Value, Self::Error> {
+        self[index].parse_stream_mut(input).into_result()
+    }
+}
+
+impl<Str, Par> Branches<Str> for Vec<Par>
+where
+    Str: Stream,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_branch(&self, index: usize, input: &mut Str) -> Result<Self::Value, Self::Error> {
+        self[index].parse_stream(input).into_result()
+    }
+}
+
+macro_rules! branches {
+    ($count:literal; $($P:ident $p:ident $i:tt),+) => {
+        impl<$($P),+> BranchCount for ($($P,)+) {
+            fn count(&self) -> usize {
+                $count
+            }
+        }
+
+        impl<Str, Val, Err, $($P),+> BranchesOnce<Str> for ($($P,)+)
+        where
+            Str: Stream,
+            $($P: ParserOnce<Str>, $P::Output: ResultConvertable<Value = Val, Error = Err>,)+
+        {
+            type Value = Val;
+            type Error = Err;
+
+            fn parse_branch_once(self, index: usize, input: &mut Str) -> Result<Val, Err> {
+                let ($($p,)+) = self;
+                match index {
+                    $($i => $p.parse_stream_once(input).into_result(),)+
+                    _ => unreachable!("dispatch checks its branch indices"),
+                }
+            }
+        }
+
+        impl<Str, Val, Err, $($P),+> BranchesMut<Str> for ($($P,)+)
+        where
+            Str: Stream,
+            $($P: ParserMut<Str>, $P::Output: ResultConvertable<Value = Val, Error = Err>,)+
+        {
+            fn parse_branch_mut(&mut self, index: usize, input: &mut Str) -> Result<Val, Err> {
+                let ($($p,)+) = self;
+                match index {
+                    $($i => $p.parse_stream_mut(input).into_result(),)+
+                    _ => unreachable!("dispatch checks its branch indices"),
+                }
+            }
+        }
+
+        impl<Str, Val, Err, $($P),+> Branches<Str> for ($($P,)+)
+        where
+            Str: Stream,
+            $($P: Parser<Str>, $P::Output: ResultConvertable<Value = Val, Error = Err>,)+
+        {
+            fn parse_branch(&self, index: usize, input: &mut Str) -> Result<Val, Err> {
+                let ($($p,)+) = self;
+                match index {
+                    $($i => $p.parse_stream(input).into_result(),)+
+                    _ => unreachable!("dispatch checks its branch indices"),
+                }
+            }
+        }
+    };
+}
+
+branches!(1; P0 p0 0);
+branches!(2; P0 p0 0, P1 p1 1);
+branches!(3; P0 p0 0, P1 p1 1, P2 p2 2);
+branches!(4; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3);
+branches!(5; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4);
+branches!(6; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5);
+branches!(7; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6);
+branches!(8; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7);
+branches!(9; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8);
+branches!(10; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9);
+branches!(11; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10);
+branches!(12; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10, P11 p11 11);
+branches!(13; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10, P11 p11 11, P12 p12 12);
+branches!(14; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10, P11 p11 11, P12 p12 12, P13 p13 13);
+branches!(15; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10, P11 p11 11, P12 p12 12, P13 p13 13, P14 p14 14);
+branches!(16; P0 p0 0, P1 p1 1, P2 p2 2, P3 p3 3, P4 p4 4, P5 p5 5, P6 p6 6, P7 p7 7, P8 p8 8, P9 p9 9, P10 p10 10, P11 p11 11, P12 p12 12, P13 p13 13, P14 p14 14, P15 p15 15);
+
+type DispatchResult<Item, Bra, Str> = Result<
+    <Bra as BranchesOnce<Str>>::Value,
+    DispatchError<Item, <Bra as BranchesOnce<Str>>::Error, <Str as Stream>::Span>,
+>;
+
+pub struct Dispatch<Item, Bra> {
+    table: Vec<(RangeInclusive<Item>, usize)>,
+    branches: Bra,
+    default: Option<usize>,
+}
+
+// Picks the parser whose key covers the next item, without consuming it. The
+// keys are kept sorted so that choosing a branch is a single binary search.
+pub fn dispatch<Item, Par>(
+    table: impl IntoIterator<Item = (RangeInclusive<Item>, Par)>,
+) -> Result<Dispatch<Item, Vec<Par>>, DispatchKeyError<Item>>
+where
+    Item: Ord + Clone,
+{
+    let (keys, branches): (Vec<_>, Vec<_>) = table
+        .into_iter()
+        .enumerate()
+        .map(|(index, (key, parser))| ((key, index), parser))
+        .unzip();
+    dispatch_branches(keys, None, branches)
+}
+
+// Like `dispatch`, with each key naming its branch by index, so that the
+// branches can be a tuple of different parser types.
+pub fn dispatch_branches<Item, Bra>(
+    keys: impl IntoIterator<Item = (RangeInclusive<Item>, usize)>,
+    default: Option<usize>,
+    branches: Bra,
+) -> Result<Dispatch<Item, Bra>, DispatchKeyError<Item>>
+where
+    Item: Ord + Clone,
+    Bra: BranchCount,
+{
+    let mut table: Vec<_> = keys.into_iter().collect();
+    table.sort_by(|(key0, _), (key1, _)| key0.start().cmp(key1.start()));
+    if let Some((key, _)) = table.iter().find(|(key, _)| key.start() > key.end()) {
+        return Err(DispatchKeyError::Empty(key.clone()));
+    }
+    if let Some(pair) = table
+        .windows(2)
+        .find(|pair| pair[0].0.end() >= pair[1].0.start())
+    {
+        return Err(DispatchKeyError::Overlapping(
+            pair[0].0.clone(),
+            pair[1].0.clone(),
+        ));
+    }
+    let count = branches.count();
+    let mut indices = table.iter().map(|(_, index)| *index).chain(default);
+    if let Some(index) = indices.find(|index| *index >= count) {
+        return Err(DispatchKeyError::Missing(index));
+    }
+    Ok(Dispatch {
+        table,
+        branches,
+        default,
+    })
+}
+
+impl<Item, Par> Dispatch<Item, Vec<Par>> {
+    // Used for every item no key covers, including the end of input.
+    pub fn default(mut self, parser: Par) -> Self {
+        self.branches.push(parser);
+        Dispatch {
+            default: Some(self.branches.len() - 1),
+            ..self
+        }
+    }
+}
+
+impl<Item, Bra> Dispatch<Item, Bra>
+where
+    Item: Ord,
+{
+    fn branch(&self, item: Option<&Item>) -> Option<usize> {
+        let found = match item {
+            Some(item) => {
+                let index = self.table.partition_point(|(key, _)| key.end() < item);
+                self.table
+                    .get(index)
+                    .filter(|(key, _)| key.start() <= item)
+                    .map(|(_, branch)| *branch)
+            }
+            None => None,
+        };
+        found.or(self.default)
+    }
+
+    fn unexpected<Str, Err>(
+        &self,
+        input: &Str,
+        found: Option<Item>,
+    ) -> DispatchError<Item, Err, Str::Span>
+    where
+        Item: Clone,
+        Str: Stream,
+        Str::Offset: Clone,
+    {
+        let offset = input.offset();
+        DispatchError::Unexpected {
+            expected: self.table.iter().map(|(key, _)| key.clone()).collect(),
+            found,
+            span: input.span(offset.clone(), offset),
+        }
+    }
+}
+
+impl<Str, Item, Bra> Parser<Str> for Dispatch<Item, Bra>
+where
+    Str: Stream<Item = Item>,
+    Str::Offset: Clone,
+    Item: Ord + Clone,
+    Bra: Branches<Str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let item = input.nth(input.offset());
+        let Some(index) = self.branch(item.as_ref()) else {
+            return Err(self.unexpected(input, item));
+        };
+        self.branches
+            .parse_branch(index, input)
+            .map_err(DispatchError::Branch)
+    }
+}
+
+impl<Str, Item, Bra> ParserMut<Str> for Dispatch<Item, Bra>
+where
+    Str: Stream<Item = Item>,
+    Str::Offset: Clone,
+    Item: Ord + Clone,
+    Bra: BranchesMut<Str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        let item = input.nth(input.offset());
+        let Some(index) = self.branch(item.as_ref()) else {
+            return Err(self.unexpected(input, item));
+        };
+        self.branches
+            .parse_branch_mut(index, input)
+            .map_err(DispatchError::Branch)
+    }
+}
+
+impl<Str, Item, Bra> ParserOnce<Str> for Dispatch<Item, Bra>
+where
+    Str: Stream<Item = Item>,
+    Str::Offset: Clone,
+    Item: Ord + Clone,
+    Bra: BranchesOnce<Str>,
+{
+    type Output = DispatchResult<Item, Bra, Str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let item = input.nth(input.offset());
+        let Some(index) = self.branch(item.as_ref()) else {
+            return Err(self.unexpected(input, item));
+        };
+        self.branches
+            .parse_branch_once(index, input)
+            .map_err(DispatchError::Branch)
+    }
+}
+
+// `dispatch!['a'..='z' => word, '0'..='9' => number, _ => other]` builds the
+// same table from literal keys, with an optional default branch last. The
+// branches form a tuple, so each can have its own type, and the keys are
+// checked while compiling.
+#[macro_export]
+macro_rules! dispatch {
+    (@lo $lo:literal $($hi:literal)?) => { $lo };
+    (@hi $lo:literal) => { $lo };
+    (@hi $lo:literal $hi:literal) => { $hi };
+    (@unit $parser:expr) => { () };
+    (@count $($parser:expr,)*) => {
+        <[()]>::len(&[$($crate::dispatch!(@unit $parser)),*])
+    };
+    (@check) => {};
+    (@check $(($($key:tt)+))+) => {
+        const {
+            let keys = [$(($crate::dispatch!(@lo $($key)+), $crate::dispatch!(@hi $($key)+))),+];
+            let mut i = 0;
+            while i < keys.len() {
+                assert!(keys[i].0 <= keys[i].1, "empty dispatch key");
+                let mut j = i + 1;
+                while j < keys.len() {
+                    assert!(
+                        keys[i].1 < keys[j].0 || keys[j].1 < keys[i].0,
+                        "overlapping dispatch keys"
+                    );
+                    j += 1;
+                }
+                i += 1;
+            }
+        }
+    };
+    (@build [$((($($key:tt)+) [$($before:expr,)*]))*] [$($parsers:expr,)*] $default:expr) => {{
+        $crate::dispatch!(@check $(($($key)+))*);
+        let keys = [$((
+            $crate::dispatch!(@lo $($key)+)..=$crate::dispatch!(@hi $($key)+),
+            $crate::dispatch!(@count $($before,)*),
+        )),*];
+        match $crate::parser::adapters::dispatch::dispatch_branches(
+            keys,
+            $default,
+            ($($parsers,)*),
+        ) {
+            Ok(dispatch) => dispatch,
+            Err(_) => unreachable!("dispatch keys are checked while compiling"),
+        }
+    }};
+    (@table $keys:tt [$($parsers:expr,)*] $(,)?) => {
+        $crate::dispatch!(@build $keys [$($parsers,)*] None)
+    };
+    (@table $keys:tt [$($parsers:expr,)*] _ => $default:expr $(,)?) => {
+        $crate::dispatch!(
+            @build $keys [$($parsers,)* $default,]
+            Some($crate::dispatch!(@count $($parsers,)*))
+        )
+    };
+    (@table [$($keys:tt)*] [$($parsers:expr,)*] $lo:literal $(..= $hi:literal)? => $parser:expr $(, $($rest:tt)*)?) => {
+        $crate::dispatch!(
+            @table [$($keys)* (($lo $($hi)?) [$($parsers,)*])] [$($parsers,)* $parser,]
+            $($($rest)*)?
+        )
+    };
+    ($($branches:tt)*) => {
+        $crate::dispatch!(@table [] [] $($branches)*)
+    };
+}
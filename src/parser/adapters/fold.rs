@@ -0,0 +1,114 @@
+use super::repeat::{Collector, Repeater};
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+// A collector that threads each item through `function` instead of keeping
+// it. The accumulator is only ever missing while `function` runs.
+pub struct Accumulator<Acc, Fun> {
+    accumulator: Option<Acc>,
+    function: Fun,
+}
+
+impl<Acc, Fun> Accumulator<Acc, Fun> {
+    pub(crate) fn new(init: Acc, function: Fun) -> Self {
+        Accumulator {
+            accumulator: Some(init),
+            function,
+        }
+    }
+
+    fn into_inner(self) -> Acc {
+        self.accumulator
+            .expect("the accumulator is always put back")
+    }
+}
+
+impl<Acc, Fun, Val> Collector<Val> for Accumulator<Acc, Fun>
+where
+    Fun: FnMut(Acc, Val) -> Acc,
+{
+    fn push(&mut self, value: Val) {
+        let accumulator = self
+            .accumulator
+            .take()
+            .expect("the accumulator is always put back");
+        self.accumulator = Some((self.function)(accumulator, value));
+    }
+}
+
+type Folding<Par, Mod, Acc, Fun> = Repeater<Par, Mod, Accumulator<Acc, Fun>>;
+
+pub struct Fold<Par, Mod, Acc, Fun> {
+    repeater: Folding<Par, Mod, Acc, Fun>,
+}
+
+impl<Par, Mod, Acc, Fun> Fold<Par, Mod, Acc, Fun> {
+    pub(crate) fn new(repeater: Folding<Par, Mod, Acc, Fun>) -> Self {
+        Fold { repeater }
+    }
+}
+
+impl<Str, Par, Mod, Acc, Fun, Out> ParserOnce<Str> for Fold<Par, Mod, Acc, Fun>
+where
+    Str: Stream,
+    Folding<Par, Mod, Acc, Fun>: ParserOnce<Str, Output = Out>,
+    Out: Data<Value = Accumulator<Acc, Fun>>,
+{
+    type Output = Out::WithVal<Acc>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.repeater
+            .parse_stream_once(input)
+            .map(Accumulator::into_inner)
+    }
+}
+
+// Folds the repetition starting from the value of `first`, as in
+// left-associative operators.
+pub struct FoldLeft<First, Par, Mod, Fun> {
+    first: First,
+    rest: Repeater<Par, Mod>,
+    function: Fun,
+}
+
+impl<First, Par, Mod, Fun> FoldLeft<First, Par, Mod, Fun> {
+    pub(crate) fn new<Str>(first: First, rest: Repeater<Par, Mod>, function: Fun) -> Self
+    where
+        Str: Stream,
+        First: ParserOnce<Str>,
+        First::Output: Data,
+    {
+        FoldLeft {
+            first,
+            rest,
+            function,
+        }
+    }
+}
+
+impl<Str, First, Par, Mod, Fun, Out> ParserOnce<Str> for FoldLeft<First, Par, Mod, Fun>
+where
+    Str: Stream,
+    First: ParserOnce<Str>,
+    First::Output: Data,
+    Fold<Par, Mod, val![First], Fun>: ParserOnce<Str, Output = Out>,
+    Out: Response,
+    val![First<()>]: Combinable<Out>,
+    <val![First<()>] as Combinable<Out>>::Output: Data<Value = ((), val![First])>,
+{
+    type Output = <<val![First<()>] as Combinable<Out>>::Output as Data>::WithVal<val![First]>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let mut init = None;
+        self.first
+            .parse_stream_once(input)
+            .map(|value| init = Some(value))
+            .combine_response(|| {
+                let init = init.expect("`first` succeeded");
+                let accumulator = Accumulator::new(init, self.function);
+                Fold::new(self.rest.collector(accumulator)).parse_stream_once(input)
+            })
+            .map(|((), accumulator)| accumulator)
+    }
+}
@@ -0,0 +1,369 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Display};
+use std::hash::Hash;
+
+use super::repeat::{Collected, Collector};
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::bounded::Bounded;
+use crate::stream::traits::{Checkpoint, Stream};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FrameError<Hdr, Err, Spn> {
+    // The length, count or tag parser failed.
+    Header(Hdr),
+    // The declared length or count can't fit in the input. The span covers
+    // the header.
+    Truncated(Spn),
+    // The tag has no entry in the table. The span covers the tag.
+    UnknownTag(Spn),
+    Body(Err),
+    // The body parser left part of the frame unread. The span covers the
+    // unread part.
+    Unconsumed(Spn),
+}
+
+impl<Hdr, Err, Spn> Display for FrameError<Hdr, Err, Spn>
+where
+    Hdr: Display,
+    Err: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FrameError::Header(error) => write!(f, "invalid frame header: {error}"),
+            FrameError::Truncated(_) => f.write_str("frame runs past the end of the input"),
+            FrameError::UnknownTag(_) => f.write_str("unknown frame tag"),
+            FrameError::Body(error) => write!(f, "invalid frame body: {error}"),
+            FrameError::Unconsumed(_) => f.write_str("frame body was not fully consumed"),
+        }
+    }
+}
+
+type Framed<Val, Hdr, Err, Str> = Result<Val, FrameError<Hdr, Err, <Str as Stream>::Span>>;
+
+fn fail<Str, Val, Hdr, Err>(
+    input: &mut Str,
+    start: Checkpoint<usize>,
+    error: FrameError<Hdr, Err, Str::Span>,
+) -> Framed<Val, Hdr, Err, Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    input.rewind(start);
+    Err(error)
+}
+
+// The end of a frame of `length` items starting at the current offset, if
+// the input holds that many.
+fn frame_end<Str, Len>(input: &Str, length: Len) -> Option<usize>
+where
+    Str: Stream<Offset = usize>,
+    Len: TryInto<usize>,
+{
+    let start = input.offset();
+    let end = start.checked_add(length.try_into().ok()?)?;
+    let fits = match input.total_len() {
+        Some(total) => end <= total,
+        None => end == start || input.peek_nth(end - 1).is_some(),
+    };
+    fits.then_some(end)
+}
+
+// Runs `parser` on the `end`-bounded view of `input`, which has to be read up
+// to `end` exactly.
+fn parse_frame<Str, Par, Out, Hdr>(
+    input: &mut Str,
+    start: Checkpoint<usize>,
+    end: usize,
+    parser: Par,
+) -> Framed<Out::Value, Hdr, Out::Error, Str>
+where
+    Str: Stream<Offset = usize>,
+    Par: for<'a> ParserOnce<Bounded<'a, Str>, Output = Out>,
+    Out: ResultConvertable,
+{
+    let value = parser
+        .parse_stream_once(&mut Bounded::new(input, end))
+        .into_result();
+    let offset = input.offset();
+    match value {
+        Ok(value) if offset == end => Ok(value),
+        Ok(_) => {
+            let span = input.span(offset, end);
+            fail(input, start, FrameError::Unconsumed(span))
+        }
+        Err(error) => fail(input, start, FrameError::Body(error)),
+    }
+}
+
+// A frame of as many items as `length` reads. The body runs on a `Bounded`
+// view of the input that ends with the frame, whatever the lifetime of that
+// view, so it has to be generic over the stream: sources such as `satisfy`,
+// `take_while` and literals are, and so are the adapters built on them, but a
+// `fn(&mut Str)` only takes `Str` itself. `tlv` bodies are framed the same
+// way; `count_prefixed` bodies run on the input directly.
+pub struct LengthPrefixed<Len, Par> {
+    length: Len,
+    parser: Par,
+}
+
+pub fn length_prefixed<Len, Par>(length: Len, parser: Par) -> LengthPrefixed<Len, Par> {
+    LengthPrefixed { length, parser }
+}
+
+impl<Str, Len, Par, Out> ParserOnce<Str> for LengthPrefixed<Len, Par>
+where
+    Str: Stream<Offset = usize>,
+    Len: ParserOnce<Str>,
+    Len::Output: ResultConvertable,
+    <Len::Output as ResultConvertable>::Value: TryInto<usize>,
+    Par: for<'a> ParserOnce<Bounded<'a, Str>, Output = Out>,
+    Out: ResultConvertable,
+{
+    type Output = Framed<Out::Value, <Len::Output as ResultConvertable>::Error, Out::Error, Str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let start = input.checkpoint();
+        let length = match self.length.parse_stream_once(input).into_result() {
+            Ok(length) => length,
+            Err(error) => return fail(input, start, FrameError::Header(error)),
+        };
+        match frame_end(input, length) {
+            Some(end) => parse_frame(input, start, end, self.parser),
+            None => {
+                let span = input.span(start.offset, input.offset());
+                fail(input, start, FrameError::Truncated(span))
+            }
+        }
+    }
+}
+
+impl<Str, Len, Par, Out> ParserMut<Str> for LengthPrefixed<Len, Par>
+where
+    Str: Stream<Offset = usize>,
+    Len: ParserMut<Str>,
+    Len::Output: ResultConvertable,
+    <Len::Output as ResultConvertable>::Value: TryInto<usize>,
+    Par: for<'a> ParserMut<Bounded<'a, Str>, Output = Out>,
+    Out: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        length_prefixed(&mut self.length, &mut self.parser).parse_stream_once(input)
+    }
+}
+
+pub struct CountPrefixed<Cnt, Par, Col = ()> {
+    count: Cnt,
+    parser: Par,
+    collector: Col,
+}
+
+pub fn count_prefixed<Cnt, Par>(count: Cnt, parser: Par) -> CountPrefixed<Cnt, Par> {
+    CountPrefixed {
+        count,
+        parser,
+        collector: (),
+    }
+}
+
+impl<Cnt, Par, Col> CountPrefixed<Cnt, Par, Col> {
+    pub fn collector<T>(self, collector: T) -> CountPrefixed<Cnt, Par, T> {
+        CountPrefixed {
+            count: self.count,
+            parser: self.parser,
+            collector,
+        }
+    }
+}
+
+impl<Str, Cnt, Par, Col, Out> ParserOnce<Str> for CountPrefixed<Cnt, Par, Col>
+where
+    Str: Stream<Offset = usize>,
+    Cnt: ParserOnce<Str>,
+    Cnt::Output: ResultConvertable,
+    <Cnt::Output as ResultConvertable>::Value: TryInto<usize>,
+    Par: ParserMut<Str, Output = Out>,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
+    Collected<Out, Col>: ResultConvertable<Value = Col, Error = Out::Error>,
+{
+    type Output = Framed<Col, <Cnt::Output as ResultConvertable>::Error, Out::Error, Str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let start = input.checkpoint();
+        let count = match self.count.parse_stream_once(input).into_result() {
+            Ok(count) => count,
+            Err(error) => return fail(input, start, FrameError::Header(error)),
+        };
+        let count = match count.try_into() {
+            Ok(count) => count,
+            Err(_) => {
+                let span = input.span(start.offset, input.offset());
+                return fail(input, start, FrameError::Truncated(span));
+            }
+        };
+
+        let items = self
+            .parser
+            .repeat_exact(count)
+            .collector(self.collector)
+            .parse_stream_once(input);
+        match items.into_result() {
+            Ok(items) => Ok(items),
+            Err(error) => fail(input, start, FrameError::Body(error)),
+        }
+    }
+}
+
+// Parsers keyed by the tag that selects them.
+pub trait Table<Key> {
+    type Parser;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser>;
+}
+
+impl<Key, Par> Table<Key> for [(Key, Par)]
+where
+    Key: PartialEq,
+{
+    type Parser = Par;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        self.iter_mut()
+            .find(|(other, _)| other == key)
+            .map(|(_, parser)| parser)
+    }
+}
+
+impl<Key, Par, const N: usize> Table<Key> for [(Key, Par); N]
+where
+    Key: PartialEq,
+{
+    type Parser = Par;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        self.as_mut_slice().lookup(key)
+    }
+}
+
+impl<Key, Par> Table<Key> for Vec<(Key, Par)>
+where
+    Key: PartialEq,
+{
+    type Parser = Par;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        self.as_mut_slice().lookup(key)
+    }
+}
+
+impl<Key, Par> Table<Key> for HashMap<Key, Par>
+where
+    Key: Eq + Hash,
+{
+    type Parser = Par;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        HashMap::get_mut(self, key)
+    }
+}
+
+impl<Key, Par> Table<Key> for BTreeMap<Key, Par>
+where
+    Key: Ord,
+{
+    type Parser = Par;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        BTreeMap::get_mut(self, key)
+    }
+}
+
+impl<Key, Tab> Table<Key> for &mut Tab
+where
+    Tab: Table<Key> + ?Sized,
+{
+    type Parser = Tab::Parser;
+
+    fn lookup(&mut self, key: &Key) -> Option<&mut Self::Parser> {
+        (**self).lookup(key)
+    }
+}
+
+// Tag, length, value: the tag picks the parser the value is framed for.
+pub struct Tlv<Tag, Len, Tab> {
+    tag: Tag,
+    length: Len,
+    table: Tab,
+}
+
+pub fn tlv<Tag, Len, Tab>(tag: Tag, length: Len, table: Tab) -> Tlv<Tag, Len, Tab> {
+    Tlv { tag, length, table }
+}
+
+impl<Str, Tag, Len, Tab, Out> ParserOnce<Str> for Tlv<Tag, Len, Tab>
+where
+    Str: Stream<Offset = usize>,
+    Tag: ParserOnce<Str>,
+    Tag::Output: ResultConvertable,
+    Len: ParserOnce<Str>,
+    Len::Output: ResultConvertable<Error = <Tag::Output as ResultConvertable>::Error>,
+    <Len::Output as ResultConvertable>::Value: TryInto<usize>,
+    Tab: Table<<Tag::Output as ResultConvertable>::Value>,
+    Tab::Parser: for<'a> ParserMut<Bounded<'a, Str>, Output = Out>,
+    Out: ResultConvertable,
+{
+    type Output = Framed<
+        (<Tag::Output as ResultConvertable>::Value, Out::Value),
+        <Tag::Output as ResultConvertable>::Error,
+        Out::Error,
+        Str,
+    >;
+
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let start = input.checkpoint();
+        let tag = match self.tag.parse_stream_once(input).into_result() {
+            Ok(tag) => tag,
+            Err(error) => return fail(input, start, FrameError::Header(error)),
+        };
+        let tag_end = input.offset();
+        let length = match self.length.parse_stream_once(input).into_result() {
+            Ok(length) => length,
+            Err(error) => return fail(input, start, FrameError::Header(error)),
+        };
+
+        let end = match frame_end(input, length) {
+            Some(end) => end,
+            None => {
+                let span = input.span(start.offset, input.offset());
+                return fail(input, start, FrameError::Truncated(span));
+            }
+        };
+        let parser = match self.table.lookup(&tag) {
+            Some(parser) => parser,
+            None => {
+                let span = input.span(start.offset, tag_end);
+                return fail(input, start, FrameError::UnknownTag(span));
+            }
+        };
+        let value = parse_frame(input, start, end, parser)?;
+        Ok((tag, value))
+    }
+}
+
+impl<Str, Tag, Len, Tab, Out> ParserMut<Str> for Tlv<Tag, Len, Tab>
+where
+    Str: Stream<Offset = usize>,
+    Tag: ParserMut<Str>,
+    Tag::Output: ResultConvertable,
+    Len: ParserMut<Str>,
+    Len::Output: ResultConvertable<Error = <Tag::Output as ResultConvertable>::Error>,
+    <Len::Output as ResultConvertable>::Value: TryInto<usize>,
+    Tab: Table<<Tag::Output as ResultConvertable>::Value>,
+    Tab::Parser: for<'a> ParserMut<Bounded<'a, Str>, Output = Out>,
+    Out: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        tlv(&mut self.tag, &mut self.length, &mut self.table).parse_stream_once(input)
+    }
+}
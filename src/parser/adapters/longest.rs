@@ -0,0 +1,106 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Checkpoint, Stream};
+
+pub struct Longest<Alt> {
+    alternatives: Alt,
+}
+
+// Runs every alternative from the same point and keeps the success that got
+// furthest, the earliest one on ties. Only fails, with every error, when all
+// of them do.
+pub fn longest<Alt>(alternatives: Alt) -> Longest<Alt> {
+    Longest { alternatives }
+}
+
+// The winner's end is kept as a checkpoint, and the input moved back there
+// once every alternative has run.
+macro_rules! longest_body {
+    ($alternatives:expr, $input:ident, $parse:ident; $($p:ident),+) => {{
+        let ($($p,)+) = $alternatives;
+        let checkpoint = $input.checkpoint();
+        let mut best: Option<(Checkpoint<Str::Offset>, Val)> = None;
+        let errors = ($(
+            {
+                $input.rewind(checkpoint.clone());
+                match $p.$parse($input).into_result() {
+                    Ok(value) => {
+                        let end = $input.checkpoint();
+                        if best
+                            .as_ref()
+                            .is_none_or(|(furthest, _)| end.offset > furthest.offset)
+                        {
+                            best = Some((end, value));
+                        }
+                        None
+                    }
+                    Err(error) => Some(error),
+                }
+            },
+        )+);
+
+        let Some((end, value)) = best else {
+            let ($(Some($p),)+) = errors else {
+                unreachable!("without a success every alternative failed")
+            };
+            return Err(($($p,)+));
+        };
+        $input.rewind(end);
+        Ok(value)
+    }};
+}
+
+macro_rules! longest {
+    ($($P:ident $p:ident),+) => {
+        impl<Str, Val, $($P),+> Parser<Str> for Longest<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialOrd,
+            $($P: Parser<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            fn parse_stream(&self, input: &mut Str) -> Self::Output {
+                longest_body!(&self.alternatives, input, parse_stream; $($p),+)
+            }
+        }
+
+        impl<Str, Val, $($P),+> ParserMut<Str> for Longest<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialOrd,
+            $($P: ParserMut<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+                longest_body!(&mut self.alternatives, input, parse_stream_mut; $($p),+)
+            }
+        }
+
+        impl<Str, Val, $($P),+> ParserOnce<Str> for Longest<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialOrd,
+            $($P: ParserOnce<Str>, $P::Output: ResultConvertable<Value = Val>,)+
+        {
+            type Output = Result<Val, ($(<$P::Output as ResultConvertable>::Error,)+)>;
+
+            fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+                longest_body!(self.alternatives, input, parse_stream_once; $($p),+)
+            }
+        }
+    };
+}
+
+longest!(P0 p0, P1 p1);
+longest!(P0 p0, P1 p1, P2 p2);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12, P13 p13);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12, P13 p13, P14 p14);
+longest!(P0 p0, P1 p1, P2 p2, P3 p3, P4 p4, P5 p5, P6 p6, P7 p7, P8 p8, P9 p9, P10 p10, P11 p11, P12 p12, P13 p13, P14 p14, P15 p15);
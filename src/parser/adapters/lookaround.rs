@@ -0,0 +1,197 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Checkpoint, Stream};
+
+pub struct Peek<Par> {
+    parser: Par,
+}
+
+impl<Par> Peek<Par> {
+    pub(crate) fn new<Str>(parser: Par) -> Self
+    where
+        Str: Stream,
+        Par: ParserOnce<Str>,
+    {
+        Self { parser }
+    }
+}
+
+impl<Str, Par> Parser<Str> for Peek<Par>
+where
+    Str: Stream,
+    Par: Parser<Str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream(input);
+        input.rewind(checkpoint);
+        output
+    }
+}
+
+impl<Str, Par> ParserMut<Str> for Peek<Par>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream_mut(input);
+        input.rewind(checkpoint);
+        output
+    }
+}
+
+impl<Str, Par> ParserOnce<Str> for Peek<Par>
+where
+    Str: Stream,
+    Par: ParserOnce<Str>,
+{
+    type Output = Par::Output;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream_once(input);
+        input.rewind(checkpoint);
+        output
+    }
+}
+
+pub struct Not<Par> {
+    parser: Par,
+}
+
+// Succeeds, without consuming anything, exactly when `parser` fails here.
+pub fn not<Par>(parser: Par) -> Not<Par> {
+    Not { parser }
+}
+
+fn negate<Str, Out>(input: &mut Str, checkpoint: Checkpoint<Str::Offset>, output: Out) -> Option<()>
+where
+    Str: Stream,
+    Out: ResultConvertable,
+{
+    input.rewind(checkpoint);
+    output.into_result().is_err().then_some(())
+}
+
+impl<Str, Par> Parser<Str> for Not<Par>
+where
+    Str: Stream,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream(input);
+        negate(input, checkpoint, output)
+    }
+}
+
+impl<Str, Par> ParserMut<Str> for Not<Par>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream_mut(input);
+        negate(input, checkpoint, output)
+    }
+}
+
+impl<Str, Par> ParserOnce<Str> for Not<Par>
+where
+    Str: Stream,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Output = Option<()>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        let output = self.parser.parse_stream_once(input);
+        negate(input, checkpoint, output)
+    }
+}
+
+pub struct PrecededBy<Par> {
+    parser: Par,
+    items: usize,
+}
+
+// Succeeds, without consuming anything, when `parser` matches the items
+// right before the current offset, ending exactly there. Starts are tried up
+// to `items` items back, nearest first.
+pub fn preceded_by<Par>(parser: Par, items: usize) -> PrecededBy<Par> {
+    PrecededBy { parser, items }
+}
+
+fn look_behind<Str, Fun>(input: &mut Str, items: usize, mut matches: Fun) -> Option<()>
+where
+    Str: Stream<Offset = usize>,
+    Fun: FnMut(&mut Str) -> bool,
+{
+    let checkpoint = input.checkpoint();
+    let end = checkpoint.offset;
+    let mut start = end;
+    let mut found = false;
+    for _ in 0..items {
+        input.rewind(checkpoint);
+        if start == 0 {
+            break;
+        }
+        input.go_back(end - start);
+        input.retract();
+        if input.offset() == start {
+            break;
+        }
+        start = input.offset();
+        if matches(input) && input.offset() == end {
+            found = true;
+            break;
+        }
+    }
+    input.rewind(checkpoint);
+    found.then_some(())
+}
+
+impl<Str, Par> Parser<Str> for PrecededBy<Par>
+where
+    Str: Stream<Offset = usize>,
+    Par: Parser<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        look_behind(input, self.items, |input| {
+            self.parser.parse_stream(input).into_result().is_ok()
+        })
+    }
+}
+
+impl<Str, Par> ParserMut<Str> for PrecededBy<Par>
+where
+    Str: Stream<Offset = usize>,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        look_behind(input, self.items, |input| {
+            self.parser.parse_stream_mut(input).into_result().is_ok()
+        })
+    }
+}
+
+impl<Str, Par> ParserOnce<Str> for PrecededBy<Par>
+where
+    Str: Stream<Offset = usize>,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable,
+{
+    type Output = Option<()>;
+
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream_mut(input)
+    }
+}
@@ -26,13 +26,10 @@ where
     type Output = Par::Output;
 
     fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let offset = input.offset();
-        self.parser.parse_stream_once(input).recover_response(
-            |input| {
-                *input.offset_mut() = offset;
-            },
-            input,
-        )
+        let checkpoint = input.checkpoint();
+        self.parser
+            .parse_stream_once(input)
+            .recover_response(|input| input.rewind(checkpoint), input)
     }
 }
 
@@ -43,12 +40,9 @@ where
     Par::Output: Recoverable,
 {
     fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
-        let offset = input.offset();
-        self.parser.parse_stream_mut(input).recover_response(
-            |input| {
-                *input.offset_mut() = offset;
-            },
-            input,
-        )
+        let checkpoint = input.checkpoint();
+        self.parser
+            .parse_stream_mut(input)
+            .recover_response(|input| input.rewind(checkpoint), input)
     }
 }
@@ -32,3 +32,29 @@ where
             .opt_response()
     }
 }
+
+impl<Str, Par> ParserMut<Str> for Opt<Par>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+    Par::Output: Optionable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        ParserOnce::<Str>::non_terminal(&mut self.parser)
+            .parse_stream_once(input)
+            .opt_response()
+    }
+}
+
+impl<Str, Par> Parser<Str> for Opt<Par>
+where
+    Str: Stream,
+    Par: Parser<Str>,
+    Par::Output: Optionable,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        ParserOnce::<Str>::non_terminal(&self.parser)
+            .parse_stream_once(input)
+            .opt_response()
+    }
+}
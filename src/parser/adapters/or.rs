@@ -52,10 +52,10 @@ where
     type Output = <Par0::Output as Disjoinable<Par1::Output>>::Output;
 
     fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let offset = input.offset();
+        let checkpoint = input.checkpoint();
         self.parser0.parse_stream_once(input).disjoin_response(
             |str| self.parser1.parse_stream_once(str),
-            |str| *str.offset_mut() = offset,
+            |str| str.rewind(checkpoint),
             input,
         )
     }
@@ -0,0 +1,158 @@
+use std::fmt::{self, Display};
+
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PermutationErrorKind {
+    // The field matched again after all the others had their turn.
+    Duplicate,
+    // The field can fail and never matched.
+    Missing,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PermutationError<Spn> {
+    pub kind: PermutationErrorKind,
+    // The position of the field in the tuple.
+    pub field: usize,
+    pub span: Spn,
+}
+
+impl<Spn> Display for PermutationError<Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.kind {
+            PermutationErrorKind::Duplicate => write!(f, "field {} given twice", self.field),
+            PermutationErrorKind::Missing => write!(f, "missing field {}", self.field),
+        }
+    }
+}
+
+pub struct Permutation<Fld> {
+    fields: Fld,
+}
+
+// Matches each field at most once, in any order, and returns the values in
+// the order of the tuple. Only a success that consumed input counts as a
+// match; one that did not, such as a missing `opt`, is kept as the field's
+// value should it never match.
+pub fn permutation<Fld>(fields: Fld) -> Permutation<Fld> {
+    Permutation { fields }
+}
+
+macro_rules! permutation_body {
+    ($fields:expr, $input:ident, $parse:ident; $($p:ident $v:ident $i:tt),+) => {{
+        let ($($p,)+) = $fields;
+        $(let mut $v = None;)+
+        'round: loop {
+            $(
+                if !matches!($v, Some((_, true))) {
+                    let checkpoint = $input.checkpoint();
+                    let start = $input.offset();
+                    match $p.$parse($input).into_result() {
+                        Ok(value) if $input.offset() != start => {
+                            $v = Some((value, true));
+                            continue 'round;
+                        }
+                        Ok(value) => {
+                            $input.rewind(checkpoint);
+                            $v.get_or_insert((value, false));
+                        }
+                        Err(_) => $input.rewind(checkpoint),
+                    }
+                }
+            )+
+            break;
+        }
+
+        $(
+            if matches!($v, Some((_, true))) {
+                let checkpoint = $input.checkpoint();
+                let start = $input.offset();
+                let matched = $p.$parse($input).into_result().is_ok();
+                let end = $input.offset();
+                $input.rewind(checkpoint);
+                if matched && end != start {
+                    return Err(PermutationError {
+                        kind: PermutationErrorKind::Duplicate,
+                        field: $i,
+                        span: $input.span(start, end),
+                    });
+                }
+            }
+        )+
+
+        let offset = $input.offset();
+        Ok(($(
+            match $v {
+                Some((value, _)) => value,
+                None => {
+                    return Err(PermutationError {
+                        kind: PermutationErrorKind::Missing,
+                        field: $i,
+                        span: $input.span(offset.clone(), offset),
+                    })
+                }
+            },
+        )+))
+    }};
+}
+
+macro_rules! permutation {
+    ($($P:ident $p:ident $v:ident $i:tt),+) => {
+        impl<Str, $($P),+> Parser<Str> for Permutation<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialEq,
+            $($P: Parser<Str>, $P::Output: ResultConvertable,)+
+        {
+            fn parse_stream(&self, input: &mut Str) -> Self::Output {
+                permutation_body!(&self.fields, input, parse_stream; $($p $v $i),+)
+            }
+        }
+
+        impl<Str, $($P),+> ParserMut<Str> for Permutation<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialEq,
+            $($P: ParserMut<Str>, $P::Output: ResultConvertable,)+
+        {
+            fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+                permutation_body!(&mut self.fields, input, parse_stream_mut; $($p $v $i),+)
+            }
+        }
+
+        impl<Str, $($P),+> ParserOnce<Str> for Permutation<($($P,)+)>
+        where
+            Str: Stream,
+            Str::Offset: Clone + PartialEq,
+            $($P: ParserMut<Str>, $P::Output: ResultConvertable,)+
+        {
+            type Output = Result<
+                ($(<$P::Output as ResultConvertable>::Value,)+),
+                PermutationError<Str::Span>,
+            >;
+
+            fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+                self.parse_stream_mut(input)
+            }
+        }
+    };
+}
+
+permutation!(P0 p0 v0 0, P1 p1 v1 1);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10, P11 p11 v11 11);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10, P11 p11 v11 11, P12 p12 v12 12);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10, P11 p11 v11 11, P12 p12 v12 12, P13 p13 v13 13);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10, P11 p11 v11 11, P12 p12 v12 12, P13 p13 v13 13, P14 p14 v14 14);
+permutation!(P0 p0 v0 0, P1 p1 v1 1, P2 p2 v2 2, P3 p3 v3 3, P4 p4 v4 4, P5 p5 v5 5, P6 p6 v6 6, P7 p7 v7 7, P8 p8 v8 8, P9 p9 v9 9, P10 p10 v10 10, P11 p11 v11 11, P12 p12 v12 12, P13 p13 v13 13, P14 p14 v14 14, P15 p15 v15 15);
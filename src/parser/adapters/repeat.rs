@@ -1,6 +1,12 @@
+use super::fold::{Accumulator, Fold};
 use crate::data::prelude::*;
 use crate::parser::prelude::*;
 use crate::stream::traits::Stream;
+use std::any::type_name;
+use std::collections::{BTreeMap, HashMap};
+use std::convert::Infallible;
+use std::fmt::{self, Display};
+use std::hash::Hash;
 use std::marker::PhantomData;
 
 #[derive(Debug, Default)]
@@ -14,8 +20,17 @@ where
     Multiple(Col),
 }
 
-pub trait Collector<T>: Default {
+// Where a repetition puts its values. `()` drops them.
+pub trait Collector<T> {
     fn push(&mut self, value: T);
+
+    // Whether the collector would refuse another value. A repetition that may
+    // stop early stops before parsing an item it couldn't keep; one that still
+    // needs items pushes them anyway, and the collector records the overflow.
+    #[inline(always)]
+    fn is_full(&self) -> bool {
+        false
+    }
 }
 
 impl<T> Collector<T> for () {
@@ -43,9 +58,30 @@ impl<'a> Collector<&'a str> for String {
     }
 }
 
+// Later values replace earlier ones under the same key.
+impl<K, V> Collector<(K, V)> for HashMap<K, V>
+where
+    K: Eq + Hash,
+{
+    #[inline(always)]
+    fn push(&mut self, (key, value): (K, V)) {
+        self.insert(key, value);
+    }
+}
+
+impl<K, V> Collector<(K, V)> for BTreeMap<K, V>
+where
+    K: Ord,
+{
+    #[inline(always)]
+    fn push(&mut self, (key, value): (K, V)) {
+        self.insert(key, value);
+    }
+}
+
 impl<T, Col> Collector<T> for OneOrMul<T, Col>
 where
-    Col: Collector<T>,
+    Col: Collector<T> + Default,
 {
     #[inline(always)]
     fn push(&mut self, value: T) {
@@ -65,10 +101,129 @@ where
             }
         };
     }
+
+    #[inline(always)]
+    fn is_full(&self) -> bool {
+        match self {
+            OneOrMul::Multiple(col) => col.is_full(),
+            _ => false,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Count(pub usize);
+
+impl<T> Collector<T> for Count {
+    #[inline(always)]
+    fn push(&mut self, _: T) {
+        self.0 += 1;
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Last<T>(pub Option<T>);
+
+impl<T> Default for Last<T> {
+    fn default() -> Self {
+        Last(None)
+    }
+}
+
+impl<T> Collector<T> for Last<T> {
+    #[inline(always)]
+    fn push(&mut self, value: T) {
+        self.0 = Some(value);
+    }
+}
+
+// Up to `N` values, without allocating.
+#[derive(Clone, Debug)]
+pub struct ArrayBuf<T, const N: usize> {
+    items: [Option<T>; N],
+    len: usize,
+}
+
+impl<T, const N: usize> ArrayBuf<T, N> {
+    pub fn new() -> Self {
+        Self {
+            items: std::array::from_fn(|_| None),
+            len: 0,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)?.as_ref()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items[..self.len].iter().flatten()
+    }
+
+    // The values, if there are exactly `N` of them.
+    pub fn into_array(self) -> Result<[T; N], Self> {
+        match self.len == N {
+            true => Ok(self
+                .items
+                .map(|item| item.expect("a full buffer has every item"))),
+            false => Err(self),
+        }
+    }
+}
+
+impl<T, const N: usize> Default for ArrayBuf<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CapacityError {
+    pub capacity: usize,
+}
+
+impl Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "more than {} values", self.capacity)
+    }
+}
+
+// Collect into `Ok(ArrayBuf::new())`: the buffer is full at `N` values, and a
+// value pushed past that turns the collection into an error, after which the
+// rest are dropped.
+impl<T, const N: usize> Collector<T> for Result<ArrayBuf<T, N>, CapacityError> {
+    #[inline(always)]
+    fn push(&mut self, value: T) {
+        match self {
+            Ok(buf) if buf.len < N => {
+                buf.items[buf.len] = Some(value);
+                buf.len += 1;
+            }
+            Ok(_) => *self = Err(CapacityError { capacity: N }),
+            Err(_) => {}
+        }
+    }
+
+    #[inline(always)]
+    fn is_full(&self) -> bool {
+        match self {
+            Ok(buf) => buf.len == N,
+            Err(_) => true,
+        }
+    }
 }
 
 pub mod mode {
     use std::marker::PhantomData;
+    use std::ops::{Bound, RangeBounds};
 
     #[derive(Clone, Copy, Debug)]
     pub struct UntilErr;
@@ -88,18 +243,116 @@ pub mod mode {
     #[derive(Clone, Copy, Debug)]
     pub struct Exact(pub(crate) usize);
 
+    // Inclusive bounds, with no upper bound when `None`.
     #[derive(Clone, Copy, Debug)]
-    pub struct Inter<Mod, Int>(pub(crate) Mod, pub(crate) Int);
+    pub struct Range(pub(crate) usize, pub(crate) Option<usize>);
+
+    impl Range {
+        // An empty range such as `0..0` or `5..2` is clamped to its start, so
+        // it takes exactly that many items.
+        pub(crate) fn new<Rng>(range: Rng) -> Self
+        where
+            Rng: RangeBounds<usize>,
+        {
+            let minimum = match range.start_bound() {
+                Bound::Included(&start) => start,
+                Bound::Excluded(&start) => start.saturating_add(1),
+                Bound::Unbounded => 0,
+            };
+            let maximum = match range.end_bound() {
+                Bound::Included(&end) => Some(end),
+                Bound::Excluded(&end) => Some(end.saturating_sub(1)),
+                Bound::Unbounded => None,
+            };
+            Self(minimum, maximum.map(|maximum| maximum.max(minimum)))
+        }
+    }
+
+    // Stops before the terminator, leaving it in the input.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Until<Term>(pub(crate) Term);
+
+    // Stops after the terminator, returning it alongside the collection.
+    #[derive(Clone, Copy, Debug)]
+    pub struct Terminated<Term>(pub(crate) Term);
+
+    #[derive(Clone, Copy, Debug)]
+    pub struct Inter<Mod, Int>(pub(crate) Mod, pub(crate) Int, pub(crate) Trailing);
+
+    // Whether a separator may, or must, follow the last item.
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+    pub enum Trailing {
+        #[default]
+        Forbidden,
+        Allowed,
+        Required,
+    }
 }
 
 use mode::*;
 
+// The response of a repetition that collects into `Col`.
+pub type Collected<Out, Col> = <Out as ResultConvertable>::WithVal<Col>;
+
+// `SepErr` is the error of the separator between interspersed items, which
+// can't fail anywhere else.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RepeatError<Err, Spn, SepErr = Infallible> {
+    // An item matched at `span` without consuming any input, so repeating it
+    // could never reach the terminator or the end of input. `parser` names the
+    // item's type.
+    Stalled { span: Spn, parser: &'static str },
+    Item(Err),
+    Separator(SepErr),
+}
+
+impl<Err, Spn, SepErr> Display for RepeatError<Err, Spn, SepErr> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RepeatError::Stalled { parser, .. } => {
+                write!(f, "`{parser}` was repeated without consuming any input")
+            }
+            RepeatError::Item(_) => f.write_str("invalid item in the repetition"),
+            RepeatError::Separator(_) => f.write_str("invalid separator in the repetition"),
+        }
+    }
+}
+
+// Whether an item that started at `start` consumed any input. An item that
+// didn't would be repeated at the same offset forever, so unbounded
+// repetitions stop there, or fail with `Stalled` when they must reach a
+// terminator or the end of input.
+fn progressed<Str>(input: &Str, start: &Str::Offset) -> bool
+where
+    Str: Stream,
+    Str::Offset: PartialEq,
+{
+    input.offset() != *start
+}
+
+fn stalled<Par, Str, Err, SepErr>(
+    input: &Str,
+    start: Str::Offset,
+) -> RepeatError<Err, Str::Span, SepErr>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+{
+    RepeatError::Stalled {
+        span: input.span(start.clone(), start),
+        parser: type_name::<Par>(),
+    }
+}
+
 pub type Repeat<Par, Col = ()> = Repeater<Par, UntilErr, Col>;
 pub type RepeatEOI<Par, Col = ()> = Repeater<Par, UntilEOI, Col>;
 pub type RepeatMin<Par, Col = ()> = Repeater<Par, Minimum, Col>;
 pub type RepeatMinEOI<Par, Col = ()> = Repeater<Par, MinimumEOI, Col>;
 pub type RepeatMax<Par, Col = ()> = Repeater<Par, Maximum, Col>;
 pub type RepeatExact<Par, Col = ()> = Repeater<Par, Exact, Col>;
+pub type RepeatRange<Par, Col = ()> = Repeater<Par, Range, Col>;
+pub type RepeatUntil<Par, Term, Col = ()> = Repeater<Par, Until<Term>, Col>;
+pub type RepeatTerminated<Par, Term, Col = ()> = Repeater<Par, Terminated<Term>, Col>;
 
 #[derive(Clone, Copy, Debug)]
 pub struct Repeater<Par, Mod, Col = ()> {
@@ -126,22 +379,31 @@ impl<Par, Mod> Repeater<Par, Mod> {
 impl<Str, Par, Col, Out> ParserOnce<Str> for Repeat<Par, Col>
 where
     Str: Stream,
+    Str::Offset: PartialEq,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: Recoverable + UnerringConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col>,
 {
-    type Output = Out::Infallible;
+    type Output = <Collected<Out, Col> as UnerringConvertable>::Infallible;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
         loop {
+            if self.collector.is_full() {
+                return Pure::pure(self.collector);
+            }
+            let start = input.offset();
             match (&mut self.parser)
                 .non_terminal()
                 .parse_stream_mut(input)
                 .into_result()
             {
-                Ok(val) => self.collector.extend([val]),
-                Err(_) => return <Out::Infallible as Pure>::pure(self.collector),
+                Ok(val) => self.collector.push(val),
+                Err(_) => return Pure::pure(self.collector),
+            }
+            if !progressed(input, &start) {
+                return Pure::pure(self.collector);
             }
         }
     }
@@ -150,22 +412,27 @@ where
 impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatEOI<Par, Col>
 where
     Str: Stream,
+    Str::Offset: Clone + PartialEq,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: ResultConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
 {
-    type Output = Out;
+    type Output = Result<Col, RepeatError<Out::Error, Str::Span>>;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
         loop {
             match input.peek() {
                 Some(_) => {}
-                None => return Out::ok(self.collector),
+                None => return Ok(self.collector),
             }
 
+            let start = input.offset();
             self.collector
-                .extend([result!(self.parser.parse_stream_mut(input))]);
+                .push(item!(self.parser.parse_stream_mut(input)));
+            if !progressed(input, &start) {
+                return Err(stalled::<Par, _, _, _>(input, start));
+            }
         }
     }
 }
@@ -174,24 +441,28 @@ impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatMax<Par, Col>
 where
     Str: Stream,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: Recoverable + UnerringConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col>,
 {
-    type Output = Out::Infallible;
+    type Output = <Collected<Out, Col> as UnerringConvertable>::Infallible;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
         for _ in 0..self.mode.0 {
+            if self.collector.is_full() {
+                break;
+            }
             match (&mut self.parser)
                 .non_terminal()
                 .parse_stream_mut(input)
                 .into_result()
             {
-                Ok(val) => self.collector.extend([val]),
+                Ok(val) => self.collector.push(val),
                 Err(_) => break,
             }
         }
-        <Out::Infallible as Pure>::pure(self.collector)
+        Pure::pure(self.collector)
     }
 }
 
@@ -199,37 +470,40 @@ impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatExact<Par, Col>
 where
     Str: Stream,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: ResultConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
+    Collected<Out, Col>: ResultConvertable<Value = Col, Error = Out::Error>,
 {
-    type Output = Out;
+    type Output = Collected<Out, Col>;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
         for _ in 0..self.mode.0 {
             self.collector
-                .extend([result!(self.parser.parse_stream_mut(input))])
+                .push(result!(self.parser.parse_stream_mut(input)))
         }
-        Out::ok(self.collector)
+        ResultConvertable::ok(self.collector)
     }
 }
 
 impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatMin<Par, Col>
 where
     Str: Stream,
+    Str::Offset: PartialEq,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: Recoverable + UnerringConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col, Error = Out::Error>,
 {
-    type Output = Out;
+    type Output = Collected<Out, Col>;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
-        let mut collector = result!((&mut self.parser)
+        let collector = result!((&mut self.parser)
             .repeat_exact(self.mode.0)
             .collector(self.collector)
             .parse_stream_once(input));
-        Out::ok(
+        ResultConvertable::ok(
             self.parser
                 .repeat()
                 .collector(collector)
@@ -239,199 +513,410 @@ where
     }
 }
 
-impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatMinEOI<Par, Col>
+impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatRange<Par, Col>
 where
     Str: Stream,
+    Str::Offset: PartialEq,
     Par: ParserMut<Str, Output = Out>,
-    Col: Extend<Out::Value>,
-    Out: UnerringConvertable<Value = Col>,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col, Error = Out::Error>,
 {
-    type Output = Out;
+    type Output = Collected<Out, Col>;
 
     #[inline]
     fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
-        let mut collector = result!((&mut self.parser)
-            .repeat_exact(self.mode.0)
+        let Range(minimum, maximum) = self.mode;
+        let collector = result!((&mut self.parser)
+            .repeat_exact(minimum)
             .collector(self.collector)
             .parse_stream_once(input));
-        self.parser
-            .repeat_eoi()
-            .collector(collector)
-            .parse_stream_once(input)
+        ResultConvertable::ok(match maximum {
+            Some(maximum) => self
+                .parser
+                .repeat_max(maximum - minimum)
+                .collector(collector)
+                .parse_stream_once(input)
+                .unwrap(),
+            None => self
+                .parser
+                .repeat()
+                .collector(collector)
+                .parse_stream_once(input)
+                .unwrap(),
+        })
     }
 }
 
-// Interspersed
-
-/*impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<UntilErr, Int>, Col>
+impl<Str, Par, Term, Col, Out> ParserOnce<Str> for RepeatUntil<Par, Term, Col>
 where
     Str: Stream,
+    Str::Offset: Clone + PartialEq,
     Par: ParserMut<Str, Output = Out>,
-    Int: ParserMut<Str>,
-    Col: Extend<Out::Value>,
-    Out: Recoverable + Combinable<Int::Output> + UnerringConvertable<Value = Col>,
+    Term: ParserMut<Str>,
+    Term::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
 {
-    type Output = Out;
+    type Output = Result<Col, RepeatError<Out::Error, Str::Span>>;
 
     #[inline]
-    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let Inter(UntilErr, ref mut int) = self.mode;
-
-        match self
-            .parser
-            .non_terminal()
-            .parse_stream_mut(input)
-            .into_result()
-        {
-            Ok(value) => self.collector.extend([value]),
-            Err(_) => return Out::ok(self.collector),
-        }
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        loop {
+            let checkpoint = input.checkpoint();
+            let found = self.mode.0.parse_stream_mut(input).into_result().is_ok();
+            input.rewind(checkpoint.clone());
+            if found {
+                return Ok(self.collector);
+            }
 
-        int.and(self.parser)
-            .repeat()
-            .collector(self.collector)
-            .parse_stream_once(input)
+            // The terminator can't match where it just failed, so there's
+            // nowhere left to stop.
+            self.collector
+                .push(item!(self.parser.parse_stream_mut(input)));
+            if !progressed(input, &checkpoint.offset) {
+                return Err(stalled::<Par, _, _, _>(input, checkpoint.offset));
+            }
+        }
     }
 }
 
-impl<Str, Par, Int, Col> ParserOnce<Str> for Repeater<Par, Inter<Maximum, Int>, Col>
+impl<Str, Par, Term, Col, Out> ParserOnce<Str> for RepeatTerminated<Par, Term, Col>
 where
     Str: Stream,
-    Par: ParserOnce<Str>,
-    Int: ParserOnce<Str>,
-    Col: Collector<Par::Output>,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Term: ParserMut<Str>,
+    Term::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
 {
-    type Output = Pure<Col>;
+    type Output = Result<
+        (Col, <Term::Output as ResultConvertable>::Value),
+        RepeatError<Out::Error, Str::Span>,
+    >;
 
     #[inline]
-    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let mut collector = std::mem::take(&mut self.collector);
-        let Inter(Maximum(maximum), ref mut int) = self.mode;
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        loop {
+            let checkpoint = input.checkpoint();
+            match self.mode.0.parse_stream_mut(input).into_result() {
+                Ok(terminator) => return Ok((self.collector, terminator)),
+                Err(_) => input.rewind(checkpoint.clone()),
+            }
 
-        if maximum == 0 {
-            return Ok(collector);
+            self.collector
+                .push(item!(self.parser.parse_stream_mut(input)));
+            if !progressed(input, &checkpoint.offset) {
+                return Err(stalled::<Par, _, _, _>(input, checkpoint.offset));
+            }
         }
+    }
+}
 
-        match self.parser.non_terminal().parse_stream_once(input) {
-            Ok(value) => collector.push(value),
-            Err(_) => return Ok(collector),
-        }
+impl<Str, Par, Col, Out> ParserOnce<Str> for RepeatMinEOI<Par, Col>
+where
+    Str: Stream,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Col: Collector<Out::Value>,
+    Out: UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col, Error = Out::Error>,
+{
+    type Output = Result<Col, RepeatError<Out::Error, Str::Span>>;
 
-        int.then(self.parser)
-            .repeat_max(maximum - 1)
+    #[inline]
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let collector = item!((&mut self.parser)
+            .repeat_exact(self.mode.0)
+            .collector(self.collector)
+            .parse_stream_once(input));
+        self.parser
+            .repeat_eoi()
             .collector(collector)
             .parse_stream_once(input)
     }
 }
 
-impl<Str, Par, Int, Col> ParserOnce<Str> for Repeater<Par, Inter<Exact, Int>, Col>
+// Interspersed
+
+type InterError<Str, Out, Int> = RepeatError<
+    <Out as ResultConvertable>::Error,
+    <Str as Stream>::Span,
+    <<Int as ParserOnce<Str>>::Output as ResultConvertable>::Error,
+>;
+
+// How many items an interspersed repetition takes. Up to `minimum` items, or
+// past the last item when `eoi` is set, any failure is an error; otherwise
+// the list ends at the last item, plus its separator when trailing
+// separators are allowed.
+#[derive(Clone, Copy)]
+struct Limits {
+    minimum: usize,
+    maximum: Option<usize>,
+    eoi: bool,
+}
+
+impl<Par, Mod, Int, Col> Repeater<Par, Inter<Mod, Int>, Col> {
+    fn run_interspersed<Str, Out>(
+        &mut self,
+        input: &mut Str,
+        limits: Limits,
+    ) -> Result<(), InterError<Str, Out, Int>>
+    where
+        Str: Stream,
+        Str::Offset: Clone + PartialEq,
+        Par: ParserMut<Str, Output = Out>,
+        Int: ParserMut<Str>,
+        Int::Output: ResultConvertable,
+        Col: Collector<Out::Value>,
+        Out: ResultConvertable,
+    {
+        let Limits {
+            minimum,
+            maximum,
+            eoi,
+        } = limits;
+        let Inter(_, ref mut int, trailing) = self.mode;
+        let parser = &mut self.parser;
+        let collector = &mut self.collector;
+
+        let mut count = 0;
+        let mut end = input.checkpoint();
+        loop {
+            if Some(count) == maximum || (count >= minimum && !eoi && collector.is_full()) {
+                break;
+            }
+            let start = input.offset();
+            let at_end = eoi && input.peek().is_none();
+            if at_end && count >= minimum && (count == 0 || trailing != Trailing::Forbidden) {
+                return Ok(());
+            }
+
+            let value = match parser.parse_stream_mut(input).into_result() {
+                Ok(value) => value,
+                Err(error) if count < minimum || eoi => return Err(RepeatError::Item(error)),
+                Err(_) => break,
+            };
+
+            if trailing == Trailing::Required {
+                match int.parse_stream_mut(input).into_result() {
+                    Ok(_) => {}
+                    Err(error) if count < minimum || eoi => return Err(RepeatError::Separator(error)),
+                    Err(_) => break,
+                }
+                collector.push(value);
+                count += 1;
+                end = input.checkpoint();
+                if maximum.is_none() && !progressed(input, &start) {
+                    match eoi {
+                        true => return Err(stalled::<(Par, Int), _, _, _>(input, start)),
+                        false => break,
+                    }
+                }
+                continue;
+            }
+
+            collector.push(value);
+            count += 1;
+            end = input.checkpoint();
+
+            if Some(count) == maximum {
+                if trailing == Trailing::Allowed
+                    && int.parse_stream_mut(input).into_result().is_ok()
+                {
+                    end = input.checkpoint();
+                }
+                break;
+            }
+            if eoi && input.peek().is_none() {
+                return Ok(());
+            }
+
+            match int.parse_stream_mut(input).into_result() {
+                Ok(_) if trailing == Trailing::Allowed => end = input.checkpoint(),
+                Ok(_) => {}
+                Err(error) if count < minimum || eoi => return Err(RepeatError::Separator(error)),
+                Err(_) => break,
+            }
+            if maximum.is_none() && !progressed(input, &start) {
+                match eoi {
+                    true => return Err(stalled::<(Par, Int), _, _, _>(input, start)),
+                    false => break,
+                }
+            }
+        }
+        input.rewind(end);
+        Ok(())
+    }
+}
+
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<UntilErr, Int>, Col>
 where
     Str: Stream,
-    Par: ParserOnce<Str>,
-    Int: ParserOnce<Str>,
-    Par::Output: Exceptional,
-    Int::Output: Exceptional<Error = <Par::Output as Exceptional>::Error>,
-    Col: Collector<Par::Output>,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col>,
 {
-    type Output = Result<Col, err![Par]>;
+    type Output = <Collected<Out, Col> as UnerringConvertable>::Infallible;
 
     #[inline]
-    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let mut collector = std::mem::take(&mut self.collector);
-        let Inter(Exact(exact), ref mut int) = self.mode;
-
-        if exact == 0 {
-            return Ok(collector);
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let limits = Limits {
+            minimum: 0,
+            maximum: None,
+            eoi: false,
+        };
+        match self.run_interspersed(input, limits) {
+            Ok(()) => Pure::pure(self.collector),
+            Err(_) => unreachable!("a repetition without a minimum can't fail"),
         }
+    }
+}
 
-        collector.push(self.parser.parse_stream_once(input)?);
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<UntilEOI, Int>, Col>
+where
+    Str: Stream,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
+{
+    type Output = Result<Col, InterError<Str, Out, Int>>;
 
-        int.then(self.parser)
-            .repeat_exact(exact - 1)
-            .collector(collector)
-            .parse_stream_once(input)
+    #[inline]
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let limits = Limits {
+            minimum: 0,
+            maximum: None,
+            eoi: true,
+        };
+        self.run_interspersed(input, limits)?;
+        Ok(self.collector)
     }
 }
 
-impl<Str, Par, Int, Col> ParserOnce<Str> for Repeater<Par, Inter<Minimum, Int>, Col>
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<Maximum, Int>, Col>
 where
     Str: Stream,
-    Par: ParserOnce<Str>,
-    Int: ParserOnce<Str>,
-    Par::Output: Exceptional,
-    Int::Output: Exceptional<Error = <Par::Output as Exceptional>::Error>,
-    Col: Collector<Par::Output>,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: Recoverable + UnerringConvertable,
+    Collected<Out, Col>: UnerringConvertable<Value = Col>,
 {
-    type Output = Result<Col, err![Par]>;
+    type Output = <Collected<Out, Col> as UnerringConvertable>::Infallible;
 
     #[inline]
-    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let collector = std::mem::take(&mut self.collector);
-        let Inter(Minimum(minimum), ref mut int) = self.mode;
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let Inter(Maximum(maximum), ..) = self.mode;
+        let limits = Limits {
+            minimum: 0,
+            maximum: Some(maximum),
+            eoi: false,
+        };
+        match self.run_interspersed(input, limits) {
+            Ok(()) => Pure::pure(self.collector),
+            Err(_) => unreachable!("a repetition without a minimum can't fail"),
+        }
+    }
+}
 
-        let collector = self
-            .parser
-            .repeat_exact(minimum)
-            .collector(collector)
-            .interspersed(int)
-            .parse_stream_once(input)?;
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<Exact, Int>, Col>
+where
+    Str: Stream,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
+{
+    type Output = Result<Col, InterError<Str, Out, Int>>;
 
-        Ok(int
-            .then(self.parser)
-            .repeat()
-            .collector(collector)
-            .parse_stream_once(input)
-            .unwrap())
+    #[inline]
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let Inter(Exact(exact), ..) = self.mode;
+        let limits = Limits {
+            minimum: exact,
+            maximum: Some(exact),
+            eoi: false,
+        };
+        self.run_interspersed(input, limits)?;
+        Ok(self.collector)
     }
 }
 
-impl<Str, Par, Int, Col> ParserOnce<Str> for Repeater<Par, Inter<MinimumEOI, Int>, Col>
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<Minimum, Int>, Col>
 where
     Str: Stream,
-    Par: ParserOnce<Str>,
-    Int: ParserOnce<Str>,
-    Par::Output: Exceptional,
-    Int::Output: Exceptional<Error = <Par::Output as Exceptional>::Error>,
-    Col: Collector<Par::Output>,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
 {
-    type Output = Result<Col, err![Par]>;
+    type Output = Result<Col, InterError<Str, Out, Int>>;
 
     #[inline]
-    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
-        let collector = std::mem::take(&mut self.collector);
-        let Inter(MinimumEOI(minimum), ref mut int) = self.mode;
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let Inter(Minimum(minimum), ..) = self.mode;
+        let limits = Limits {
+            minimum,
+            maximum: None,
+            eoi: false,
+        };
+        self.run_interspersed(input, limits)?;
+        Ok(self.collector)
+    }
+}
 
-        let collector = self
-            .parser
-            .repeat_exact(minimum)
-            .collector(collector)
-            .interspersed(int)
-            .parse_stream_once(input)?;
+impl<Str, Par, Int, Col, Out> ParserOnce<Str> for Repeater<Par, Inter<MinimumEOI, Int>, Col>
+where
+    Str: Stream,
+    Str::Offset: Clone + PartialEq,
+    Par: ParserMut<Str, Output = Out>,
+    Int: ParserMut<Str>,
+    Int::Output: ResultConvertable,
+    Col: Collector<Out::Value>,
+    Out: ResultConvertable,
+{
+    type Output = Result<Col, InterError<Str, Out, Int>>;
 
-        Ok(int
-            .then(self.parser)
-            .repeat_eoi()
-            .collector(collector)
-            .parse_stream_once(input)?)
+    #[inline]
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let Inter(MinimumEOI(minimum), ..) = self.mode;
+        let limits = Limits {
+            minimum,
+            maximum: None,
+            eoi: true,
+        };
+        self.run_interspersed(input, limits)?;
+        Ok(self.collector)
     }
-}*/
+}
 
 impl<Par, Mod, Col> Repeater<Par, Mod, Col> {
-    /*#[inline(always)]
-    pub fn interspersed<Str>(self, parser: Int) -> Repeater<Par, Inter<Mod, Int>, Col> {
+    #[inline(always)]
+    pub fn interspersed<Int>(self, parser: Int) -> Repeater<Par, Inter<Mod, Int>, Col> {
         Repeater {
             parser: self.parser,
-            mode: Inter(self.mode, parser),
+            mode: Inter(self.mode, parser, Trailing::Forbidden),
             collector: self.collector,
         }
-    }*/
+    }
 
     #[inline(always)]
-    pub fn collector<T, Str>(self, collector: T) -> Repeater<Par, Mod, T>
-    where
-        Str: Stream,
-        Par: ParserMut<Str>,
-    {
+    pub fn collector<T>(self, collector: T) -> Repeater<Par, Mod, T> {
         Repeater {
             parser: self.parser,
             mode: self.mode,
@@ -440,12 +925,17 @@ impl<Par, Mod, Col> Repeater<Par, Mod, Col> {
     }
 
     #[inline(always)]
-    pub fn collect<T, Str>(self) -> Repeater<Par, Mod, T>
+    pub fn fold<Acc, Fun, Val>(self, init: Acc, function: Fun) -> Fold<Par, Mod, Acc, Fun>
+    where
+        Fun: FnMut(Acc, Val) -> Acc,
+    {
+        Fold::new(self.collector(Accumulator::new(init, function)))
+    }
+
+    #[inline(always)]
+    pub fn collect<T>(self) -> Repeater<Par, Mod, T>
     where
         T: Default,
-        Str: Stream,
-        Par: ParserMut<Str>,
-        Par::Output: Data,
     {
         Repeater {
             parser: self.parser,
@@ -455,11 +945,11 @@ impl<Par, Mod, Col> Repeater<Par, Mod, Col> {
     }
 
     #[inline(always)]
-    pub fn to_vec<Str>(self) -> Repeater<Par, Mod, Vec<Par::Output>>
+    pub fn to_vec<Str>(self) -> Repeater<Par, Mod, Vec<<Par::Output as ResultConvertable>::Value>>
     where
         Str: Stream,
         Par: ParserMut<Str>,
-        Par::Output: Data,
+        Par::Output: ResultConvertable,
     {
         Repeater {
             parser: self.parser,
@@ -469,6 +959,18 @@ impl<Par, Mod, Col> Repeater<Par, Mod, Col> {
     }
 }
 
+impl<Par, Mod, Int, Col> Repeater<Par, Inter<Mod, Int>, Col> {
+    #[inline(always)]
+    pub fn trailing(self, trailing: Trailing) -> Self {
+        let Inter(mode, int, _) = self.mode;
+        Repeater {
+            parser: self.parser,
+            mode: Inter(mode, int, trailing),
+            collector: self.collector,
+        }
+    }
+}
+
 impl<Par, Col> Repeat<Par, Col> {
     #[inline(always)]
     pub fn until_eoi(self) -> RepeatEOI<Par, Col> {
@@ -480,6 +982,17 @@ impl<Par, Col> Repeat<Par, Col> {
     }
 }
 
+impl<Par, Term, Col> RepeatUntil<Par, Term, Col> {
+    #[inline(always)]
+    pub fn consume_terminator(self) -> RepeatTerminated<Par, Term, Col> {
+        Repeater {
+            parser: self.parser,
+            mode: Terminated(self.mode.0),
+            collector: self.collector,
+        }
+    }
+}
+
 impl<Par, Col> RepeatMin<Par, Col> {
     #[inline(always)]
     pub fn until_eoi(self) -> RepeatMinEOI<Par, Col> {
@@ -495,8 +1008,18 @@ macro_rules! result {
     ($expr:expr) => {
         match $expr.into_result() {
             Ok(it) => it,
-            Err(err) => return Out::err(err),
+            Err(err) => return ResultConvertable::err(err),
         }
     };
 }
 use result;
+
+macro_rules! item {
+    ($expr:expr) => {
+        match $expr.into_result() {
+            Ok(it) => it,
+            Err(err) => return Err(RepeatError::Item(err)),
+        }
+    };
+}
+use item;
@@ -0,0 +1,398 @@
+use std::fmt::{self, Display};
+
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+// A sequence combines the outcomes of its parsers with the kept value taken
+// out, then puts the value back. That way responses carrying nothing, such
+// as `()`, fit in, and the output is a `Sure` exactly when every part is.
+// Like `and`, a failed sequence does not rewind, `non_terminal` does that.
+pub trait Separable: ResultConvertable {
+    type Outcome: ResultConvertable<Value = (), Error = Self::Error>;
+
+    fn separate(self) -> (Self::Outcome, Option<Self::Value>);
+}
+
+impl<Res> Separable for Res
+where
+    Res: Ignorable + ResultConvertable,
+    <Res as Ignorable>::Output: ResultConvertable<Value = (), Error = Res::Error>,
+{
+    type Outcome = <Res as Ignorable>::Output;
+
+    fn separate(self) -> (Self::Outcome, Option<Self::Value>) {
+        match self.into_result() {
+            Ok(value) => (ResultConvertable::ok(()), Some(value)),
+            Err(error) => (ResultConvertable::err(error), None),
+        }
+    }
+}
+
+pub trait Rejoinable<Val>: ResultConvertable {
+    type Output: ResultConvertable<Value = Val>;
+
+    fn rejoin(self, value: Option<Val>) -> Self::Output;
+}
+
+impl<Res, Val> Rejoinable<Val> for Res
+where
+    Res: Attachable<Val> + ResultConvertable,
+    <Res as Attachable<Val>>::Output: ResultConvertable<Value = Val, Error = Res::Error>,
+{
+    type Output = <Res as Attachable<Val>>::Output;
+
+    fn rejoin(self, value: Option<Val>) -> Self::Output {
+        match value {
+            Some(value) => self.attach_to_response(value),
+            // The value is only missing when its parser failed, and that
+            // failed the whole sequence.
+            None => match self.into_result() {
+                Ok(_) => unreachable!(),
+                Err(error) => ResultConvertable::err(error),
+            },
+        }
+    }
+}
+
+type Outcome<Res> = <Res as Separable>::Outcome;
+type Ignored<Res> = <Res as Ignorable>::Output;
+type Combined<Res0, Res1> = <Res0 as Combinable<Res1>>::Output;
+type Rejoined<Res, Val> = <Res as Rejoinable<Val>>::Output;
+
+pub struct ThenIgnore<Par0, Par1> {
+    parser0: Par0,
+    parser1: Par1,
+}
+
+impl<Par0, Par1> ThenIgnore<Par0, Par1> {
+    pub(crate) fn new<Str>(parser0: Par0, parser1: Par1) -> Self
+    where
+        Str: Stream,
+        Par0: ParserOnce<Str>,
+        Par0::Output: Separable,
+        Par1: ParserOnce<Str>,
+        Par1::Output: Ignorable,
+    {
+        ThenIgnore { parser0, parser1 }
+    }
+}
+
+impl<Str, Par0, Par1> ParserOnce<Str> for ThenIgnore<Par0, Par1>
+where
+    Str: Stream,
+    Par0: ParserOnce<Str>,
+    Par0::Output: Separable,
+    Outcome<Par0::Output>: Combinable<Ignored<Par1::Output>>,
+    Combined<Outcome<Par0::Output>, Ignored<Par1::Output>>:
+        Rejoinable<<Par0::Output as ResultConvertable>::Value>,
+    Par1: ParserOnce<Str>,
+    Par1::Output: Ignorable,
+{
+    type Output = Rejoined<
+        Combined<Outcome<Par0::Output>, Ignored<Par1::Output>>,
+        <Par0::Output as ResultConvertable>::Value,
+    >;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let (outcome, value) = self.parser0.parse_stream_once(input).separate();
+        outcome
+            .combine_response(|| self.parser1.parse_stream_once(input).ignore_response())
+            .rejoin(value)
+    }
+}
+
+impl<Str, Par0, Par1> ParserMut<Str> for ThenIgnore<Par0, Par1>
+where
+    Str: Stream,
+    Par0: ParserMut<Str>,
+    Par0::Output: Separable,
+    Outcome<Par0::Output>: Combinable<Ignored<Par1::Output>>,
+    Combined<Outcome<Par0::Output>, Ignored<Par1::Output>>:
+        Rejoinable<<Par0::Output as ResultConvertable>::Value>,
+    Par1: ParserMut<Str>,
+    Par1::Output: Ignorable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        ThenIgnore {
+            parser0: &mut self.parser0,
+            parser1: &mut self.parser1,
+        }
+        .parse_stream_once(input)
+    }
+}
+
+pub struct IgnoreThen<Par0, Par1> {
+    parser0: Par0,
+    parser1: Par1,
+}
+
+impl<Par0, Par1> IgnoreThen<Par0, Par1> {
+    pub(crate) fn new<Str>(parser0: Par0, parser1: Par1) -> Self
+    where
+        Str: Stream,
+        Par0: ParserOnce<Str>,
+        Par0::Output: Ignorable,
+        Par1: ParserOnce<Str>,
+        Par1::Output: Separable,
+    {
+        IgnoreThen { parser0, parser1 }
+    }
+}
+
+impl<Str, Par0, Par1> ParserOnce<Str> for IgnoreThen<Par0, Par1>
+where
+    Str: Stream,
+    Par0: ParserOnce<Str>,
+    Par0::Output: Ignorable,
+    Ignored<Par0::Output>: Combinable<Outcome<Par1::Output>>,
+    Combined<Ignored<Par0::Output>, Outcome<Par1::Output>>:
+        Rejoinable<<Par1::Output as ResultConvertable>::Value>,
+    Par1: ParserOnce<Str>,
+    Par1::Output: Separable,
+{
+    type Output = Rejoined<
+        Combined<Ignored<Par0::Output>, Outcome<Par1::Output>>,
+        <Par1::Output as ResultConvertable>::Value,
+    >;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let mut value = None;
+        self.parser0
+            .parse_stream_once(input)
+            .ignore_response()
+            .combine_response(|| {
+                let (outcome, kept) = self.parser1.parse_stream_once(input).separate();
+                value = kept;
+                outcome
+            })
+            .rejoin(value)
+    }
+}
+
+impl<Str, Par0, Par1> ParserMut<Str> for IgnoreThen<Par0, Par1>
+where
+    Str: Stream,
+    Par0: ParserMut<Str>,
+    Par0::Output: Ignorable,
+    Ignored<Par0::Output>: Combinable<Outcome<Par1::Output>>,
+    Combined<Ignored<Par0::Output>, Outcome<Par1::Output>>:
+        Rejoinable<<Par1::Output as ResultConvertable>::Value>,
+    Par1: ParserMut<Str>,
+    Par1::Output: Separable,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        IgnoreThen {
+            parser0: &mut self.parser0,
+            parser1: &mut self.parser1,
+        }
+        .parse_stream_once(input)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DelimitedError<Open, Err, Close, Spn> {
+    Open(Open),
+    Body(Err),
+    // The closing delimiter is missing. The span covers the opening one.
+    Unclosed { open: Spn, error: Close },
+}
+
+impl<Open, Err, Close, Spn> Display for DelimitedError<Open, Err, Close, Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            DelimitedError::Open(_) => "expected an opening delimiter",
+            DelimitedError::Body(_) => "invalid delimited content",
+            DelimitedError::Unclosed { .. } => "unclosed delimiter",
+        })
+    }
+}
+
+type DelimitedErr<Open, Par, Close, Str> = DelimitedError<
+    <Open as ResultConvertable>::Error,
+    <Par as ResultConvertable>::Error,
+    <Close as ResultConvertable>::Error,
+    <Str as Stream>::Span,
+>;
+type Annotated<Res, Err> = <Res as Annotatable>::WithErr<Err>;
+
+// The errors of all three parts are wrapped into one `DelimitedError` before
+// their outcomes are combined.
+type BodyPart<Open, Par, Close, Str> = Annotated<Par, DelimitedErr<Open, Par, Close, Str>>;
+type OpenOutcome<Open, Par, Close, Str> =
+    Ignored<Annotated<Open, DelimitedErr<Open, Par, Close, Str>>>;
+type BodyOutcome<Open, Par, Close, Str> = Outcome<BodyPart<Open, Par, Close, Str>>;
+type CloseOutcome<Open, Par, Close, Str> =
+    Ignored<Annotated<Close, DelimitedErr<Open, Par, Close, Str>>>;
+type InnerOutcome<Open, Par, Close, Str> =
+    Combined<BodyOutcome<Open, Par, Close, Str>, CloseOutcome<Open, Par, Close, Str>>;
+type DelimitedOutcome<Open, Par, Close, Str> =
+    Combined<OpenOutcome<Open, Par, Close, Str>, InnerOutcome<Open, Par, Close, Str>>;
+
+pub struct Delimited<Par, Open, Close> {
+    parser: Par,
+    open: Open,
+    close: Close,
+}
+
+impl<Par, Open, Close> Delimited<Par, Open, Close> {
+    pub(crate) fn new<Str>(parser: Par, open: Open, close: Close) -> Self
+    where
+        Str: Stream,
+        Par: ParserOnce<Str>,
+        Open: ParserOnce<Str>,
+        Close: ParserOnce<Str>,
+    {
+        Delimited {
+            parser,
+            open,
+            close,
+        }
+    }
+}
+
+impl<Str, Par, Open, Close> ParserOnce<Str> for Delimited<Par, Open, Close>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserOnce<Str>,
+    Par::Output: Annotatable,
+    Open: ParserOnce<Str>,
+    Open::Output: Annotatable,
+    Close: ParserOnce<Str>,
+    Close::Output: Annotatable,
+    BodyPart<Open::Output, Par::Output, Close::Output, Str>: Separable,
+    BodyOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Combinable<CloseOutcome<Open::Output, Par::Output, Close::Output, Str>>,
+    OpenOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Combinable<InnerOutcome<Open::Output, Par::Output, Close::Output, Str>>,
+    DelimitedOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Rejoinable<<Par::Output as ResultConvertable>::Value>,
+{
+    type Output = Rejoined<
+        DelimitedOutcome<Open::Output, Par::Output, Close::Output, Str>,
+        <Par::Output as ResultConvertable>::Value,
+    >;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let mut value = None;
+        self.open
+            .parse_stream_once(input)
+            .annotate_response(DelimitedError::Open)
+            .ignore_response()
+            .combine_response(|| {
+                let open = input.span(start, input.offset());
+                let (outcome, kept) = self
+                    .parser
+                    .parse_stream_once(input)
+                    .annotate_response(DelimitedError::Body)
+                    .separate();
+                value = kept;
+                outcome.combine_response(|| {
+                    self.close
+                        .parse_stream_once(input)
+                        .annotate_response(|error| DelimitedError::Unclosed { open, error })
+                        .ignore_response()
+                })
+            })
+            .rejoin(value)
+    }
+}
+
+impl<Str, Par, Open, Close> ParserMut<Str> for Delimited<Par, Open, Close>
+where
+    Str: Stream,
+    Str::Offset: Clone,
+    Par: ParserMut<Str>,
+    Par::Output: Annotatable,
+    Open: ParserMut<Str>,
+    Open::Output: Annotatable,
+    Close: ParserMut<Str>,
+    Close::Output: Annotatable,
+    BodyPart<Open::Output, Par::Output, Close::Output, Str>: Separable,
+    BodyOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Combinable<CloseOutcome<Open::Output, Par::Output, Close::Output, Str>>,
+    OpenOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Combinable<InnerOutcome<Open::Output, Par::Output, Close::Output, Str>>,
+    DelimitedOutcome<Open::Output, Par::Output, Close::Output, Str>:
+        Rejoinable<<Par::Output as ResultConvertable>::Value>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        Delimited {
+            parser: &mut self.parser,
+            open: &mut self.open,
+            close: &mut self.close,
+        }
+        .parse_stream_once(input)
+    }
+}
+
+pub struct Padded<Par, Pad> {
+    parser: Par,
+    padding: Pad,
+}
+
+impl<Par, Pad> Padded<Par, Pad> {
+    pub(crate) fn new<Str>(parser: Par, padding: Pad) -> Self
+    where
+        Str: Stream,
+        Par: ParserOnce<Str>,
+        Pad: ParserMut<Str>,
+    {
+        Padded { parser, padding }
+    }
+}
+
+// The padding is parsed on both sides.
+type PaddedOutcome<Pad, Par> = Combined<Ignored<Pad>, Combined<Outcome<Par>, Ignored<Pad>>>;
+
+impl<Str, Par, Pad> ParserOnce<Str> for Padded<Par, Pad>
+where
+    Str: Stream,
+    Par: ParserOnce<Str>,
+    Par::Output: Separable,
+    Outcome<Par::Output>: Combinable<Ignored<Pad::Output>>,
+    Pad: ParserMut<Str>,
+    Pad::Output: Ignorable,
+    Ignored<Pad::Output>: Combinable<Combined<Outcome<Par::Output>, Ignored<Pad::Output>>>,
+    PaddedOutcome<Pad::Output, Par::Output>: Rejoinable<<Par::Output as ResultConvertable>::Value>,
+{
+    type Output = Rejoined<
+        PaddedOutcome<Pad::Output, Par::Output>,
+        <Par::Output as ResultConvertable>::Value,
+    >;
+
+    fn parse_stream_once(mut self, input: &mut Str) -> Self::Output {
+        let mut value = None;
+        self.padding
+            .parse_stream_mut(input)
+            .ignore_response()
+            .combine_response(|| {
+                let (outcome, kept) = self.parser.parse_stream_once(input).separate();
+                value = kept;
+                outcome.combine_response(|| self.padding.parse_stream_mut(input).ignore_response())
+            })
+            .rejoin(value)
+    }
+}
+
+impl<Str, Par, Pad> ParserMut<Str> for Padded<Par, Pad>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+    Par::Output: Separable,
+    Outcome<Par::Output>: Combinable<Ignored<Pad::Output>>,
+    Pad: ParserMut<Str>,
+    Pad::Output: Ignorable,
+    Ignored<Pad::Output>: Combinable<Combined<Outcome<Par::Output>, Ignored<Pad::Output>>>,
+    PaddedOutcome<Pad::Output, Par::Output>: Rejoinable<<Par::Output as ResultConvertable>::Value>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        Padded {
+            parser: &mut self.parser,
+            padding: &mut self.padding,
+        }
+        .parse_stream_once(input)
+    }
+}
@@ -0,0 +1,70 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+// The next parser is built from the value of the first, which is kept
+// alongside the next one's as in `and`.
+pub struct ThenWith<Par, Fun> {
+    parser: Par,
+    function: Fun,
+}
+
+impl<Par, Fun> ThenWith<Par, Fun> {
+    pub(crate) fn new<Str, Next>(parser: Par, function: Fun) -> Self
+    where
+        Str: Stream,
+        Par: ParserOnce<Str>,
+        Par::Output: ResultConvertable + Combinable<Next::Output>,
+        Fun: FnOnce(&<Par::Output as ResultConvertable>::Value) -> Next,
+        Next: ParserOnce<Str>,
+    {
+        ThenWith { parser, function }
+    }
+}
+
+fn bind<Str, Out, Fun, Next>(output: Out, function: Fun, input: &mut Str) -> Out::Output
+where
+    Str: Stream,
+    Out: ResultConvertable + Combinable<Next::Output>,
+    Fun: FnOnce(&Out::Value) -> Next,
+    Next: ParserOnce<Str>,
+{
+    match output.into_result() {
+        Ok(value) => {
+            let next = function(&value);
+            Out::ok(value).combine_response(|| next.parse_stream_once(input))
+        }
+        Err(error) => Out::err(error)
+            .combine_response(|| unreachable!("a failed response doesn't run the next parser")),
+    }
+}
+
+impl<Str, Par, Fun, Next> ParserOnce<Str> for ThenWith<Par, Fun>
+where
+    Str: Stream,
+    Par: ParserOnce<Str>,
+    Par::Output: ResultConvertable + Combinable<Next::Output>,
+    Fun: FnOnce(&<Par::Output as ResultConvertable>::Value) -> Next,
+    Next: ParserOnce<Str>,
+{
+    type Output = <Par::Output as Combinable<Next::Output>>::Output;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        let output = self.parser.parse_stream_once(input);
+        bind(output, self.function, input)
+    }
+}
+
+impl<Str, Par, Fun, Next> ParserMut<Str> for ThenWith<Par, Fun>
+where
+    Str: Stream,
+    Par: ParserMut<Str>,
+    Par::Output: ResultConvertable + Combinable<Next::Output>,
+    Fun: FnMut(&<Par::Output as ResultConvertable>::Value) -> Next,
+    Next: ParserOnce<Str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        let output = self.parser.parse_stream_mut(input);
+        bind(output, &mut self.function, input)
+    }
+}
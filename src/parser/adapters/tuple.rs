@@ -0,0 +1,103 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+// Tuples of parsers run in sequence. Their outputs are combined left to right
+// exactly like nested `and`s, and only the value is flattened afterwards, so
+// `(a, b, c)` fails, recovers and defaults the same way `a.and(b).and(c)` does.
+macro_rules! sequence {
+    ($P0:ident $p0:ident $($P:ident $p:ident)+) => {
+        sequence!(
+            @chain [$P0 $p0 $($P $p)+]
+            [<$P0 as ParserOnce<Str>>::Output] [val![$P0]] [$p0] [] [$($P $p)+]
+        );
+    };
+    (@chain $all:tt [$acc:ty] [$nested:ty] [$pat:pat] [$($bounds:tt)*] [$P:ident $p:ident $($rest:tt)*]) => {
+        sequence!(
+            @chain $all
+            [<$acc as Combinable<<$P as ParserOnce<Str>>::Output>>::Output]
+            [($nested, val![$P])]
+            [($pat, $p)]
+            [$($bounds)* $acc: Combinable<<$P as ParserOnce<Str>>::Output>,]
+            [$($rest)*]
+        );
+    };
+    (@chain [$P0:ident $p0:ident $($P:ident $p:ident)+] [$acc:ty] [$nested:ty] [$pat:pat] [$($bounds:tt)*] []) => {
+        impl<Str, $P0, $($P),+> ParserOnce<Str> for ($P0, $($P),+)
+        where
+            Str: Stream,
+            $P0: ParserOnce<Str>,
+            $P0::Output: Data,
+            $($P: ParserOnce<Str>, $P::Output: Data,)+
+            $($bounds)*
+            $acc: Data<Value = $nested>,
+        {
+            type Output = <$acc as Data>::WithVal<(val![$P0], $(val![$P]),+)>;
+
+            fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = self;
+                $p0.parse_stream_once(input)
+                    $(.combine_response(|| $p.parse_stream_once(input)))+
+                    .map(|$pat| ($p0, $($p),+))
+            }
+        }
+
+        impl<Str, $P0, $($P),+> ParserMut<Str> for ($P0, $($P),+)
+        where
+            Str: Stream,
+            $P0: ParserMut<Str>,
+            $P0::Output: Data,
+            $($P: ParserMut<Str>, $P::Output: Data,)+
+            $($bounds)*
+            $acc: Data<Value = $nested>,
+        {
+            fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = self;
+                $p0.parse_stream_mut(input)
+                    $(.combine_response(|| $p.parse_stream_mut(input)))+
+                    .map(|$pat| ($p0, $($p),+))
+            }
+        }
+
+        impl<Str, $P0, $($P),+> Parser<Str> for ($P0, $($P),+)
+        where
+            Str: Stream,
+            $P0: Parser<Str>,
+            $P0::Output: Data,
+            $($P: Parser<Str>, $P::Output: Data,)+
+            $($bounds)*
+            $acc: Data<Value = $nested>,
+        {
+            fn parse_stream(&self, input: &mut Str) -> Self::Output {
+                let ($p0, $($p),+) = self;
+                $p0.parse_stream(input)
+                    $(.combine_response(|| $p.parse_stream(input)))+
+                    .map(|$pat| ($p0, $($p),+))
+            }
+        }
+    };
+}
+
+sequence!(P0 p0 P1 p1);
+sequence!(P0 p0 P1 p1 P2 p2);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10 P11 p11);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10 P11 p11 P12 p12);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10 P11 p11 P12 p12 P13 p13);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10 P11 p11 P12 p12 P13 p13 P14 p14);
+sequence!(P0 p0 P1 p1 P2 p2 P3 p3 P4 p4 P5 p5 P6 p6 P7 p7 P8 p8 P9 p9 P10 p10 P11 p11 P12 p12 P13 p13 P14 p14 P15 p15);
+
+// `seq!(a, b, c)` is the tuple parser `(a, b, c)`; a single parser is
+// returned as is.
+#[macro_export]
+macro_rules! seq {
+    ($parser:expr $(,)?) => { $parser };
+    ($($parser:expr),+ $(,)?) => { ($($parser,)+) };
+}
@@ -0,0 +1,321 @@
+use std::borrow::Cow;
+use std::ops::{BitAnd, BitOr, Not, RangeInclusive, Sub};
+
+use super::properties::{WHITE_SPACE, XID_CONTINUE, XID_START};
+use super::satisfy::{next_if, Predicate};
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+// `ranges` covers the whole set, sorted and with no two ranges overlapping or
+// touching. `ascii` caches its first 128 code points as a bitmap.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CharSet {
+    ascii: u128,
+    ranges: Cow<'static, [(char, char)]>,
+}
+
+impl CharSet {
+    pub const EMPTY: Self = Self::from_static(&[]);
+    pub const ANY: Self = Self::from_static(&[('\0', char::MAX)]);
+    pub const DIGIT: Self = Self::from_static(&[('0', '9')]);
+    pub const HEX_DIGIT: Self = Self::from_static(&[('0', '9'), ('A', 'F'), ('a', 'f')]);
+    // ALPHA and ALNUM are ASCII only, XID_START and XID_CONTINUE cover Unicode.
+    pub const ALPHA: Self = Self::from_static(&[('A', 'Z'), ('a', 'z')]);
+    pub const ALNUM: Self = Self::from_static(&[('0', '9'), ('A', 'Z'), ('a', 'z')]);
+    pub const WHITESPACE: Self = Self::from_static(WHITE_SPACE);
+    pub const XID_START: Self = Self::from_static(XID_START);
+    pub const XID_CONTINUE: Self = Self::from_static(XID_CONTINUE);
+
+    pub fn new() -> Self {
+        Self::EMPTY
+    }
+
+    // `ranges` must already be sorted, disjoint and non-adjacent.
+    pub const fn from_static(ranges: &'static [(char, char)]) -> Self {
+        Self {
+            ascii: ascii_bitmap(ranges),
+            ranges: Cow::Borrowed(ranges),
+        }
+    }
+
+    #[doc(hidden)]
+    pub const fn from_normalized<const N: usize>(
+        ranges: &'static [(char, char); N],
+        len: usize,
+    ) -> Self {
+        Self::from_static(ranges.split_at(len).0)
+    }
+
+    pub fn from_ranges<Itr>(ranges: Itr) -> Self
+    where
+        Itr: IntoIterator<Item = (char, char)>,
+    {
+        let mut ranges: Vec<_> = ranges.into_iter().filter(|(lo, hi)| lo <= hi).collect();
+        ranges.sort_unstable();
+
+        let mut merged: Vec<(char, char)> = Vec::with_capacity(ranges.len());
+        for (lo, hi) in ranges {
+            match merged.last_mut() {
+                Some(last) if lo as u32 <= succ(last.1 as u32) => last.1 = last.1.max(hi),
+                _ => merged.push((lo, hi)),
+            }
+        }
+
+        Self {
+            ascii: ascii_bitmap(&merged),
+            ranges: Cow::Owned(merged),
+        }
+    }
+
+    pub fn ranges(&self) -> &[(char, char)] {
+        &self.ranges
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    #[inline]
+    pub fn contains(&self, ch: char) -> bool {
+        match ch as u32 {
+            code if code < 128 => self.ascii & (1 << code) != 0,
+            _ => self
+                .ranges
+                .binary_search_by(|&(lo, hi)| match (lo > ch, hi < ch) {
+                    (true, _) => std::cmp::Ordering::Greater,
+                    (_, true) => std::cmp::Ordering::Less,
+                    _ => std::cmp::Ordering::Equal,
+                })
+                .is_ok(),
+        }
+    }
+
+    pub fn with(self, chars: impl Into<CharSet>) -> Self {
+        self.union(&chars.into())
+    }
+
+    pub fn union(&self, other: &CharSet) -> Self {
+        Self::from_ranges(self.ranges.iter().chain(other.ranges.iter()).copied())
+    }
+
+    pub fn negate(&self) -> Self {
+        let mut ranges = Vec::with_capacity(self.ranges.len() + 1);
+        let mut next = 0;
+        for &(lo, hi) in self.ranges.iter() {
+            if next < lo as u32 {
+                ranges.push((to_char(next), to_char(pred(lo as u32))));
+            }
+            next = succ(hi as u32);
+        }
+        if next <= char::MAX as u32 {
+            ranges.push((to_char(next), char::MAX));
+        }
+
+        Self {
+            ascii: ascii_bitmap(&ranges),
+            ranges: Cow::Owned(ranges),
+        }
+    }
+
+    pub fn intersection(&self, other: &CharSet) -> Self {
+        self.negate().union(&other.negate()).negate()
+    }
+
+    pub fn difference(&self, other: &CharSet) -> Self {
+        self.intersection(&other.negate())
+    }
+}
+
+impl Default for CharSet {
+    fn default() -> Self {
+        Self::EMPTY
+    }
+}
+
+impl From<char> for CharSet {
+    fn from(ch: char) -> Self {
+        Self::from_ranges([(ch, ch)])
+    }
+}
+
+impl From<RangeInclusive<char>> for CharSet {
+    fn from(range: RangeInclusive<char>) -> Self {
+        Self::from_ranges([range.into_inner()])
+    }
+}
+
+impl From<&str> for CharSet {
+    fn from(chars: &str) -> Self {
+        chars.chars().collect()
+    }
+}
+
+impl FromIterator<char> for CharSet {
+    fn from_iter<Itr>(iter: Itr) -> Self
+    where
+        Itr: IntoIterator<Item = char>,
+    {
+        Self::from_ranges(iter.into_iter().map(|ch| (ch, ch)))
+    }
+}
+
+impl Not for CharSet {
+    type Output = CharSet;
+
+    fn not(self) -> Self::Output {
+        self.negate()
+    }
+}
+
+impl<Set> BitOr<Set> for CharSet
+where
+    Set: Into<CharSet>,
+{
+    type Output = CharSet;
+
+    fn bitor(self, rhs: Set) -> Self::Output {
+        self.union(&rhs.into())
+    }
+}
+
+impl<Set> BitAnd<Set> for CharSet
+where
+    Set: Into<CharSet>,
+{
+    type Output = CharSet;
+
+    fn bitand(self, rhs: Set) -> Self::Output {
+        self.intersection(&rhs.into())
+    }
+}
+
+impl<Set> Sub<Set> for CharSet
+where
+    Set: Into<CharSet>,
+{
+    type Output = CharSet;
+
+    fn sub(self, rhs: Set) -> Self::Output {
+        self.difference(&rhs.into())
+    }
+}
+
+impl Predicate<char> for CharSet {
+    #[inline(always)]
+    fn test(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+impl<Str> Parser<Str> for CharSet
+where
+    Str: Stream<Item = char>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        next_if(input, |ch| self.contains(*ch))
+    }
+}
+
+impl<Str> ParserMut<Str> for CharSet
+where
+    Str: Stream<Item = char>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str> ParserOnce<Str> for CharSet
+where
+    Str: Stream<Item = char>,
+{
+    type Output = Option<char>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+// Code points skip the surrogate block, which `char` cannot represent.
+const fn succ(code: u32) -> u32 {
+    match code {
+        0xD7FF => 0xE000,
+        code => code + 1,
+    }
+}
+
+const fn pred(code: u32) -> u32 {
+    match code {
+        0xE000 => 0xD7FF,
+        code => code - 1,
+    }
+}
+
+fn to_char(code: u32) -> char {
+    char::from_u32(code).expect("code point outside of the surrogate block")
+}
+
+const fn ascii_bitmap(ranges: &[(char, char)]) -> u128 {
+    let mut bitmap = 0u128;
+    let mut index = 0;
+    while index < ranges.len() {
+        let (lo, hi) = ranges[index];
+        let mut code = lo as u32;
+        while code <= hi as u32 && code < 128 {
+            bitmap |= 1 << code;
+            code += 1;
+        }
+        index += 1;
+    }
+    bitmap
+}
+
+#[doc(hidden)]
+pub const fn normalize<const N: usize>(
+    mut ranges: [(char, char); N],
+) -> ([(char, char); N], usize) {
+    let mut index = 1;
+    while index < N {
+        let mut back = index;
+        while back > 0 && ranges[back - 1].0 as u32 > ranges[back].0 as u32 {
+            let swap = ranges[back - 1];
+            ranges[back - 1] = ranges[back];
+            ranges[back] = swap;
+            back -= 1;
+        }
+        index += 1;
+    }
+
+    let mut len = 0;
+    let mut index = 0;
+    while index < N {
+        let (lo, hi) = ranges[index];
+        if lo as u32 <= hi as u32 {
+            if len > 0 && lo as u32 <= succ(ranges[len - 1].1 as u32) {
+                if hi as u32 > ranges[len - 1].1 as u32 {
+                    ranges[len - 1].1 = hi;
+                }
+            } else {
+                ranges[len] = (lo, hi);
+                len += 1;
+            }
+        }
+        index += 1;
+    }
+    (ranges, len)
+}
+
+#[macro_export]
+macro_rules! chars {
+    ($($lo:literal $(..= $hi:literal)?),* $(,)?) => {{
+        const RANGES: ([(char, char); $crate::chars!(@count $($lo)*)], usize) =
+            $crate::parser::sources::charset::normalize([$(($lo, $crate::chars!(@hi $lo $($hi)?))),*]);
+        const SET: $crate::parser::sources::charset::CharSet =
+            $crate::parser::sources::charset::CharSet::from_normalized(&RANGES.0, RANGES.1);
+        SET
+    }};
+    (@hi $lo:literal) => { $lo };
+    (@hi $lo:literal $hi:literal) => { $hi };
+    (@count $($lo:literal)*) => { <[()]>::len(&[$($crate::chars!(@unit $lo)),*]) };
+    (@unit $lo:literal) => { () };
+}
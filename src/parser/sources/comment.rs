@@ -0,0 +1,162 @@
+use std::fmt::{self, Display};
+
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+pub struct LineComment<'d> {
+    open: &'d str,
+}
+
+// The comment runs up to, but not including, the end of the line.
+pub fn line_comment(open: &str) -> LineComment<'_> {
+    LineComment { open }
+}
+
+impl LineComment<'_> {
+    // Offsets of the body and the end of the comment, relative to its start.
+    fn scan(&self, text: &str) -> Option<(usize, usize)> {
+        let body = text.strip_prefix(self.open)?;
+        let len = body.find('\n').unwrap_or(body.len());
+        let len = match body[..len].ends_with('\r') {
+            true => len - 1,
+            false => len,
+        };
+        Some((self.open.len(), self.open.len() + len))
+    }
+}
+
+impl<'src, Str> Parser<Str> for LineComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let (body, end) = self.scan(&input.source()[start..])?;
+        input.advance(end);
+        Some(input.borrow_slice(start + body, start + end))
+    }
+}
+
+impl<'src, Str> ParserMut<Str> for LineComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str> ParserOnce<Str> for LineComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    type Output = Option<&'src str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CommentErrorKind {
+    Missing,
+    Unterminated,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CommentError<Spn> {
+    pub kind: CommentErrorKind,
+    pub span: Spn,
+}
+
+impl<Spn> Display for CommentError<Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            CommentErrorKind::Missing => "expected a comment",
+            CommentErrorKind::Unterminated => "unterminated block comment",
+        })
+    }
+}
+
+pub struct BlockComment<'d> {
+    open: &'d str,
+    close: &'d str,
+    nested: bool,
+}
+
+pub fn block_comment<'d>(open: &'d str, close: &'d str, nested: bool) -> BlockComment<'d> {
+    BlockComment {
+        open,
+        close,
+        nested,
+    }
+}
+
+impl BlockComment<'_> {
+    // Offsets of the end of the body and of the comment, relative to its
+    // start. An unterminated comment reports the opening delimiter of the
+    // outermost comment left open.
+    fn scan(&self, text: &str) -> Result<(usize, usize), (CommentErrorKind, usize, usize)> {
+        if !text.starts_with(self.open) {
+            return Err((CommentErrorKind::Missing, 0, 0));
+        }
+
+        let mut depth = 1;
+        let mut index = self.open.len();
+        while index < text.len() {
+            let rest = &text[index..];
+            if rest.starts_with(self.close) {
+                depth -= 1;
+                if depth == 0 {
+                    return Ok((index, index + self.close.len()));
+                }
+                index += self.close.len();
+            } else if self.nested && rest.starts_with(self.open) {
+                depth += 1;
+                index += self.open.len();
+            } else {
+                index += rest.chars().next().map_or(1, char::len_utf8);
+            }
+        }
+        Err((CommentErrorKind::Unterminated, 0, self.open.len()))
+    }
+}
+
+impl<'src, Str> Parser<Str> for BlockComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        match self.scan(&input.source()[start..]) {
+            Ok((body, end)) => {
+                input.advance(end);
+                Ok(input.borrow_slice(start + self.open.len(), start + body))
+            }
+            Err((kind, from, to)) => Err(CommentError {
+                kind,
+                span: input.span(start + from, start + to),
+            }),
+        }
+    }
+}
+
+impl<'src, Str> ParserMut<Str> for BlockComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str> ParserOnce<Str> for BlockComment<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    type Output = Result<&'src str, CommentError<Str::Span>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
@@ -0,0 +1,92 @@
+use super::charset::CharSet;
+use crate::chars;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+const ASCII_START: CharSet = chars!['A'..='Z', 'a'..='z', '_'];
+const ASCII_CONTINUE: CharSet = chars!['0'..='9', 'A'..='Z', 'a'..='z', '_'];
+
+pub struct Identifier<'kw> {
+    start: CharSet,
+    rest: CharSet,
+    reserved: &'kw [&'kw str],
+}
+
+// UAX #31 default identifiers, with `_` also allowed as the first character.
+pub fn identifier() -> Identifier<'static> {
+    Identifier {
+        start: CharSet::XID_START.with('_'),
+        rest: CharSet::XID_CONTINUE,
+        reserved: &[],
+    }
+}
+
+impl<'kw> Identifier<'kw> {
+    pub fn ascii(self) -> Self {
+        self.chars(ASCII_START, ASCII_CONTINUE)
+    }
+
+    pub fn chars(self, start: CharSet, rest: CharSet) -> Self {
+        Self {
+            start,
+            rest,
+            ..self
+        }
+    }
+
+    pub fn reserved<'a>(self, words: &'a [&'a str]) -> Identifier<'a> {
+        Identifier {
+            start: self.start,
+            rest: self.rest,
+            reserved: words,
+        }
+    }
+
+    fn scan(&self, text: &str) -> Option<usize> {
+        let mut chars = text.char_indices();
+        match chars.next() {
+            Some((_, ch)) if self.start.contains(ch) => {}
+            _ => return None,
+        }
+
+        let len = chars
+            .find(|(_, ch)| !self.rest.contains(*ch))
+            .map_or(text.len(), |(index, _)| index);
+        match self.reserved.contains(&&text[..len]) {
+            true => None,
+            false => Some(len),
+        }
+    }
+}
+
+impl<'src, Str> Parser<Str> for Identifier<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let len = self.scan(&input.source()[start..])?;
+        input.advance(len);
+        Some(input.borrow_slice(start, start + len))
+    }
+}
+
+impl<'src, Str> ParserMut<Str> for Identifier<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str> ParserOnce<Str> for Identifier<'_>
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    type Output = Option<&'src str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
@@ -0,0 +1,223 @@
+use std::ops::Deref;
+
+use super::satisfy::next_if;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+pub trait Symbols {
+    type Symbol: Ord + Clone;
+
+    fn symbols(&self) -> &[Self::Symbol];
+}
+
+impl Symbols for str {
+    type Symbol = u8;
+
+    fn symbols(&self) -> &[Self::Symbol] {
+        self.as_bytes()
+    }
+}
+
+impl<T> Symbols for [T]
+where
+    T: Ord + Clone,
+{
+    type Symbol = T;
+
+    fn symbols(&self) -> &[Self::Symbol] {
+        self
+    }
+}
+
+impl<T, const N: usize> Symbols for [T; N]
+where
+    T: Ord + Clone,
+{
+    type Symbol = T;
+
+    fn symbols(&self) -> &[Self::Symbol] {
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+struct Node<Sym> {
+    children: Vec<(Sym, usize)>,
+    value: Option<usize>,
+}
+
+impl<Sym> Default for Node<Sym> {
+    fn default() -> Self {
+        Self {
+            children: vec![],
+            value: None,
+        }
+    }
+}
+
+// A trie over the literals' symbols, bytes for text. Matching always takes
+// the longest literal, no matter the order the literals were given in.
+#[derive(Clone, Debug)]
+pub struct Literals<Sym, Val> {
+    nodes: Vec<Node<Sym>>,
+    values: Vec<Val>,
+}
+
+pub fn one_of_literals<Lit, Sym, Itr>(literals: Itr) -> Literals<Sym, usize>
+where
+    Itr: IntoIterator<Item = Lit>,
+    Lit: Deref,
+    Lit::Target: Symbols<Symbol = Sym>,
+    Sym: Ord + Clone,
+{
+    literal_map(
+        literals
+            .into_iter()
+            .enumerate()
+            .map(|(index, lit)| (lit, index)),
+    )
+}
+
+// Later duplicates replace the value of earlier ones.
+pub fn literal_map<Lit, Sym, Val, Itr>(literals: Itr) -> Literals<Sym, Val>
+where
+    Itr: IntoIterator<Item = (Lit, Val)>,
+    Lit: Deref,
+    Lit::Target: Symbols<Symbol = Sym>,
+    Sym: Ord + Clone,
+{
+    let mut trie: Literals<Sym, Val> = Literals {
+        nodes: vec![Node::default()],
+        values: vec![],
+    };
+
+    for (literal, value) in literals {
+        let mut node = 0;
+        for symbol in literal.symbols() {
+            node = match trie.nodes[node]
+                .children
+                .binary_search_by(|(other, _)| other.cmp(symbol))
+            {
+                Ok(index) => trie.nodes[node].children[index].1,
+                Err(index) => {
+                    let child = trie.nodes.len();
+                    trie.nodes[node]
+                        .children
+                        .insert(index, (symbol.clone(), child));
+                    trie.nodes.push(Node::default());
+                    child
+                }
+            };
+        }
+
+        match trie.nodes[node].value {
+            Some(index) => trie.values[index] = value,
+            None => {
+                trie.nodes[node].value = Some(trie.values.len());
+                trie.values.push(value);
+            }
+        }
+    }
+    trie
+}
+
+impl<Sym, Val> Literals<Sym, Val>
+where
+    Sym: Ord,
+{
+    // Length of the longest literal `symbols` starts with, and its value.
+    pub fn longest_match(&self, symbols: &[Sym]) -> Option<(usize, &Val)> {
+        let mut node = 0;
+        let mut found = self.nodes[0].value.map(|value| (0, value));
+        for (len, symbol) in symbols.iter().enumerate() {
+            let children = &self.nodes[node].children;
+            node = match children.binary_search_by(|(other, _)| other.cmp(symbol)) {
+                Ok(index) => children[index].1,
+                Err(_) => break,
+            };
+            if let Some(value) = self.nodes[node].value {
+                found = Some((len + 1, value));
+            }
+        }
+        found.map(|(len, value)| (len, &self.values[value]))
+    }
+}
+
+impl<'src, Str, Src, Sym, Val> Parser<Str> for Literals<Sym, Val>
+where
+    Str: Borrowed<Source = &'src Src>,
+    Src: Symbols<Symbol = Sym> + ?Sized + 'src,
+    Sym: Ord,
+    Val: Clone,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let source = input.source().symbols();
+        let (len, value) = self.longest_match(&source[start..])?;
+        input.advance(len);
+        Some(value.clone())
+    }
+}
+
+impl<'src, Str, Src, Sym, Val> ParserMut<Str> for Literals<Sym, Val>
+where
+    Str: Borrowed<Source = &'src Src>,
+    Src: Symbols<Symbol = Sym> + ?Sized + 'src,
+    Sym: Ord,
+    Val: Clone,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str, Src, Sym, Val> ParserOnce<Str> for Literals<Sym, Val>
+where
+    Str: Borrowed<Source = &'src Src>,
+    Src: Symbols<Symbol = Sym> + ?Sized + 'src,
+    Sym: Ord,
+    Val: Clone,
+{
+    type Output = Option<Val>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+// A string matches itself, one character at a time.
+impl<Str> Parser<Str> for &str
+where
+    Str: Stream<Item = char>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let checkpoint = input.checkpoint();
+        for ch in self.chars() {
+            if next_if(input, |item| *item == ch).is_none() {
+                input.rewind(checkpoint);
+                return None;
+            }
+        }
+        Some(*self)
+    }
+}
+
+impl<Str> ParserMut<Str> for &str
+where
+    Str: Stream<Item = char>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'lit, Str> ParserOnce<Str> for &'lit str
+where
+    Str: Stream<Item = char>,
+{
+    type Output = Option<&'lit str>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
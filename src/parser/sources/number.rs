@@ -0,0 +1,413 @@
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use super::satisfy::next_if;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Checkpoint, Stream};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NumberSyntax {
+    pub plus_sign: bool,
+    pub minus_sign: bool,
+    // `0x`, `0o` and `0b`, for integers only.
+    pub radix_prefixes: bool,
+    // C style octal, where any integer with a leading `0` is in base 8.
+    pub octal_leading_zero: bool,
+    pub leading_zeros: bool,
+    pub separator: Option<char>,
+    pub leading_dot: bool,
+    pub trailing_dot: bool,
+    pub exponent: bool,
+}
+
+impl NumberSyntax {
+    pub const RUST: Self = Self {
+        plus_sign: false,
+        minus_sign: true,
+        radix_prefixes: true,
+        octal_leading_zero: false,
+        leading_zeros: true,
+        separator: Some('_'),
+        leading_dot: false,
+        trailing_dot: true,
+        exponent: true,
+    };
+
+    pub const JSON: Self = Self {
+        plus_sign: false,
+        minus_sign: true,
+        radix_prefixes: false,
+        octal_leading_zero: false,
+        leading_zeros: false,
+        separator: None,
+        leading_dot: false,
+        trailing_dot: false,
+        exponent: true,
+    };
+
+    pub const C: Self = Self {
+        plus_sign: true,
+        minus_sign: true,
+        radix_prefixes: true,
+        octal_leading_zero: true,
+        leading_zeros: true,
+        separator: None,
+        leading_dot: true,
+        trailing_dot: true,
+        exponent: true,
+    };
+}
+
+impl Default for NumberSyntax {
+    fn default() -> Self {
+        Self::RUST
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum NumberErrorKind {
+    Missing,
+    InvalidDigit,
+    LeadingZero,
+    EmptyExponent,
+    Overflow,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberError<Spn> {
+    pub kind: NumberErrorKind,
+    pub span: Spn,
+}
+
+impl<Spn> Display for NumberError<Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            NumberErrorKind::Missing => "expected a number",
+            NumberErrorKind::InvalidDigit => "invalid digit for the number's radix",
+            NumberErrorKind::LeadingZero => "leading zeros are not allowed",
+            NumberErrorKind::EmptyExponent => "expected exponent digits",
+            NumberErrorKind::Overflow => "number does not fit the target type",
+        })
+    }
+}
+
+pub trait PrimInt: Copy {
+    const ZERO: Self;
+
+    fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self>;
+}
+
+macro_rules! prim_int {
+    ($($ty:ty),*) => {$(
+        impl PrimInt for $ty {
+            const ZERO: Self = 0;
+
+            #[inline(always)]
+            fn push_digit(self, radix: u32, digit: u32, negative: bool) -> Option<Self> {
+                let value = self.checked_mul(radix as Self)?;
+                match negative {
+                    true => value.checked_sub(digit as Self),
+                    false => value.checked_add(digit as Self),
+                }
+            }
+        }
+    )*};
+}
+
+prim_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+pub trait PrimFloat: Copy + FromStr {
+    fn is_finite(self) -> bool;
+}
+
+impl PrimFloat for f32 {
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+}
+
+impl PrimFloat for f64 {
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+}
+
+pub struct IntegerLiteral<T> {
+    syntax: NumberSyntax,
+    _marker: PhantomData<T>,
+}
+
+pub fn integer<T>() -> IntegerLiteral<T>
+where
+    T: PrimInt,
+{
+    IntegerLiteral {
+        syntax: NumberSyntax::default(),
+        _marker: PhantomData,
+    }
+}
+
+impl<T> IntegerLiteral<T> {
+    pub fn syntax(self, syntax: NumberSyntax) -> Self {
+        Self { syntax, ..self }
+    }
+}
+
+pub struct FloatLiteral<T> {
+    syntax: NumberSyntax,
+    _marker: PhantomData<T>,
+}
+
+pub fn float<T>() -> FloatLiteral<T>
+where
+    T: PrimFloat,
+{
+    FloatLiteral {
+        syntax: NumberSyntax::default(),
+        _marker: PhantomData,
+    }
+}
+
+impl<T> FloatLiteral<T> {
+    pub fn syntax(self, syntax: NumberSyntax) -> Self {
+        Self { syntax, ..self }
+    }
+}
+
+fn peek_at<Str>(input: &mut Str, offset: usize) -> Option<char>
+where
+    Str: Stream<Item = char, Offset = usize>,
+{
+    input.nth(offset)
+}
+
+fn fail<Str, T>(
+    input: &mut Str,
+    start: Checkpoint<usize>,
+    kind: NumberErrorKind,
+    span: (usize, usize),
+) -> Result<T, NumberError<Str::Span>>
+where
+    Str: Stream<Item = char, Offset = usize>,
+{
+    let span = input.span(span.0, span.1);
+    input.rewind(start);
+    Err(NumberError { kind, span })
+}
+
+fn sign<Str>(input: &mut Str, syntax: &NumberSyntax) -> bool
+where
+    Str: Stream<Item = char, Offset = usize>,
+{
+    next_if(input, |ch| {
+        (*ch == '+' && syntax.plus_sign) || (*ch == '-' && syntax.minus_sign)
+    }) == Some('-')
+}
+
+// Consumes digits and separators, calling `f` with the value of each digit.
+// Returns the number of digits seen.
+fn digits<Str, Fun>(input: &mut Str, radix: u32, separator: Option<char>, mut f: Fun) -> usize
+where
+    Str: Stream<Item = char, Offset = usize>,
+    Fun: FnMut(char, u32),
+{
+    let mut count = 0;
+    while let Some(ch) = input.nth(input.offset()) {
+        match ch.to_digit(radix) {
+            Some(digit) => {
+                f(ch, digit);
+                count += 1;
+            }
+            None if count > 0 && Some(ch) == separator => {}
+            None => break,
+        }
+        input.skip();
+    }
+    count
+}
+
+fn invalid_digits<Str>(input: &mut Str) -> Option<(usize, usize)>
+where
+    Str: Stream<Item = char, Offset = usize>,
+{
+    let start = input.offset();
+    while next_if(input, char::is_ascii_digit).is_some() {}
+    match input.offset() {
+        end if end > start => Some((start, end)),
+        _ => None,
+    }
+}
+
+impl<Str, T> Parser<Str> for IntegerLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimInt,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let syntax = &self.syntax;
+        let start = input.checkpoint();
+        let negative = sign(input, syntax);
+
+        let offset = input.offset();
+        let (radix, prefixed) = match (peek_at(input, offset), peek_at(input, offset + 1)) {
+            (Some('0'), Some('x' | 'X')) if syntax.radix_prefixes => (16, true),
+            (Some('0'), Some('o' | 'O')) if syntax.radix_prefixes => (8, true),
+            (Some('0'), Some('b' | 'B')) if syntax.radix_prefixes => (2, true),
+            (Some('0'), Some('0'..='9')) if syntax.octal_leading_zero => (8, false),
+            _ => (10, false),
+        };
+        if prefixed {
+            input.advance(2);
+            if let Some(separator) = syntax.separator {
+                while next_if(input, |ch| *ch == separator).is_some() {}
+            }
+        }
+
+        let digits_start = input.offset();
+        let mut value = Some(T::ZERO);
+        let mut first = None;
+        let count = digits(input, radix, syntax.separator, |ch, digit| {
+            first.get_or_insert(ch);
+            value = value.and_then(|value| value.push_digit(radix, digit, negative));
+        });
+        let digits_end = input.offset();
+
+        if count == 0 {
+            let span = (start.offset, digits_end);
+            return fail(input, start, NumberErrorKind::Missing, span);
+        }
+        if radix < 10 {
+            if let Some(span) = invalid_digits(input) {
+                return fail(input, start, NumberErrorKind::InvalidDigit, span);
+            }
+        }
+        if !syntax.leading_zeros && radix == 10 && first == Some('0') && count > 1 {
+            let span = (digits_start, digits_end);
+            return fail(input, start, NumberErrorKind::LeadingZero, span);
+        }
+
+        match value {
+            Some(value) => Ok(value),
+            None => fail(
+                input,
+                start,
+                NumberErrorKind::Overflow,
+                (digits_start, digits_end),
+            ),
+        }
+    }
+}
+
+impl<Str, T> ParserMut<Str> for IntegerLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimInt,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str, T> ParserOnce<Str> for IntegerLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimInt,
+{
+    type Output = Result<T, NumberError<Str::Span>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str, T> Parser<Str> for FloatLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimFloat,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let syntax = &self.syntax;
+        let start = input.checkpoint();
+        let mut text = String::new();
+        if sign(input, syntax) {
+            text.push('-');
+        }
+
+        let digits_start = input.offset();
+        let mut first = None;
+        let integral = digits(input, 10, syntax.separator, |ch, _| {
+            first.get_or_insert(ch);
+            text.push(ch);
+        });
+        let integral_end = input.offset();
+
+        let offset = input.offset();
+        let fractional = match (peek_at(input, offset), peek_at(input, offset + 1)) {
+            (Some('.'), Some('0'..='9')) if integral > 0 || syntax.leading_dot => {
+                input.skip();
+                text.push('.');
+                digits(input, 10, syntax.separator, |ch, _| text.push(ch))
+            }
+            (Some('.'), next) if integral > 0 && syntax.trailing_dot => {
+                // `1..2` and `1.max(2)` are left alone.
+                if !matches!(next, Some(ch) if ch == '.' || ch == '_' || ch.is_alphabetic()) {
+                    input.skip();
+                }
+                0
+            }
+            _ => 0,
+        };
+
+        if integral == 0 && fractional == 0 {
+            let end = input.offset();
+            return fail(input, start, NumberErrorKind::Missing, (start.offset, end));
+        }
+        if !syntax.leading_zeros && first == Some('0') && integral > 1 {
+            let span = (digits_start, integral_end);
+            return fail(input, start, NumberErrorKind::LeadingZero, span);
+        }
+
+        if syntax.exponent && next_if(input, |ch| matches!(ch, 'e' | 'E')).is_some() {
+            let exponent_start = input.offset() - 1;
+            text.push('e');
+            if let Some(sign) = next_if(input, |ch| matches!(ch, '+' | '-')) {
+                text.push(sign);
+            }
+            if digits(input, 10, syntax.separator, |ch, _| text.push(ch)) == 0 {
+                let span = (exponent_start, input.offset());
+                return fail(input, start, NumberErrorKind::EmptyExponent, span);
+            }
+        }
+
+        match text.parse::<T>() {
+            Ok(value) if value.is_finite() => Ok(value),
+            _ => {
+                let end = input.offset();
+                fail(input, start, NumberErrorKind::Overflow, (digits_start, end))
+            }
+        }
+    }
+}
+
+impl<Str, T> ParserMut<Str> for FloatLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimFloat,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str, T> ParserOnce<Str> for FloatLiteral<T>
+where
+    Str: Stream<Item = char, Offset = usize>,
+    T: PrimFloat,
+{
+    type Output = Result<T, NumberError<Str::Span>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
@@ -0,0 +1,821 @@
+use std::fmt::{self, Display};
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use super::charset::CharSet;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+// Supported syntax: literals, `.`, classes (`[a-z_]`, `[^...]`), the escapes
+// `\d \w \s` and their negations (ASCII digits and word characters, Unicode
+// white space), `\n \r \t \xHH \x{...} \u{...}`, groups (`(...)`, `(?:...)`,
+// `(?<name>...)`, `(?P<name>...)`), alternation, the greedy and lazy
+// quantifiers `* + ? {n} {n,} {n,m}`, and the assertions `^ $ \A \z \b \B`.
+//
+// Patterns compile to an NFA that is simulated in lockstep (a Pike VM), so
+// matching is linear in the input and takes leftmost-first semantics, as in
+// backtracking engines.
+
+const MAX_REPEAT: u32 = 1000;
+const MAX_INSTS: usize = 1 << 16;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegexErrorKind {
+    UnexpectedEnd,
+    UnclosedGroup,
+    UnopenedGroup,
+    UnclosedClass,
+    InvalidClassRange,
+    InvalidEscape,
+    InvalidGroup,
+    DuplicateGroupName,
+    NothingToRepeat,
+    InvalidRepetition,
+    TooLarge,
+}
+
+// `span` is a byte range into the pattern.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RegexError {
+    pub kind: RegexErrorKind,
+    pub span: Range<usize>,
+}
+
+impl Display for RegexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            RegexErrorKind::UnexpectedEnd => "unexpected end of pattern",
+            RegexErrorKind::UnclosedGroup => "unclosed group",
+            RegexErrorKind::UnopenedGroup => "unopened group",
+            RegexErrorKind::UnclosedClass => "unclosed character class",
+            RegexErrorKind::InvalidClassRange => "invalid character class range",
+            RegexErrorKind::InvalidEscape => "invalid escape sequence",
+            RegexErrorKind::InvalidGroup => "invalid group syntax",
+            RegexErrorKind::DuplicateGroupName => "duplicate capture group name",
+            RegexErrorKind::NothingToRepeat => "repetition operator has nothing to repeat",
+            RegexErrorKind::InvalidRepetition => "invalid repetition count",
+            RegexErrorKind::TooLarge => "compiled pattern is too large",
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Assertion {
+    Start,
+    End,
+    WordBoundary,
+    NotWordBoundary,
+}
+
+impl Assertion {
+    fn holds(self, text: &str, at: usize) -> bool {
+        let word =
+            |ch: Option<char>| matches!(ch, Some(ch) if ch.is_ascii_alphanumeric() || ch == '_');
+        let boundary = || word(text[..at].chars().next_back()) != word(text[at..].chars().next());
+        match self {
+            Assertion::Start => at == 0,
+            Assertion::End => at == text.len(),
+            Assertion::WordBoundary => boundary(),
+            Assertion::NotWordBoundary => !boundary(),
+        }
+    }
+}
+
+enum Node {
+    Class(CharSet),
+    Assert(Assertion),
+    Capture(usize, Box<Node>),
+    Concat(Vec<Node>),
+    Alternate(Vec<Node>),
+    Repeat {
+        node: Box<Node>,
+        min: u32,
+        max: Option<u32>,
+        greedy: bool,
+    },
+}
+
+fn word_chars() -> CharSet {
+    CharSet::ALNUM.with('_')
+}
+
+fn single(set: &CharSet) -> Option<char> {
+    match set.ranges() {
+        [(lo, hi)] if lo == hi => Some(*lo),
+        _ => None,
+    }
+}
+
+struct Syntax<'p> {
+    pattern: &'p str,
+    index: usize,
+    captures: usize,
+    names: Vec<(String, usize)>,
+}
+
+impl Syntax<'_> {
+    fn peek(&self) -> Option<char> {
+        self.pattern[self.index..].chars().next()
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let ch = self.peek()?;
+        self.index += ch.len_utf8();
+        Some(ch)
+    }
+
+    fn eat(&mut self, ch: char) -> bool {
+        match self.peek() == Some(ch) {
+            true => self.bump().is_some(),
+            false => false,
+        }
+    }
+
+    fn error<T>(&self, kind: RegexErrorKind, start: usize) -> Result<T, RegexError> {
+        Err(RegexError {
+            kind,
+            span: start..self.index,
+        })
+    }
+
+    fn pattern(&mut self) -> Result<Node, RegexError> {
+        let node = self.alternation()?;
+        match self.peek() {
+            Some(_) => {
+                let start = self.index;
+                self.bump();
+                self.error(RegexErrorKind::UnopenedGroup, start)
+            }
+            None => Ok(node),
+        }
+    }
+
+    fn alternation(&mut self) -> Result<Node, RegexError> {
+        let mut branches = vec![self.concat()?];
+        while self.eat('|') {
+            branches.push(self.concat()?);
+        }
+        Ok(match branches.len() {
+            1 => branches.swap_remove(0),
+            _ => Node::Alternate(branches),
+        })
+    }
+
+    fn concat(&mut self) -> Result<Node, RegexError> {
+        let mut nodes = vec![];
+        while !matches!(self.peek(), None | Some('|' | ')')) {
+            let atom = self.atom()?;
+            nodes.push(self.repetition(atom)?);
+        }
+        Ok(Node::Concat(nodes))
+    }
+
+    fn atom(&mut self) -> Result<Node, RegexError> {
+        let start = self.index;
+        match self.bump() {
+            Some('(') => self.group(start),
+            Some('[') => self.class(start).map(Node::Class),
+            Some('.') => Ok(Node::Class(CharSet::from('\n').negate())),
+            Some('^') => Ok(Node::Assert(Assertion::Start)),
+            Some('$') => Ok(Node::Assert(Assertion::End)),
+            Some('\\') => self.escape(start),
+            Some('*' | '+' | '?' | '{') => self.error(RegexErrorKind::NothingToRepeat, start),
+            Some(ch) => Ok(Node::Class(CharSet::from(ch))),
+            None => self.error(RegexErrorKind::UnexpectedEnd, start),
+        }
+    }
+
+    fn group(&mut self, start: usize) -> Result<Node, RegexError> {
+        let capture = match self.eat('?') {
+            true if self.eat(':') => None,
+            true => {
+                self.eat('P');
+                if !self.eat('<') {
+                    return self.error(RegexErrorKind::InvalidGroup, start);
+                }
+                let name_start = self.index;
+                let rest = &self.pattern[name_start..];
+                let len = rest
+                    .find(|ch: char| !ch.is_ascii_alphanumeric() && ch != '_')
+                    .unwrap_or(rest.len());
+                let name = &rest[..len];
+                self.index += len;
+                if name.is_empty() || name.starts_with(|ch: char| ch.is_ascii_digit()) {
+                    return self.error(RegexErrorKind::InvalidGroup, start);
+                }
+                if self.names.iter().any(|(other, _)| other == name) {
+                    return self.error(RegexErrorKind::DuplicateGroupName, name_start);
+                }
+                if !self.eat('>') {
+                    return self.error(RegexErrorKind::InvalidGroup, start);
+                }
+                self.captures += 1;
+                self.names.push((name.to_owned(), self.captures));
+                Some(self.captures)
+            }
+            false => {
+                self.captures += 1;
+                Some(self.captures)
+            }
+        };
+
+        let node = self.alternation()?;
+        if !self.eat(')') {
+            return Err(RegexError {
+                kind: RegexErrorKind::UnclosedGroup,
+                span: start..start + 1,
+            });
+        }
+        Ok(match capture {
+            Some(index) => Node::Capture(index, Box::new(node)),
+            None => node,
+        })
+    }
+
+    fn repetition(&mut self, node: Node) -> Result<Node, RegexError> {
+        let start = self.index;
+        let (min, max) = match self.peek() {
+            Some('*') => (0, None),
+            Some('+') => (1, None),
+            Some('?') => (0, Some(1)),
+            Some('{') => {
+                self.bump();
+                self.counts(start)?
+            }
+            _ => return Ok(node),
+        };
+        if self.index == start {
+            self.bump();
+        }
+        let greedy = !self.eat('?');
+
+        if matches!(self.peek(), Some('*' | '+' | '?' | '{')) {
+            let start = self.index;
+            self.bump();
+            return self.error(RegexErrorKind::NothingToRepeat, start);
+        }
+        Ok(Node::Repeat {
+            node: Box::new(node),
+            min,
+            max,
+            greedy,
+        })
+    }
+
+    // Called right after the opening brace.
+    fn counts(&mut self, start: usize) -> Result<(u32, Option<u32>), RegexError> {
+        let min = self.count();
+        let max = match self.eat(',') {
+            true => self.count(),
+            false => min,
+        };
+        if !self.eat('}') {
+            return self.error(RegexErrorKind::InvalidRepetition, start);
+        }
+        match (min, max) {
+            (Some(min), _) if min > MAX_REPEAT => self.error(RegexErrorKind::TooLarge, start),
+            (_, Some(max)) if max > MAX_REPEAT => self.error(RegexErrorKind::TooLarge, start),
+            (Some(min), Some(max)) if min > max => {
+                self.error(RegexErrorKind::InvalidRepetition, start)
+            }
+            (Some(min), max) => Ok((min, max)),
+            (None, _) => self.error(RegexErrorKind::InvalidRepetition, start),
+        }
+    }
+
+    fn count(&mut self) -> Option<u32> {
+        let rest = &self.pattern[self.index..];
+        let len = rest
+            .find(|ch: char| !ch.is_ascii_digit())
+            .unwrap_or(rest.len());
+        self.index += len;
+        match len {
+            0 => None,
+            _ => Some(rest[..len].parse().unwrap_or(u32::MAX)),
+        }
+    }
+
+    // Called right after the backslash.
+    fn escape(&mut self, start: usize) -> Result<Node, RegexError> {
+        let class = |set: CharSet| Ok(Node::Class(set));
+        match self.bump() {
+            None => self.error(RegexErrorKind::UnexpectedEnd, start),
+            Some('d') => class(CharSet::DIGIT),
+            Some('D') => class(CharSet::DIGIT.negate()),
+            Some('w') => class(word_chars()),
+            Some('W') => class(word_chars().negate()),
+            Some('s') => class(CharSet::WHITESPACE),
+            Some('S') => class(CharSet::WHITESPACE.negate()),
+            Some('n') => class(CharSet::from('\n')),
+            Some('r') => class(CharSet::from('\r')),
+            Some('t') => class(CharSet::from('\t')),
+            Some('x') if !self.pattern[self.index..].starts_with('{') => {
+                let digits = self.pattern[self.index..]
+                    .get(..2)
+                    .filter(|digits| !digits.starts_with('+'));
+                match digits.and_then(|digits| u8::from_str_radix(digits, 16).ok()) {
+                    Some(code) => {
+                        self.index += 2;
+                        class(CharSet::from(char::from(code)))
+                    }
+                    None => self.error(RegexErrorKind::InvalidEscape, start),
+                }
+            }
+            Some('x' | 'u') => {
+                let rest = &self.pattern[self.index..];
+                let code = rest
+                    .strip_prefix('{')
+                    .and_then(|rest| rest.split_once('}'))
+                    .map(|(digits, _)| digits);
+                let ch = code
+                    .filter(|digits| !digits.starts_with('+'))
+                    .and_then(|digits| u32::from_str_radix(digits, 16).ok())
+                    .and_then(char::from_u32);
+                match (code, ch) {
+                    (Some(code), Some(ch)) => {
+                        self.index += code.len() + 2;
+                        class(CharSet::from(ch))
+                    }
+                    _ => self.error(RegexErrorKind::InvalidEscape, start),
+                }
+            }
+            Some('A') => Ok(Node::Assert(Assertion::Start)),
+            Some('z') => Ok(Node::Assert(Assertion::End)),
+            Some('b') => Ok(Node::Assert(Assertion::WordBoundary)),
+            Some('B') => Ok(Node::Assert(Assertion::NotWordBoundary)),
+            Some(ch) if ch.is_ascii_punctuation() || ch == ' ' => class(CharSet::from(ch)),
+            Some(_) => self.error(RegexErrorKind::InvalidEscape, start),
+        }
+    }
+
+    // Called right after the opening bracket. A `]` right after the opening
+    // bracket or the negation is taken literally.
+    fn class(&mut self, start: usize) -> Result<CharSet, RegexError> {
+        let negated = self.eat('^');
+        let mut set = CharSet::EMPTY;
+        let mut first = true;
+        loop {
+            let item = self.index;
+            let lo = match self.class_item(start)? {
+                None if !first => break,
+                None => CharSet::from(']'),
+                Some(lo) => lo,
+            };
+            first = false;
+
+            let range = self.pattern[self.index..]
+                .strip_prefix('-')
+                .is_some_and(|rest| !rest.is_empty() && !rest.starts_with(']'));
+            if !range {
+                set = set.union(&lo);
+                continue;
+            }
+            self.bump();
+            let hi = self.class_item(start)?.unwrap_or(CharSet::from(']'));
+            match (single(&lo), single(&hi)) {
+                (Some(lo), Some(hi)) if lo <= hi => set = set.with(lo..=hi),
+                _ => return self.error(RegexErrorKind::InvalidClassRange, item),
+            }
+        }
+        Ok(match negated {
+            true => set.negate(),
+            false => set,
+        })
+    }
+
+    // Returns `None` on the closing bracket.
+    fn class_item(&mut self, start: usize) -> Result<Option<CharSet>, RegexError> {
+        let item = self.index;
+        match self.bump() {
+            Some(']') => Ok(None),
+            Some('\\') => match self.escape(item)? {
+                Node::Class(set) => Ok(Some(set)),
+                _ => self.error(RegexErrorKind::InvalidEscape, item),
+            },
+            Some(ch) => Ok(Some(CharSet::from(ch))),
+            None => Err(RegexError {
+                kind: RegexErrorKind::UnclosedClass,
+                span: start..start + 1,
+            }),
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Inst {
+    Char(char),
+    Class(CharSet),
+    Assert(Assertion),
+    Save(usize),
+    Split(usize, usize),
+    Jump(usize),
+    Match,
+}
+
+struct Compiler {
+    insts: Vec<Inst>,
+}
+
+impl Compiler {
+    fn program(node: &Node) -> Result<Vec<Inst>, RegexErrorKind> {
+        let mut compiler = Compiler { insts: vec![] };
+        compiler.emit(Inst::Save(0))?;
+        compiler.compile(node)?;
+        compiler.emit(Inst::Save(1))?;
+        compiler.emit(Inst::Match)?;
+        Ok(compiler.insts)
+    }
+
+    fn emit(&mut self, inst: Inst) -> Result<usize, RegexErrorKind> {
+        if self.insts.len() >= MAX_INSTS {
+            return Err(RegexErrorKind::TooLarge);
+        }
+        self.insts.push(inst);
+        Ok(self.insts.len() - 1)
+    }
+
+    fn split(&mut self, at: usize, next: usize, skip: usize, greedy: bool) {
+        self.insts[at] = match greedy {
+            true => Inst::Split(next, skip),
+            false => Inst::Split(skip, next),
+        };
+    }
+
+    fn compile(&mut self, node: &Node) -> Result<(), RegexErrorKind> {
+        match node {
+            Node::Class(set) => {
+                self.emit(match single(set) {
+                    Some(ch) => Inst::Char(ch),
+                    None => Inst::Class(set.clone()),
+                })?;
+            }
+            Node::Assert(assertion) => {
+                self.emit(Inst::Assert(*assertion))?;
+            }
+            Node::Capture(index, node) => {
+                self.emit(Inst::Save(2 * index))?;
+                self.compile(node)?;
+                self.emit(Inst::Save(2 * index + 1))?;
+            }
+            Node::Concat(nodes) => {
+                for node in nodes {
+                    self.compile(node)?;
+                }
+            }
+            Node::Alternate(branches) => {
+                let (last, branches) = branches.split_last().expect("alternation of one branch");
+                let mut jumps = vec![];
+                for branch in branches {
+                    let split = self.emit(Inst::Split(0, 0))?;
+                    self.compile(branch)?;
+                    jumps.push(self.emit(Inst::Jump(0))?);
+                    let next = self.insts.len();
+                    self.split(split, split + 1, next, true);
+                }
+                self.compile(last)?;
+                let end = self.insts.len();
+                for jump in jumps {
+                    self.insts[jump] = Inst::Jump(end);
+                }
+            }
+            Node::Repeat {
+                node,
+                min,
+                max,
+                greedy,
+            } => {
+                for _ in 0..*min {
+                    self.compile(node)?;
+                }
+                match max {
+                    None => {
+                        let split = self.emit(Inst::Split(0, 0))?;
+                        self.compile(node)?;
+                        self.emit(Inst::Jump(split))?;
+                        let end = self.insts.len();
+                        self.split(split, split + 1, end, *greedy);
+                    }
+                    Some(max) => {
+                        let mut splits = vec![];
+                        for _ in *min..*max {
+                            splits.push(self.emit(Inst::Split(0, 0))?);
+                            self.compile(node)?;
+                        }
+                        let end = self.insts.len();
+                        for split in splits {
+                            self.split(split, split + 1, end, *greedy);
+                        }
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// The threads alive at one position, in priority order, each with its own
+// capture slots.
+#[derive(Debug)]
+struct Threads {
+    dense: Vec<usize>,
+    sparse: Vec<usize>,
+    slots: Vec<Option<usize>>,
+    width: usize,
+}
+
+impl Threads {
+    fn new(len: usize, width: usize) -> Self {
+        Self {
+            dense: Vec::with_capacity(len),
+            sparse: vec![0; len],
+            slots: vec![None; len * width],
+            width,
+        }
+    }
+
+    fn contains(&self, pc: usize) -> bool {
+        let index = self.sparse[pc];
+        index < self.dense.len() && self.dense[index] == pc
+    }
+
+    fn insert(&mut self, pc: usize) {
+        self.sparse[pc] = self.dense.len();
+        self.dense.push(pc);
+    }
+
+    fn slots(&mut self, pc: usize) -> &mut [Option<usize>] {
+        &mut self.slots[pc * self.width..(pc + 1) * self.width]
+    }
+}
+
+#[derive(Debug)]
+enum Frame {
+    Explore(usize),
+    Restore(usize, Option<usize>),
+}
+
+// Buffers for one search, kept between calls so that matching allocates only
+// the first time.
+#[derive(Debug)]
+struct Cache {
+    current: Threads,
+    next: Threads,
+    scratch: Vec<Option<usize>>,
+    stack: Vec<Frame>,
+}
+
+impl Cache {
+    fn new(len: usize, width: usize) -> Self {
+        Self {
+            current: Threads::new(len, width),
+            next: Threads::new(len, width),
+            scratch: vec![None; width],
+            stack: vec![],
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Regex {
+    pattern: String,
+    insts: Vec<Inst>,
+    names: Arc<[(String, usize)]>,
+    captures: usize,
+    // Searches that find the cache in use, e.g. on another thread, allocate
+    // their own buffers instead of waiting.
+    cache: Mutex<Option<Cache>>,
+}
+
+impl Clone for Regex {
+    fn clone(&self) -> Self {
+        Self {
+            pattern: self.pattern.clone(),
+            insts: self.insts.clone(),
+            names: self.names.clone(),
+            captures: self.captures,
+            cache: Mutex::new(None),
+        }
+    }
+}
+
+pub fn regex(pattern: &str) -> Result<Regex, RegexError> {
+    Regex::new(pattern)
+}
+
+impl Regex {
+    pub fn new(pattern: &str) -> Result<Self, RegexError> {
+        let mut syntax = Syntax {
+            pattern,
+            index: 0,
+            captures: 0,
+            names: vec![],
+        };
+        let node = syntax.pattern()?;
+
+        let insts = Compiler::program(&node).map_err(|kind| RegexError {
+            kind,
+            span: 0..pattern.len(),
+        })?;
+
+        Ok(Self {
+            pattern: pattern.to_owned(),
+            insts,
+            names: syntax.names.into(),
+            captures: syntax.captures + 1,
+            cache: Mutex::new(None),
+        })
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    // Number of capture groups, counting the implicit group of the whole
+    // match.
+    pub fn captures_len(&self) -> usize {
+        self.captures
+    }
+
+    pub fn group_index(&self, name: &str) -> Option<usize> {
+        self.names
+            .iter()
+            .find(|(other, _)| other == name)
+            .map(|(_, index)| *index)
+    }
+
+    // Capture slots of the match starting at `start`, if any. The whole of
+    // `text` is visible to the assertions.
+    fn exec(&self, text: &str, start: usize) -> Option<Vec<Option<usize>>> {
+        let cached = self
+            .cache
+            .try_lock()
+            .ok()
+            .and_then(|mut cache| cache.take());
+        let mut cache = cached.unwrap_or_else(|| Cache::new(self.insts.len(), 2 * self.captures));
+        let found = self.search(&mut cache, text, start);
+        if let Ok(mut slot) = self.cache.try_lock() {
+            *slot = Some(cache);
+        }
+        found
+    }
+
+    fn search(&self, cache: &mut Cache, text: &str, start: usize) -> Option<Vec<Option<usize>>> {
+        let Cache {
+            current,
+            next,
+            scratch,
+            stack,
+        } = cache;
+        current.dense.clear();
+        next.dense.clear();
+        scratch.fill(None);
+        stack.clear();
+        let mut found = None;
+
+        let mut at = start;
+        self.follow(current, stack, scratch, (0, text, at));
+        loop {
+            let ch = text[at..].chars().next();
+            for index in 0..current.dense.len() {
+                let pc = current.dense[index];
+                let step = match &self.insts[pc] {
+                    Inst::Char(expected) => ch == Some(*expected),
+                    Inst::Class(set) => matches!(ch, Some(ch) if set.contains(ch)),
+                    Inst::Match => {
+                        // Threads after this one have lower priority.
+                        found = Some(current.slots(pc).to_vec());
+                        break;
+                    }
+                    _ => false,
+                };
+                if let (true, Some(ch)) = (step, ch) {
+                    scratch.copy_from_slice(current.slots(pc));
+                    let position = (pc + 1, text, at + ch.len_utf8());
+                    self.follow(next, stack, scratch, position);
+                }
+            }
+
+            match ch {
+                Some(ch) if !next.dense.is_empty() => at += ch.len_utf8(),
+                _ => return found,
+            }
+            std::mem::swap(current, next);
+            next.dense.clear();
+        }
+    }
+
+    // Adds the thread at `pc` and every thread reachable from it without
+    // consuming input.
+    fn follow(
+        &self,
+        threads: &mut Threads,
+        stack: &mut Vec<Frame>,
+        scratch: &mut [Option<usize>],
+        (pc, text, at): (usize, &str, usize),
+    ) {
+        stack.push(Frame::Explore(pc));
+        while let Some(frame) = stack.pop() {
+            let pc = match frame {
+                Frame::Explore(pc) => pc,
+                Frame::Restore(slot, value) => {
+                    scratch[slot] = value;
+                    continue;
+                }
+            };
+            if threads.contains(pc) {
+                continue;
+            }
+            threads.insert(pc);
+
+            match &self.insts[pc] {
+                Inst::Jump(target) => stack.push(Frame::Explore(*target)),
+                Inst::Split(first, second) => {
+                    stack.push(Frame::Explore(*second));
+                    stack.push(Frame::Explore(*first));
+                }
+                Inst::Save(slot) => {
+                    stack.push(Frame::Restore(*slot, scratch[*slot]));
+                    scratch[*slot] = Some(at);
+                    stack.push(Frame::Explore(pc + 1));
+                }
+                Inst::Assert(assertion) => {
+                    if assertion.holds(text, at) {
+                        stack.push(Frame::Explore(pc + 1));
+                    }
+                }
+                Inst::Char(_) | Inst::Class(_) | Inst::Match => {
+                    threads.slots(pc).copy_from_slice(scratch);
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Captures<'src> {
+    groups: Vec<Option<&'src str>>,
+    names: Arc<[(String, usize)]>,
+}
+
+impl<'src> Captures<'src> {
+    pub fn as_str(&self) -> &'src str {
+        self.groups[0].unwrap_or_default()
+    }
+
+    // Group 0 is the whole match. Groups that did not take part in the match
+    // are `None`.
+    pub fn get(&self, index: usize) -> Option<&'src str> {
+        self.groups.get(index).copied().flatten()
+    }
+
+    pub fn name(&self, name: &str) -> Option<&'src str> {
+        let (_, index) = self.names.iter().find(|(other, _)| other == name)?;
+        self.get(*index)
+    }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = Option<&'src str>> + '_ {
+        self.groups.iter().copied()
+    }
+}
+
+impl<'src, Str> Parser<Str> for Regex
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        let slots = self.exec(input.source(), start)?;
+        let groups = slots
+            .chunks(2)
+            .map(|slots| match slots {
+                [Some(from), Some(to)] => Some(input.borrow_slice(*from, *to)),
+                _ => None,
+            })
+            .collect();
+        input.advance(slots[1]? - start);
+        Some(Captures {
+            groups,
+            names: self.names.clone(),
+        })
+    }
+}
+
+impl<'src, Str> ParserMut<Str> for Regex
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str> ParserOnce<Str> for Regex
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    type Output = Option<Captures<'src>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
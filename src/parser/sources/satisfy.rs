@@ -0,0 +1,115 @@
+use crate::data::prelude::*;
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+pub trait Predicate<T> {
+    fn test(&self, item: &T) -> bool;
+}
+
+impl<T, Fun> Predicate<T> for Fun
+where
+    Fun: Fn(&T) -> bool,
+{
+    #[inline(always)]
+    fn test(&self, item: &T) -> bool {
+        self(item)
+    }
+}
+
+#[inline]
+pub(crate) fn next_if<Str, Fun>(input: &mut Str, f: Fun) -> Option<Str::Item>
+where
+    Str: Stream,
+    Fun: FnOnce(&Str::Item) -> bool,
+{
+    let item = input.nth(input.offset())?;
+    match f(&item) {
+        true => {
+            input.skip();
+            Some(item)
+        }
+        false => None,
+    }
+}
+
+pub struct Satisfy<Pred> {
+    predicate: Pred,
+}
+
+pub fn satisfy<Pred>(predicate: Pred) -> Satisfy<Pred> {
+    Satisfy { predicate }
+}
+
+impl<Str, Pred> Parser<Str> for Satisfy<Pred>
+where
+    Str: Stream,
+    Pred: Predicate<Str::Item>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        next_if(input, |item| self.predicate.test(item))
+    }
+}
+
+impl<Str, Pred> ParserMut<Str> for Satisfy<Pred>
+where
+    Str: Stream,
+    Pred: Predicate<Str::Item>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str, Pred> ParserOnce<Str> for Satisfy<Pred>
+where
+    Str: Stream,
+    Pred: Predicate<Str::Item>,
+{
+    type Output = Option<Str::Item>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+pub struct TakeWhile<Pred> {
+    predicate: Pred,
+}
+
+pub fn take_while<Pred>(predicate: Pred) -> TakeWhile<Pred> {
+    TakeWhile { predicate }
+}
+
+impl<Str, Pred> Parser<Str> for TakeWhile<Pred>
+where
+    Str: Borrowed,
+    Pred: Predicate<Str::Item>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        while next_if(input, |item| self.predicate.test(item)).is_some() {}
+        Sure(input.borrow_slice(start, input.offset()))
+    }
+}
+
+impl<Str, Pred> ParserMut<Str> for TakeWhile<Pred>
+where
+    Str: Borrowed,
+    Pred: Predicate<Str::Item>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<Str, Pred> ParserOnce<Str> for TakeWhile<Pred>
+where
+    Str: Borrowed,
+    Pred: Predicate<Str::Item>,
+{
+    type Output = Sure<Str::Source>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
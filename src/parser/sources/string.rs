@@ -0,0 +1,320 @@
+use std::borrow::Cow;
+use std::fmt::{self, Display};
+
+use crate::parser::prelude::*;
+use crate::stream::traits::{Borrowed, Stream};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Escapes {
+    None,
+    Rust,
+    Json,
+    C,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StringSyntax {
+    pub quotes: &'static [char],
+    pub escapes: Escapes,
+    // Rust style raw strings, `r"..."` and `r#"..."#`.
+    pub raw: bool,
+    pub newlines: bool,
+}
+
+impl StringSyntax {
+    pub const RUST: Self = Self {
+        quotes: &['"'],
+        escapes: Escapes::Rust,
+        raw: true,
+        newlines: true,
+    };
+
+    pub const JSON: Self = Self {
+        quotes: &['"'],
+        escapes: Escapes::Json,
+        raw: false,
+        newlines: false,
+    };
+
+    pub const C: Self = Self {
+        quotes: &['"'],
+        escapes: Escapes::C,
+        raw: false,
+        newlines: false,
+    };
+}
+
+impl Default for StringSyntax {
+    fn default() -> Self {
+        Self::RUST
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StringErrorKind {
+    Missing,
+    Unterminated,
+    InvalidEscape,
+    InvalidCodePoint,
+    ControlCharacter,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct StringError<Spn> {
+    pub kind: StringErrorKind,
+    pub span: Spn,
+}
+
+impl<Spn> Display for StringError<Spn> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self.kind {
+            StringErrorKind::Missing => "expected a string literal",
+            StringErrorKind::Unterminated => "unterminated string literal",
+            StringErrorKind::InvalidEscape => "invalid escape sequence",
+            StringErrorKind::InvalidCodePoint => "escape sequence is not a valid code point",
+            StringErrorKind::ControlCharacter => "control characters must be escaped",
+        })
+    }
+}
+
+pub struct StringLiteral {
+    syntax: StringSyntax,
+}
+
+pub fn string_literal(syntax: StringSyntax) -> StringLiteral {
+    StringLiteral { syntax }
+}
+
+// Offsets of a failure relative to the start of the literal.
+type Failure = (StringErrorKind, usize, usize);
+
+impl StringLiteral {
+    fn scan<'src>(&self, text: &'src str) -> Result<(Cow<'src, str>, usize), Failure> {
+        if let Some(raw) = self.scan_raw(text) {
+            return raw;
+        }
+
+        let quote = match text.chars().next() {
+            Some(quote) if self.syntax.quotes.contains(&quote) => quote,
+            _ => return Err((StringErrorKind::Missing, 0, 0)),
+        };
+        let unterminated = (StringErrorKind::Unterminated, 0, quote.len_utf8());
+
+        let body = quote.len_utf8();
+        let mut owned: Option<String> = None;
+        let mut index = body;
+        while let Some(ch) = text[index..].chars().next() {
+            match ch {
+                ch if ch == quote => {
+                    let value = match owned {
+                        Some(owned) => Cow::Owned(owned),
+                        None => Cow::Borrowed(&text[body..index]),
+                    };
+                    return Ok((value, index + ch.len_utf8()));
+                }
+                '\\' if self.syntax.escapes != Escapes::None => {
+                    let (escaped, len) = escape(self.syntax.escapes, &text[index + 1..])
+                        .map_err(|(kind, len)| (kind, index, index + 1 + len))?;
+                    let owned = owned.get_or_insert_with(|| text[body..index].to_owned());
+                    owned.extend(escaped);
+                    index += 1 + len;
+                    continue;
+                }
+                // JSON forbids every raw control character, newlines included.
+                ch if ch < ' ' && self.syntax.escapes == Escapes::Json => {
+                    return Err((StringErrorKind::ControlCharacter, index, index + 1))
+                }
+                '\n' if !self.syntax.newlines => return Err(unterminated),
+                ch => {
+                    if let Some(owned) = &mut owned {
+                        owned.push(ch);
+                    }
+                }
+            }
+            index += ch.len_utf8();
+        }
+        Err(unterminated)
+    }
+
+    fn scan_raw<'src>(&self, text: &'src str) -> Option<Result<(Cow<'src, str>, usize), Failure>> {
+        if !self.syntax.raw {
+            return None;
+        }
+
+        let hashes = text
+            .strip_prefix('r')?
+            .bytes()
+            .take_while(|b| *b == b'#')
+            .count();
+        let body = 1 + hashes + 1;
+        if !text[1 + hashes..].starts_with('"') {
+            return None;
+        }
+
+        let closing = format!("\"{}", "#".repeat(hashes));
+        Some(match text[body..].find(&closing) {
+            Some(len) => Ok((
+                Cow::Borrowed(&text[body..body + len]),
+                body + len + closing.len(),
+            )),
+            None => Err((StringErrorKind::Unterminated, 0, body)),
+        })
+    }
+}
+
+// `text` starts right after the backslash. Returns the escaped character, if
+// any, and the length of the escape sequence after the backslash.
+fn escape(escapes: Escapes, text: &str) -> Result<(Option<char>, usize), (StringErrorKind, usize)> {
+    let invalid = |len| Err((StringErrorKind::InvalidEscape, len));
+    let first = match text.chars().next() {
+        Some(first) => first,
+        None => return invalid(0),
+    };
+
+    let simple = match (escapes, first) {
+        (_, '\\' | '"') => Some(first),
+        (Escapes::Rust | Escapes::C, '\'') => Some('\''),
+        (_, 'n') => Some('\n'),
+        (_, 'r') => Some('\r'),
+        (_, 't') => Some('\t'),
+        (Escapes::Rust, '0') => Some('\0'),
+        (Escapes::Json, '/') => Some('/'),
+        (Escapes::Json | Escapes::C, 'b') => Some('\u{8}'),
+        (Escapes::Json | Escapes::C, 'f') => Some('\u{c}'),
+        (Escapes::C, 'a') => Some('\u{7}'),
+        (Escapes::C, 'v') => Some('\u{b}'),
+        (Escapes::C, '?') => Some('?'),
+        _ => None,
+    };
+    if let Some(ch) = simple {
+        return Ok((Some(ch), 1));
+    }
+
+    match (escapes, first) {
+        (Escapes::Rust, '\n') => {
+            let len = text[1..]
+                .find(|ch: char| !ch.is_whitespace())
+                .unwrap_or(text.len() - 1);
+            Ok((None, 1 + len))
+        }
+        (Escapes::Rust, 'x') => match hex(&text[1..], 2, 2) {
+            Some((code @ 0..=0x7F, len)) => Ok((char::from_u32(code), 1 + len)),
+            Some((_, len)) => Err((StringErrorKind::InvalidCodePoint, 1 + len)),
+            None => invalid(1),
+        },
+        (Escapes::Rust, 'u') => {
+            let digits = match text[1..].strip_prefix('{') {
+                Some(digits) => digits,
+                None => return invalid(1),
+            };
+            let (code, len) = match hex(digits, 1, 6) {
+                Some(hex) => hex,
+                None => return invalid(2),
+            };
+            match digits[len..].starts_with('}') {
+                true => code_point(code, 2 + len + 1),
+                false => invalid(2 + len),
+            }
+        }
+        (Escapes::Json, 'u') => {
+            let high = match hex(&text[1..], 4, 4) {
+                Some((code, _)) => code,
+                None => return invalid(1),
+            };
+            match high {
+                0xD800..=0xDBFF => {
+                    let low = text[5..]
+                        .strip_prefix("\\u")
+                        .and_then(|digits| hex(digits, 4, 4));
+                    match low {
+                        Some((low @ 0xDC00..=0xDFFF, _)) => {
+                            code_point(0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00), 11)
+                        }
+                        _ => Err((StringErrorKind::InvalidCodePoint, 5)),
+                    }
+                }
+                code => code_point(code, 5),
+            }
+        }
+        (Escapes::C, 'x') => match hex(&text[1..], 1, 8) {
+            Some((code @ 0..=0xFF, len)) => Ok((char::from_u32(code), 1 + len)),
+            Some((_, len)) => Err((StringErrorKind::InvalidCodePoint, 1 + len)),
+            None => invalid(1),
+        },
+        (Escapes::C, '0'..='7') => {
+            let len = text
+                .bytes()
+                .take(3)
+                .take_while(|b| (b'0'..=b'7').contains(b))
+                .count();
+            let code = u32::from_str_radix(&text[..len], 8).unwrap_or(0);
+            code_point(code, len)
+        }
+        (Escapes::C, 'u' | 'U') => {
+            let digits = if first == 'u' { 4 } else { 8 };
+            match hex(&text[1..], digits, digits) {
+                Some((code, len)) => code_point(code, 1 + len),
+                None => invalid(1),
+            }
+        }
+        _ => invalid(first.len_utf8()),
+    }
+}
+
+fn hex(text: &str, min: usize, max: usize) -> Option<(u32, usize)> {
+    let len = text
+        .bytes()
+        .take(max)
+        .take_while(u8::is_ascii_hexdigit)
+        .count();
+    match len >= min {
+        true => Some((u32::from_str_radix(&text[..len], 16).ok()?, len)),
+        false => None,
+    }
+}
+
+fn code_point(code: u32, len: usize) -> Result<(Option<char>, usize), (StringErrorKind, usize)> {
+    match char::from_u32(code) {
+        Some(ch) => Ok((Some(ch), len)),
+        None => Err((StringErrorKind::InvalidCodePoint, len)),
+    }
+}
+
+impl<'src, Str> Parser<Str> for StringLiteral
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream(&self, input: &mut Str) -> Self::Output {
+        let start = input.offset();
+        match self.scan(&input.source()[start..]) {
+            Ok((value, len)) => {
+                input.advance(len);
+                Ok(value)
+            }
+            Err((kind, from, to)) => Err(StringError {
+                kind,
+                span: input.span(start + from, start + to),
+            }),
+        }
+    }
+}
+
+impl<'src, Str> ParserMut<Str> for StringLiteral
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
+
+impl<'src, Str> ParserOnce<Str> for StringLiteral
+where
+    Str: Borrowed<Source = &'src str>,
+{
+    type Output = Result<Cow<'src, str>, StringError<Str::Span>>;
+
+    fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+        self.parse_stream(input)
+    }
+}
@@ -0,0 +1,3376 @@
+// Unicode 16.0.0 General_Category.
+// Generated by tools/ucd_tables.py from regex-syntax 0.8.11, do not edit by hand.
+// Unicode data is subject to the Unicode License v3,
+// see https://www.unicode.org/license.txt.
+
+use crate::parser::sources::unicode::GeneralCategory::{self, *};
+
+pub(crate) const GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[
+    ('\u{0}', '\u{1f}', Cc),
+    (' ', ' ', Zs),
+    ('!', '#', Po),
+    ('$', '$', Sc),
+    ('%', '\u{27}', Po),
+    ('(', '(', Ps),
+    (')', ')', Pe),
+    ('*', '*', Po),
+    ('+', '+', Sm),
+    (',', ',', Po),
+    ('-', '-', Pd),
+    ('.', '/', Po),
+    ('0', '9', Nd),
+    (':', ';', Po),
+    ('<', '>', Sm),
+    ('?', '@', Po),
+    ('A', 'Z', Lu),
+    ('[', '[', Ps),
+    ('\u{5c}', '\u{5c}', Po),
+    (']', ']', Pe),
+    ('^', '^', Sk),
+    ('_', '_', Pc),
+    ('`', '`', Sk),
+    ('a', 'z', Ll),
+    ('{', '{', Ps),
+    ('|', '|', Sm),
+    ('}', '}', Pe),
+    ('~', '~', Sm),
+    ('\u{7f}', '\u{9f}', Cc),
+    ('\u{a0}', '\u{a0}', Zs),
+    ('\u{a1}', '\u{a1}', Po),
+    ('\u{a2}', '\u{a5}', Sc),
+    ('\u{a6}', '\u{a6}', So),
+    ('\u{a7}', '\u{a7}', Po),
+    ('\u{a8}', '\u{a8}', Sk),
+    ('\u{a9}', '\u{a9}', So),
+    ('\u{aa}', '\u{aa}', Lo),
+    ('\u{ab}', '\u{ab}', Pi),
+    ('\u{ac}', '\u{ac}', Sm),
+    ('\u{ad}', '\u{ad}', Cf),
+    ('\u{ae}', '\u{ae}', So),
+    ('\u{af}', '\u{af}', Sk),
+    ('\u{b0}', '\u{b0}', So),
+    ('\u{b1}', '\u{b1}', Sm),
+    ('\u{b2}', '\u{b3}', No),
+    ('\u{b4}', '\u{b4}', Sk),
+    ('\u{b5}', '\u{b5}', Ll),
+    ('\u{b6}', '\u{b7}', Po),
+    ('\u{b8}', '\u{b8}', Sk),
+    ('\u{b9}', '\u{b9}', No),
+    ('\u{ba}', '\u{ba}', Lo),
+    ('\u{bb}', '\u{bb}', Pf),
+    ('\u{bc}', '\u{be}', No),
+    ('\u{bf}', '\u{bf}', Po),
+    ('\u{c0}', '\u{d6}', Lu),
+    ('\u{d7}', '\u{d7}', Sm),
+    ('\u{d8}', '\u{de}', Lu),
+    ('\u{df}', '\u{f6}', Ll),
+    ('\u{f7}', '\u{f7}', Sm),
+    ('\u{f8}', '\u{ff}', Ll),
+    ('\u{100}', '\u{100}', Lu),
+    ('\u{101}', '\u{101}', Ll),
+    ('\u{102}', '\u{102}', Lu),
+    ('\u{103}', '\u{103}', Ll),
+    ('\u{104}', '\u{104}', Lu),
+    ('\u{105}', '\u{105}', Ll),
+    ('\u{106}', '\u{106}', Lu),
+    ('\u{107}', '\u{107}', Ll),
+    ('\u{108}', '\u{108}', Lu),
+    ('\u{109}', '\u{109}', Ll),
+    ('\u{10a}', '\u{10a}', Lu),
+    ('\u{10b}', '\u{10b}', Ll),
+    ('\u{10c}', '\u{10c}', Lu),
+    ('\u{10d}', '\u{10d}', Ll),
+    ('\u{10e}', '\u{10e}', Lu),
+    ('\u{10f}', '\u{10f}', Ll),
+    ('\u{110}', '\u{110}', Lu),
+    ('\u{111}', '\u{111}', Ll),
+    ('\u{112}', '\u{112}', Lu),
+    ('\u{113}', '\u{113}', Ll),
+    ('\u{114}', '\u{114}', Lu),
+    ('\u{115}', '\u{115}', Ll),
+    ('\u{116}', '\u{116}', Lu),
+    ('\u{117}', '\u{117}', Ll),
+    ('\u{118}', '\u{118}', Lu),
+    ('\u{119}', '\u{119}', Ll),
+    ('\u{11a}', '\u{11a}', Lu),
+    ('\u{11b}', '\u{11b}', Ll),
+    ('\u{11c}', '\u{11c}', Lu),
+    ('\u{11d}', '\u{11d}', Ll),
+    ('\u{11e}', '\u{11e}', Lu),
+    ('\u{11f}', '\u{11f}', Ll),
+    ('\u{120}', '\u{120}', Lu),
+    ('\u{121}', '\u{121}', Ll),
+    ('\u{122}', '\u{122}', Lu),
+    ('\u{123}', '\u{123}', Ll),
+    ('\u{124}', '\u{124}', Lu),
+    ('\u{125}', '\u{125}', Ll),
+    ('\u{126}', '\u{126}', Lu),
+    ('\u{127}', '\u{127}', Ll),
+    ('\u{128}', '\u{128}', Lu),
+    ('\u{129}', '\u{129}', Ll),
+    ('\u{12a}', '\u{12a}', Lu),
+    ('\u{12b}', '\u{12b}', Ll),
+    ('\u{12c}', '\u{12c}', Lu),
+    ('\u{12d}', '\u{12d}', Ll),
+    ('\u{12e}', '\u{12e}', Lu),
+    ('\u{12f}', '\u{12f}', Ll),
+    ('\u{130}', '\u{130}', Lu),
+    ('\u{131}', '\u{131}', Ll),
+    ('\u{132}', '\u{132}', Lu),
+    ('\u{133}', '\u{133}', Ll),
+    ('\u{134}', '\u{134}', Lu),
+    ('\u{135}', '\u{135}', Ll),
+    ('\u{136}', '\u{136}', Lu),
+    ('\u{137}', '\u{138}', Ll),
+    ('\u{139}', '\u{139}', Lu),
+    ('\u{13a}', '\u{13a}', Ll),
+    ('\u{13b}', '\u{13b}', Lu),
+    ('\u{13c}', '\u{13c}', Ll),
+    ('\u{13d}', '\u{13d}', Lu),
+    ('\u{13e}', '\u{13e}', Ll),
+    ('\u{13f}', '\u{13f}', Lu),
+    ('\u{140}', '\u{140}', Ll),
+    ('\u{141}', '\u{141}', Lu),
+    ('\u{142}', '\u{142}', Ll),
+    ('\u{143}', '\u{143}', Lu),
+    ('\u{144}', '\u{144}', Ll),
+    ('\u{145}', '\u{145}', Lu),
+    ('\u{146}', '\u{146}', Ll),
+    ('\u{147}', '\u{147}', Lu),
+    ('\u{148}', '\u{149}', Ll),
+    ('\u{14a}', '\u{14a}', Lu),
+    ('\u{14b}', '\u{14b}', Ll),
+    ('\u{14c}', '\u{14c}', Lu),
+    ('\u{14d}', '\u{14d}', Ll),
+    ('\u{14e}', '\u{14e}', Lu),
+    ('\u{14f}', '\u{14f}', Ll),
+    ('\u{150}', '\u{150}', Lu),
+    ('\u{151}', '\u{151}', Ll),
+    ('\u{152}', '\u{152}', Lu),
+    ('\u{153}', '\u{153}', Ll),
+    ('\u{154}', '\u{154}', Lu),
+    ('\u{155}', '\u{155}', Ll),
+    ('\u{156}', '\u{156}', Lu),
+    ('\u{157}', '\u{157}', Ll),
+    ('\u{158}', '\u{158}', Lu),
+    ('\u{159}', '\u{159}', Ll),
+    ('\u{15a}', '\u{15a}', Lu),
+    ('\u{15b}', '\u{15b}', Ll),
+    ('\u{15c}', '\u{15c}', Lu),
+    ('\u{15d}', '\u{15d}', Ll),
+    ('\u{15e}', '\u{15e}', Lu),
+    ('\u{15f}', '\u{15f}', Ll),
+    ('\u{160}', '\u{160}', Lu),
+    ('\u{161}', '\u{161}', Ll),
+    ('\u{162}', '\u{162}', Lu),
+    ('\u{163}', '\u{163}', Ll),
+    ('\u{164}', '\u{164}', Lu),
+    ('\u{165}', '\u{165}', Ll),
+    ('\u{166}', '\u{166}', Lu),
+    ('\u{167}', '\u{167}', Ll),
+    ('\u{168}', '\u{168}', Lu),
+    ('\u{169}', '\u{169}', Ll),
+    ('\u{16a}', '\u{16a}', Lu),
+    ('\u{16b}', '\u{16b}', Ll),
+    ('\u{16c}', '\u{16c}', Lu),
+    ('\u{16d}', '\u{16d}', Ll),
+    ('\u{16e}', '\u{16e}', Lu),
+    ('\u{16f}', '\u{16f}', Ll),
+    ('\u{170}', '\u{170}', Lu),
+    ('\u{171}', '\u{171}', Ll),
+    ('\u{172}', '\u{172}', Lu),
+    ('\u{173}', '\u{173}', Ll),
+    ('\u{174}', '\u{174}', Lu),
+    ('\u{175}', '\u{175}', Ll),
+    ('\u{176}', '\u{176}', Lu),
+    ('\u{177}', '\u{177}', Ll),
+    ('\u{178}', '\u{179}', Lu),
+    ('\u{17a}', '\u{17a}', Ll),
+    ('\u{17b}', '\u{17b}', Lu),
+    ('\u{17c}', '\u{17c}', Ll),
+    ('\u{17d}', '\u{17d}', Lu),
+    ('\u{17e}', '\u{180}', Ll),
+    ('\u{181}', '\u{182}', Lu),
+    ('\u{183}', '\u{183}', Ll),
+    ('\u{184}', '\u{184}', Lu),
+    ('\u{185}', '\u{185}', Ll),
+    ('\u{186}', '\u{187}', Lu),
+    ('\u{188}', '\u{188}', Ll),
+    ('\u{189}', '\u{18b}', Lu),
+    ('\u{18c}', '\u{18d}', Ll),
+    ('\u{18e}', '\u{191}', Lu),
+    ('\u{192}', '\u{192}', Ll),
+    ('\u{193}', '\u{194}', Lu),
+    ('\u{195}', '\u{195}', Ll),
+    ('\u{196}', '\u{198}', Lu),
+    ('\u{199}', '\u{19b}', Ll),
+    ('\u{19c}', '\u{19d}', Lu),
+    ('\u{19e}', '\u{19e}', Ll),
+    ('\u{19f}', '\u{1a0}', Lu),
+    ('\u{1a1}', '\u{1a1}', Ll),
+    ('\u{1a2}', '\u{1a2}', Lu),
+    ('\u{1a3}', '\u{1a3}', Ll),
+    ('\u{1a4}', '\u{1a4}', Lu),
+    ('\u{1a5}', '\u{1a5}', Ll),
+    ('\u{1a6}', '\u{1a7}', Lu),
+    ('\u{1a8}', '\u{1a8}', Ll),
+    ('\u{1a9}', '\u{1a9}', Lu),
+    ('\u{1aa}', '\u{1ab}', Ll),
+    ('\u{1ac}', '\u{1ac}', Lu),
+    ('\u{1ad}', '\u{1ad}', Ll),
+    ('\u{1ae}', '\u{1af}', Lu),
+    ('\u{1b0}', '\u{1b0}', Ll),
+    ('\u{1b1}', '\u{1b3}', Lu),
+    ('\u{1b4}', '\u{1b4}', Ll),
+    ('\u{1b5}', '\u{1b5}', Lu),
+    ('\u{1b6}', '\u{1b6}', Ll),
+    ('\u{1b7}', '\u{1b8}', Lu),
+    ('\u{1b9}', '\u{1ba}', Ll),
+    ('\u{1bb}', '\u{1bb}', Lo),
+    ('\u{1bc}', '\u{1bc}', Lu),
+    ('\u{1bd}', '\u{1bf}', Ll),
+    ('\u{1c0}', '\u{1c3}', Lo),
+    ('\u{1c4}', '\u{1c4}', Lu),
+    ('\u{1c5}', '\u{1c5}', Lt),
+    ('\u{1c6}', '\u{1c6}', Ll),
+    ('\u{1c7}', '\u{1c7}', Lu),
+    ('\u{1c8}', '\u{1c8}', Lt),
+    ('\u{1c9}', '\u{1c9}', Ll),
+    ('\u{1ca}', '\u{1ca}', Lu),
+    ('\u{1cb}', '\u{1cb}', Lt),
+    ('\u{1cc}', '\u{1cc}', Ll),
+    ('\u{1cd}', '\u{1cd}', Lu),
+    ('\u{1ce}', '\u{1ce}', Ll),
+    ('\u{1cf}', '\u{1cf}', Lu),
+    ('\u{1d0}', '\u{1d0}', Ll),
+    ('\u{1d1}', '\u{1d1}', Lu),
+    ('\u{1d2}', '\u{1d2}', Ll),
+    ('\u{1d3}', '\u{1d3}', Lu),
+    ('\u{1d4}', '\u{1d4}', Ll),
+    ('\u{1d5}', '\u{1d5}', Lu),
+    ('\u{1d6}', '\u{1d6}', Ll),
+    ('\u{1d7}', '\u{1d7}', Lu),
+    ('\u{1d8}', '\u{1d8}', Ll),
+    ('\u{1d9}', '\u{1d9}', Lu),
+    ('\u{1da}', '\u{1da}', Ll),
+    ('\u{1db}', '\u{1db}', Lu),
+    ('\u{1dc}', '\u{1dd}', Ll),
+    ('\u{1de}', '\u{1de}', Lu),
+    ('\u{1df}', '\u{1df}', Ll),
+    ('\u{1e0}', '\u{1e0}', Lu),
+    ('\u{1e1}', '\u{1e1}', Ll),
+    ('\u{1e2}', '\u{1e2}', Lu),
+    ('\u{1e3}', '\u{1e3}', Ll),
+    ('\u{1e4}', '\u{1e4}', Lu),
+    ('\u{1e5}', '\u{1e5}', Ll),
+    ('\u{1e6}', '\u{1e6}', Lu),
+    ('\u{1e7}', '\u{1e7}', Ll),
+    ('\u{1e8}', '\u{1e8}', Lu),
+    ('\u{1e9}', '\u{1e9}', Ll),
+    ('\u{1ea}', '\u{1ea}', Lu),
+    ('\u{1eb}', '\u{1eb}', Ll),
+    ('\u{1ec}', '\u{1ec}', Lu),
+    ('\u{1ed}', '\u{1ed}', Ll),
+    ('\u{1ee}', '\u{1ee}', Lu),
+    ('\u{1ef}', '\u{1f0}', Ll),
+    ('\u{1f1}', '\u{1f1}', Lu),
+    ('\u{1f2}', '\u{1f2}', Lt),
+    ('\u{1f3}', '\u{1f3}', Ll),
+    ('\u{1f4}', '\u{1f4}', Lu),
+    ('\u{1f5}', '\u{1f5}', Ll),
+    ('\u{1f6}', '\u{1f8}', Lu),
+    ('\u{1f9}', '\u{1f9}', Ll),
+    ('\u{1fa}', '\u{1fa}', Lu),
+    ('\u{1fb}', '\u{1fb}', Ll),
+    ('\u{1fc}', '\u{1fc}', Lu),
+    ('\u{1fd}', '\u{1fd}', Ll),
+    ('\u{1fe}', '\u{1fe}', Lu),
+    ('\u{1ff}', '\u{1ff}', Ll),
+    ('\u{200}', '\u{200}', Lu),
+    ('\u{201}', '\u{201}', Ll),
+    ('\u{202}', '\u{202}', Lu),
+    ('\u{203}', '\u{203}', Ll),
+    ('\u{204}', '\u{204}', Lu),
+    ('\u{205}', '\u{205}', Ll),
+    ('\u{206}', '\u{206}', Lu),
+    ('\u{207}', '\u{207}', Ll),
+    ('\u{208}', '\u{208}', Lu),
+    ('\u{209}', '\u{209}', Ll),
+    ('\u{20a}', '\u{20a}', Lu),
+    ('\u{20b}', '\u{20b}', Ll),
+    ('\u{20c}', '\u{20c}', Lu),
+    ('\u{20d}', '\u{20d}', Ll),
+    ('\u{20e}', '\u{20e}', Lu),
+    ('\u{20f}', '\u{20f}', Ll),
+    ('\u{210}', '\u{210}', Lu),
+    ('\u{211}', '\u{211}', Ll),
+    ('\u{212}', '\u{212}', Lu),
+    ('\u{213}', '\u{213}', Ll),
+    ('\u{214}', '\u{214}', Lu),
+    ('\u{215}', '\u{215}', Ll),
+    ('\u{216}', '\u{216}', Lu),
+    ('\u{217}', '\u{217}', Ll),
+    ('\u{218}', '\u{218}', Lu),
+    ('\u{219}', '\u{219}', Ll),
+    ('\u{21a}', '\u{21a}', Lu),
+    ('\u{21b}', '\u{21b}', Ll),
+    ('\u{21c}', '\u{21c}', Lu),
+    ('\u{21d}', '\u{21d}', Ll),
+    ('\u{21e}', '\u{21e}', Lu),
+    ('\u{21f}', '\u{21f}', Ll),
+    ('\u{220}', '\u{220}', Lu),
+    ('\u{221}', '\u{221}', Ll),
+    ('\u{222}', '\u{222}', Lu),
+    ('\u{223}', '\u{223}', Ll),
+    ('\u{224}', '\u{224}', Lu),
+    ('\u{225}', '\u{225}', Ll),
+    ('\u{226}', '\u{226}', Lu),
+    ('\u{227}', '\u{227}', Ll),
+    ('\u{228}', '\u{228}', Lu),
+    ('\u{229}', '\u{229}', Ll),
+    ('\u{22a}', '\u{22a}', Lu),
+    ('\u{22b}', '\u{22b}', Ll),
+    ('\u{22c}', '\u{22c}', Lu),
+    ('\u{22d}', '\u{22d}', Ll),
+    ('\u{22e}', '\u{22e}', Lu),
+    ('\u{22f}', '\u{22f}', Ll),
+    ('\u{230}', '\u{230}', Lu),
+    ('\u{231}', '\u{231}', Ll),
+    ('\u{232}', '\u{232}', Lu),
+    ('\u{233}', '\u{239}', Ll),
+    ('\u{23a}', '\u{23b}', Lu),
+    ('\u{23c}', '\u{23c}', Ll),
+    ('\u{23d}', '\u{23e}', Lu),
+    ('\u{23f}', '\u{240}', Ll),
+    ('\u{241}', '\u{241}', Lu),
+    ('\u{242}', '\u{242}', Ll),
+    ('\u{243}', '\u{246}', Lu),
+    ('\u{247}', '\u{247}', Ll),
+    ('\u{248}', '\u{248}', Lu),
+    ('\u{249}', '\u{249}', Ll),
+    ('\u{24a}', '\u{24a}', Lu),
+    ('\u{24b}', '\u{24b}', Ll),
+    ('\u{24c}', '\u{24c}', Lu),
+    ('\u{24d}', '\u{24d}', Ll),
+    ('\u{24e}', '\u{24e}', Lu),
+    ('\u{24f}', '\u{293}', Ll),
+    ('\u{294}', '\u{294}', Lo),
+    ('\u{295}', '\u{2af}', Ll),
+    ('\u{2b0}', '\u{2c1}', Lm),
+    ('\u{2c2}', '\u{2c5}', Sk),
+    ('\u{2c6}', '\u{2d1}', Lm),
+    ('\u{2d2}', '\u{2df}', Sk),
+    ('\u{2e0}', '\u{2e4}', Lm),
+    ('\u{2e5}', '\u{2eb}', Sk),
+    ('\u{2ec}', '\u{2ec}', Lm),
+    ('\u{2ed}', '\u{2ed}', Sk),
+    ('\u{2ee}', '\u{2ee}', Lm),
+    ('\u{2ef}', '\u{2ff}', Sk),
+    ('\u{300}', '\u{36f}', Mn),
+    ('\u{370}', '\u{370}', Lu),
+    ('\u{371}', '\u{371}', Ll),
+    ('\u{372}', '\u{372}', Lu),
+    ('\u{373}', '\u{373}', Ll),
+    ('\u{374}', '\u{374}', Lm),
+    ('\u{375}', '\u{375}', Sk),
+    ('\u{376}', '\u{376}', Lu),
+    ('\u{377}', '\u{377}', Ll),
+    ('\u{37a}', '\u{37a}', Lm),
+    ('\u{37b}', '\u{37d}', Ll),
+    ('\u{37e}', '\u{37e}', Po),
+    ('\u{37f}', '\u{37f}', Lu),
+    ('\u{384}', '\u{385}', Sk),
+    ('\u{386}', '\u{386}', Lu),
+    ('\u{387}', '\u{387}', Po),
+    ('\u{388}', '\u{38a}', Lu),
+    ('\u{38c}', '\u{38c}', Lu),
+    ('\u{38e}', '\u{38f}', Lu),
+    ('\u{390}', '\u{390}', Ll),
+    ('\u{391}', '\u{3a1}', Lu),
+    ('\u{3a3}', '\u{3ab}', Lu),
+    ('\u{3ac}', '\u{3ce}', Ll),
+    ('\u{3cf}', '\u{3cf}', Lu),
+    ('\u{3d0}', '\u{3d1}', Ll),
+    ('\u{3d2}', '\u{3d4}', Lu),
+    ('\u{3d5}', '\u{3d7}', Ll),
+    ('\u{3d8}', '\u{3d8}', Lu),
+    ('\u{3d9}', '\u{3d9}', Ll),
+    ('\u{3da}', '\u{3da}', Lu),
+    ('\u{3db}', '\u{3db}', Ll),
+    ('\u{3dc}', '\u{3dc}', Lu),
+    ('\u{3dd}', '\u{3dd}', Ll),
+    ('\u{3de}', '\u{3de}', Lu),
+    ('\u{3df}', '\u{3df}', Ll),
+    ('\u{3e0}', '\u{3e0}', Lu),
+    ('\u{3e1}', '\u{3e1}', Ll),
+    ('\u{3e2}', '\u{3e2}', Lu),
+    ('\u{3e3}', '\u{3e3}', Ll),
+    ('\u{3e4}', '\u{3e4}', Lu),
+    ('\u{3e5}', '\u{3e5}', Ll),
+    ('\u{3e6}', '\u{3e6}', Lu),
+    ('\u{3e7}', '\u{3e7}', Ll),
+    ('\u{3e8}', '\u{3e8}', Lu),
+    ('\u{3e9}', '\u{3e9}', Ll),
+    ('\u{3ea}', '\u{3ea}', Lu),
+    ('\u{3eb}', '\u{3eb}', Ll),
+    ('\u{3ec}', '\u{3ec}', Lu),
+    ('\u{3ed}', '\u{3ed}', Ll),
+    ('\u{3ee}', '\u{3ee}', Lu),
+    ('\u{3ef}', '\u{3f3}', Ll),
+    ('\u{3f4}', '\u{3f4}', Lu),
+    ('\u{3f5}', '\u{3f5}', Ll),
+    ('\u{3f6}', '\u{3f6}', Sm),
+    ('\u{3f7}', '\u{3f7}', Lu),
+    ('\u{3f8}', '\u{3f8}', Ll),
+    ('\u{3f9}', '\u{3fa}', Lu),
+    ('\u{3fb}', '\u{3fc}', Ll),
+    ('\u{3fd}', '\u{42f}', Lu),
+    ('\u{430}', '\u{45f}', Ll),
+    ('\u{460}', '\u{460}', Lu),
+    ('\u{461}', '\u{461}', Ll),
+    ('\u{462}', '\u{462}', Lu),
+    ('\u{463}', '\u{463}', Ll),
+    ('\u{464}', '\u{464}', Lu),
+    ('\u{465}', '\u{465}', Ll),
+    ('\u{466}', '\u{466}', Lu),
+    ('\u{467}', '\u{467}', Ll),
+    ('\u{468}', '\u{468}', Lu),
+    ('\u{469}', '\u{469}', Ll),
+    ('\u{46a}', '\u{46a}', Lu),
+    ('\u{46b}', '\u{46b}', Ll),
+    ('\u{46c}', '\u{46c}', Lu),
+    ('\u{46d}', '\u{46d}', Ll),
+    ('\u{46e}', '\u{46e}', Lu),
+    ('\u{46f}', '\u{46f}', Ll),
+    ('\u{470}', '\u{470}', Lu),
+    ('\u{471}', '\u{471}', Ll),
+    ('\u{472}', '\u{472}', Lu),
+    ('\u{473}', '\u{473}', Ll),
+    ('\u{474}', '\u{474}', Lu),
+    ('\u{475}', '\u{475}', Ll),
+    ('\u{476}', '\u{476}', Lu),
+    ('\u{477}', '\u{477}', Ll),
+    ('\u{478}', '\u{478}', Lu),
+    ('\u{479}', '\u{479}', Ll),
+    ('\u{47a}', '\u{47a}', Lu),
+    ('\u{47b}', '\u{47b}', Ll),
+    ('\u{47c}', '\u{47c}', Lu),
+    ('\u{47d}', '\u{47d}', Ll),
+    ('\u{47e}', '\u{47e}', Lu),
+    ('\u{47f}', '\u{47f}', Ll),
+    ('\u{480}', '\u{480}', Lu),
+    ('\u{481}', '\u{481}', Ll),
+    ('\u{482}', '\u{482}', So),
+    ('\u{483}', '\u{487}', Mn),
+    ('\u{488}', '\u{489}', Me),
+    ('\u{48a}', '\u{48a}', Lu),
+    ('\u{48b}', '\u{48b}', Ll),
+    ('\u{48c}', '\u{48c}', Lu),
+    ('\u{48d}', '\u{48d}', Ll),
+    ('\u{48e}', '\u{48e}', Lu),
+    ('\u{48f}', '\u{48f}', Ll),
+    ('\u{490}', '\u{490}', Lu),
+    ('\u{491}', '\u{491}', Ll),
+    ('\u{492}', '\u{492}', Lu),
+    ('\u{493}', '\u{493}', Ll),
+    ('\u{494}', '\u{494}', Lu),
+    ('\u{495}', '\u{495}', Ll),
+    ('\u{496}', '\u{496}', Lu),
+    ('\u{497}', '\u{497}', Ll),
+    ('\u{498}', '\u{498}', Lu),
+    ('\u{499}', '\u{499}', Ll),
+    ('\u{49a}', '\u{49a}', Lu),
+    ('\u{49b}', '\u{49b}', Ll),
+    ('\u{49c}', '\u{49c}', Lu),
+    ('\u{49d}', '\u{49d}', Ll),
+    ('\u{49e}', '\u{49e}', Lu),
+    ('\u{49f}', '\u{49f}', Ll),
+    ('\u{4a0}', '\u{4a0}', Lu),
+    ('\u{4a1}', '\u{4a1}', Ll),
+    ('\u{4a2}', '\u{4a2}', Lu),
+    ('\u{4a3}', '\u{4a3}', Ll),
+    ('\u{4a4}', '\u{4a4}', Lu),
+    ('\u{4a5}', '\u{4a5}', Ll),
+    ('\u{4a6}', '\u{4a6}', Lu),
+    ('\u{4a7}', '\u{4a7}', Ll),
+    ('\u{4a8}', '\u{4a8}', Lu),
+    ('\u{4a9}', '\u{4a9}', Ll),
+    ('\u{4aa}', '\u{4aa}', Lu),
+    ('\u{4ab}', '\u{4ab}', Ll),
+    ('\u{4ac}', '\u{4ac}', Lu),
+    ('\u{4ad}', '\u{4ad}', Ll),
+    ('\u{4ae}', '\u{4ae}', Lu),
+    ('\u{4af}', '\u{4af}', Ll),
+    ('\u{4b0}', '\u{4b0}', Lu),
+    ('\u{4b1}', '\u{4b1}', Ll),
+    ('\u{4b2}', '\u{4b2}', Lu),
+    ('\u{4b3}', '\u{4b3}', Ll),
+    ('\u{4b4}', '\u{4b4}', Lu),
+    ('\u{4b5}', '\u{4b5}', Ll),
+    ('\u{4b6}', '\u{4b6}', Lu),
+    ('\u{4b7}', '\u{4b7}', Ll),
+    ('\u{4b8}', '\u{4b8}', Lu),
+    ('\u{4b9}', '\u{4b9}', Ll),
+    ('\u{4ba}', '\u{4ba}', Lu),
+    ('\u{4bb}', '\u{4bb}', Ll),
+    ('\u{4bc}', '\u{4bc}', Lu),
+    ('\u{4bd}', '\u{4bd}', Ll),
+    ('\u{4be}', '\u{4be}', Lu),
+    ('\u{4bf}', '\u{4bf}', Ll),
+    ('\u{4c0}', '\u{4c1}', Lu),
+    ('\u{4c2}', '\u{4c2}', Ll),
+    ('\u{4c3}', '\u{4c3}', Lu),
+    ('\u{4c4}', '\u{4c4}', Ll),
+    ('\u{4c5}', '\u{4c5}', Lu),
+    ('\u{4c6}', '\u{4c6}', Ll),
+    ('\u{4c7}', '\u{4c7}', Lu),
+    ('\u{4c8}', '\u{4c8}', Ll),
+    ('\u{4c9}', '\u{4c9}', Lu),
+    ('\u{4ca}', '\u{4ca}', Ll),
+    ('\u{4cb}', '\u{4cb}', Lu),
+    ('\u{4cc}', '\u{4cc}', Ll),
+    ('\u{4cd}', '\u{4cd}', Lu),
+    ('\u{4ce}', '\u{4cf}', Ll),
+    ('\u{4d0}', '\u{4d0}', Lu),
+    ('\u{4d1}', '\u{4d1}', Ll),
+    ('\u{4d2}', '\u{4d2}', Lu),
+    ('\u{4d3}', '\u{4d3}', Ll),
+    ('\u{4d4}', '\u{4d4}', Lu),
+    ('\u{4d5}', '\u{4d5}', Ll),
+    ('\u{4d6}', '\u{4d6}', Lu),
+    ('\u{4d7}', '\u{4d7}', Ll),
+    ('\u{4d8}', '\u{4d8}', Lu),
+    ('\u{4d9}', '\u{4d9}', Ll),
+    ('\u{4da}', '\u{4da}', Lu),
+    ('\u{4db}', '\u{4db}', Ll),
+    ('\u{4dc}', '\u{4dc}', Lu),
+    ('\u{4dd}', '\u{4dd}', Ll),
+    ('\u{4de}', '\u{4de}', Lu),
+    ('\u{4df}', '\u{4df}', Ll),
+    ('\u{4e0}', '\u{4e0}', Lu),
+    ('\u{4e1}', '\u{4e1}', Ll),
+    ('\u{4e2}', '\u{4e2}', Lu),
+    ('\u{4e3}', '\u{4e3}', Ll),
+    ('\u{4e4}', '\u{4e4}', Lu),
+    ('\u{4e5}', '\u{4e5}', Ll),
+    ('\u{4e6}', '\u{4e6}', Lu),
+    ('\u{4e7}', '\u{4e7}', Ll),
+    ('\u{4e8}', '\u{4e8}', Lu),
+    ('\u{4e9}', '\u{4e9}', Ll),
+    ('\u{4ea}', '\u{4ea}', Lu),
+    ('\u{4eb}', '\u{4eb}', Ll),
+    ('\u{4ec}', '\u{4ec}', Lu),
+    ('\u{4ed}', '\u{4ed}', Ll),
+    ('\u{4ee}', '\u{4ee}', Lu),
+    ('\u{4ef}', '\u{4ef}', Ll),
+    ('\u{4f0}', '\u{4f0}', Lu),
+    ('\u{4f1}', '\u{4f1}', Ll),
+    ('\u{4f2}', '\u{4f2}', Lu),
+    ('\u{4f3}', '\u{4f3}', Ll),
+    ('\u{4f4}', '\u{4f4}', Lu),
+    ('\u{4f5}', '\u{4f5}', Ll),
+    ('\u{4f6}', '\u{4f6}', Lu),
+    ('\u{4f7}', '\u{4f7}', Ll),
+    ('\u{4f8}', '\u{4f8}', Lu),
+    ('\u{4f9}', '\u{4f9}', Ll),
+    ('\u{4fa}', '\u{4fa}', Lu),
+    ('\u{4fb}', '\u{4fb}', Ll),
+    ('\u{4fc}', '\u{4fc}', Lu),
+    ('\u{4fd}', '\u{4fd}', Ll),
+    ('\u{4fe}', '\u{4fe}', Lu),
+    ('\u{4ff}', '\u{4ff}', Ll),
+    ('\u{500}', '\u{500}', Lu),
+    ('\u{501}', '\u{501}', Ll),
+    ('\u{502}', '\u{502}', Lu),
+    ('\u{503}', '\u{503}', Ll),
+    ('\u{504}', '\u{504}', Lu),
+    ('\u{505}', '\u{505}', Ll),
+    ('\u{506}', '\u{506}', Lu),
+    ('\u{507}', '\u{507}', Ll),
+    ('\u{508}', '\u{508}', Lu),
+    ('\u{509}', '\u{509}', Ll),
+    ('\u{50a}', '\u{50a}', Lu),
+    ('\u{50b}', '\u{50b}', Ll),
+    ('\u{50c}', '\u{50c}', Lu),
+    ('\u{50d}', '\u{50d}', Ll),
+    ('\u{50e}', '\u{50e}', Lu),
+    ('\u{50f}', '\u{50f}', Ll),
+    ('\u{510}', '\u{510}', Lu),
+    ('\u{511}', '\u{511}', Ll),
+    ('\u{512}', '\u{512}', Lu),
+    ('\u{513}', '\u{513}', Ll),
+    ('\u{514}', '\u{514}', Lu),
+    ('\u{515}', '\u{515}', Ll),
+    ('\u{516}', '\u{516}', Lu),
+    ('\u{517}', '\u{517}', Ll),
+    ('\u{518}', '\u{518}', Lu),
+    ('\u{519}', '\u{519}', Ll),
+    ('\u{51a}', '\u{51a}', Lu),
+    ('\u{51b}', '\u{51b}', Ll),
+    ('\u{51c}', '\u{51c}', Lu),
+    ('\u{51d}', '\u{51d}', Ll),
+    ('\u{51e}', '\u{51e}', Lu),
+    ('\u{51f}', '\u{51f}', Ll),
+    ('\u{520}', '\u{520}', Lu),
+    ('\u{521}', '\u{521}', Ll),
+    ('\u{522}', '\u{522}', Lu),
+    ('\u{523}', '\u{523}', Ll),
+    ('\u{524}', '\u{524}', Lu),
+    ('\u{525}', '\u{525}', Ll),
+    ('\u{526}', '\u{526}', Lu),
+    ('\u{527}', '\u{527}', Ll),
+    ('\u{528}', '\u{528}', Lu),
+    ('\u{529}', '\u{529}', Ll),
+    ('\u{52a}', '\u{52a}', Lu),
+    ('\u{52b}', '\u{52b}', Ll),
+    ('\u{52c}', '\u{52c}', Lu),
+    ('\u{52d}', '\u{52d}', Ll),
+    ('\u{52e}', '\u{52e}', Lu),
+    ('\u{52f}', '\u{52f}', Ll),
+    ('\u{531}', '\u{556}', Lu),
+    ('\u{559}', '\u{559}', Lm),
+    ('\u{55a}', '\u{55f}', Po),
+    ('\u{560}', '\u{588}', Ll),
+    ('\u{589}', '\u{589}', Po),
+    ('\u{58a}', '\u{58a}', Pd),
+    ('\u{58d}', '\u{58e}', So),
+    ('\u{58f}', '\u{58f}', Sc),
+    ('\u{591}', '\u{5bd}', Mn),
+    ('\u{5be}', '\u{5be}', Pd),
+    ('\u{5bf}', '\u{5bf}', Mn),
+    ('\u{5c0}', '\u{5c0}', Po),
+    ('\u{5c1}', '\u{5c2}', Mn),
+    ('\u{5c3}', '\u{5c3}', Po),
+    ('\u{5c4}', '\u{5c5}', Mn),
+    ('\u{5c6}', '\u{5c6}', Po),
+    ('\u{5c7}', '\u{5c7}', Mn),
+    ('\u{5d0}', '\u{5ea}', Lo),
+    ('\u{5ef}', '\u{5f2}', Lo),
+    ('\u{5f3}', '\u{5f4}', Po),
+    ('\u{600}', '\u{605}', Cf),
+    ('\u{606}', '\u{608}', Sm),
+    ('\u{609}', '\u{60a}', Po),
+    ('\u{60b}', '\u{60b}', Sc),
+    ('\u{60c}', '\u{60d}', Po),
+    ('\u{60e}', '\u{60f}', So),
+    ('\u{610}', '\u{61a}', Mn),
+    ('\u{61b}', '\u{61b}', Po),
+    ('\u{61c}', '\u{61c}', Cf),
+    ('\u{61d}', '\u{61f}', Po),
+    ('\u{620}', '\u{63f}', Lo),
+    ('\u{640}', '\u{640}', Lm),
+    ('\u{641}', '\u{64a}', Lo),
+    ('\u{64b}', '\u{65f}', Mn),
+    ('\u{660}', '\u{669}', Nd),
+    ('\u{66a}', '\u{66d}', Po),
+    ('\u{66e}', '\u{66f}', Lo),
+    ('\u{670}', '\u{670}', Mn),
+    ('\u{671}', '\u{6d3}', Lo),
+    ('\u{6d4}', '\u{6d4}', Po),
+    ('\u{6d5}', '\u{6d5}', Lo),
+    ('\u{6d6}', '\u{6dc}', Mn),
+    ('\u{6dd}', '\u{6dd}', Cf),
+    ('\u{6de}', '\u{6de}', So),
+    ('\u{6df}', '\u{6e4}', Mn),
+    ('\u{6e5}', '\u{6e6}', Lm),
+    ('\u{6e7}', '\u{6e8}', Mn),
+    ('\u{6e9}', '\u{6e9}', So),
+    ('\u{6ea}', '\u{6ed}', Mn),
+    ('\u{6ee}', '\u{6ef}', Lo),
+    ('\u{6f0}', '\u{6f9}', Nd),
+    ('\u{6fa}', '\u{6fc}', Lo),
+    ('\u{6fd}', '\u{6fe}', So),
+    ('\u{6ff}', '\u{6ff}', Lo),
+    ('\u{700}', '\u{70d}', Po),
+    ('\u{70f}', '\u{70f}', Cf),
+    ('\u{710}', '\u{710}', Lo),
+    ('\u{711}', '\u{711}', Mn),
+    ('\u{712}', '\u{72f}', Lo),
+    ('\u{730}', '\u{74a}', Mn),
+    ('\u{74d}', '\u{7a5}', Lo),
+    ('\u{7a6}', '\u{7b0}', Mn),
+    ('\u{7b1}', '\u{7b1}', Lo),
+    ('\u{7c0}', '\u{7c9}', Nd),
+    ('\u{7ca}', '\u{7ea}', Lo),
+    ('\u{7eb}', '\u{7f3}', Mn),
+    ('\u{7f4}', '\u{7f5}', Lm),
+    ('\u{7f6}', '\u{7f6}', So),
+    ('\u{7f7}', '\u{7f9}', Po),
+    ('\u{7fa}', '\u{7fa}', Lm),
+    ('\u{7fd}', '\u{7fd}', Mn),
+    ('\u{7fe}', '\u{7ff}', Sc),
+    ('\u{800}', '\u{815}', Lo),
+    ('\u{816}', '\u{819}', Mn),
+    ('\u{81a}', '\u{81a}', Lm),
+    ('\u{81b}', '\u{823}', Mn),
+    ('\u{824}', '\u{824}', Lm),
+    ('\u{825}', '\u{827}', Mn),
+    ('\u{828}', '\u{828}', Lm),
+    ('\u{829}', '\u{82d}', Mn),
+    ('\u{830}', '\u{83e}', Po),
+    ('\u{840}', '\u{858}', Lo),
+    ('\u{859}', '\u{85b}', Mn),
+    ('\u{85e}', '\u{85e}', Po),
+    ('\u{860}', '\u{86a}', Lo),
+    ('\u{870}', '\u{887}', Lo),
+    ('\u{888}', '\u{888}', Sk),
+    ('\u{889}', '\u{88e}', Lo),
+    ('\u{890}', '\u{891}', Cf),
+    ('\u{897}', '\u{89f}', Mn),
+    ('\u{8a0}', '\u{8c8}', Lo),
+    ('\u{8c9}', '\u{8c9}', Lm),
+    ('\u{8ca}', '\u{8e1}', Mn),
+    ('\u{8e2}', '\u{8e2}', Cf),
+    ('\u{8e3}', '\u{902}', Mn),
+    ('\u{903}', '\u{903}', Mc),
+    ('\u{904}', '\u{939}', Lo),
+    ('\u{93a}', '\u{93a}', Mn),
+    ('\u{93b}', '\u{93b}', Mc),
+    ('\u{93c}', '\u{93c}', Mn),
+    ('\u{93d}', '\u{93d}', Lo),
+    ('\u{93e}', '\u{940}', Mc),
+    ('\u{941}', '\u{948}', Mn),
+    ('\u{949}', '\u{94c}', Mc),
+    ('\u{94d}', '\u{94d}', Mn),
+    ('\u{94e}', '\u{94f}', Mc),
+    ('\u{950}', '\u{950}', Lo),
+    ('\u{951}', '\u{957}', Mn),
+    ('\u{958}', '\u{961}', Lo),
+    ('\u{962}', '\u{963}', Mn),
+    ('\u{964}', '\u{965}', Po),
+    ('\u{966}', '\u{96f}', Nd),
+    ('\u{970}', '\u{970}', Po),
+    ('\u{971}', '\u{971}', Lm),
+    ('\u{972}', '\u{980}', Lo),
+    ('\u{981}', '\u{981}', Mn),
+    ('\u{982}', '\u{983}', Mc),
+    ('\u{985}', '\u{98c}', Lo),
+    ('\u{98f}', '\u{990}', Lo),
+    ('\u{993}', '\u{9a8}', Lo),
+    ('\u{9aa}', '\u{9b0}', Lo),
+    ('\u{9b2}', '\u{9b2}', Lo),
+    ('\u{9b6}', '\u{9b9}', Lo),
+    ('\u{9bc}', '\u{9bc}', Mn),
+    ('\u{9bd}', '\u{9bd}', Lo),
+    ('\u{9be}', '\u{9c0}', Mc),
+    ('\u{9c1}', '\u{9c4}', Mn),
+    ('\u{9c7}', '\u{9c8}', Mc),
+    ('\u{9cb}', '\u{9cc}', Mc),
+    ('\u{9cd}', '\u{9cd}', Mn),
+    ('\u{9ce}', '\u{9ce}', Lo),
+    ('\u{9d7}', '\u{9d7}', Mc),
+    ('\u{9dc}', '\u{9dd}', Lo),
+    ('\u{9df}', '\u{9e1}', Lo),
+    ('\u{9e2}', '\u{9e3}', Mn),
+    ('\u{9e6}', '\u{9ef}', Nd),
+    ('\u{9f0}', '\u{9f1}', Lo),
+    ('\u{9f2}', '\u{9f3}', Sc),
+    ('\u{9f4}', '\u{9f9}', No),
+    ('\u{9fa}', '\u{9fa}', So),
+    ('\u{9fb}', '\u{9fb}', Sc),
+    ('\u{9fc}', '\u{9fc}', Lo),
+    ('\u{9fd}', '\u{9fd}', Po),
+    ('\u{9fe}', '\u{9fe}', Mn),
+    ('\u{a01}', '\u{a02}', Mn),
+    ('\u{a03}', '\u{a03}', Mc),
+    ('\u{a05}', '\u{a0a}', Lo),
+    ('\u{a0f}', '\u{a10}', Lo),
+    ('\u{a13}', '\u{a28}', Lo),
+    ('\u{a2a}', '\u{a30}', Lo),
+    ('\u{a32}', '\u{a33}', Lo),
+    ('\u{a35}', '\u{a36}', Lo),
+    ('\u{a38}', '\u{a39}', Lo),
+    ('\u{a3c}', '\u{a3c}', Mn),
+    ('\u{a3e}', '\u{a40}', Mc),
+    ('\u{a41}', '\u{a42}', Mn),
+    ('\u{a47}', '\u{a48}', Mn),
+    ('\u{a4b}', '\u{a4d}', Mn),
+    ('\u{a51}', '\u{a51}', Mn),
+    ('\u{a59}', '\u{a5c}', Lo),
+    ('\u{a5e}', '\u{a5e}', Lo),
+    ('\u{a66}', '\u{a6f}', Nd),
+    ('\u{a70}', '\u{a71}', Mn),
+    ('\u{a72}', '\u{a74}', Lo),
+    ('\u{a75}', '\u{a75}', Mn),
+    ('\u{a76}', '\u{a76}', Po),
+    ('\u{a81}', '\u{a82}', Mn),
+    ('\u{a83}', '\u{a83}', Mc),
+    ('\u{a85}', '\u{a8d}', Lo),
+    ('\u{a8f}', '\u{a91}', Lo),
+    ('\u{a93}', '\u{aa8}', Lo),
+    ('\u{aaa}', '\u{ab0}', Lo),
+    ('\u{ab2}', '\u{ab3}', Lo),
+    ('\u{ab5}', '\u{ab9}', Lo),
+    ('\u{abc}', '\u{abc}', Mn),
+    ('\u{abd}', '\u{abd}', Lo),
+    ('\u{abe}', '\u{ac0}', Mc),
+    ('\u{ac1}', '\u{ac5}', Mn),
+    ('\u{ac7}', '\u{ac8}', Mn),
+    ('\u{ac9}', '\u{ac9}', Mc),
+    ('\u{acb}', '\u{acc}', Mc),
+    ('\u{acd}', '\u{acd}', Mn),
+    ('\u{ad0}', '\u{ad0}', Lo),
+    ('\u{ae0}', '\u{ae1}', Lo),
+    ('\u{ae2}', '\u{ae3}', Mn),
+    ('\u{ae6}', '\u{aef}', Nd),
+    ('\u{af0}', '\u{af0}', Po),
+    ('\u{af1}', '\u{af1}', Sc),
+    ('\u{af9}', '\u{af9}', Lo),
+    ('\u{afa}', '\u{aff}', Mn),
+    ('\u{b01}', '\u{b01}', Mn),
+    ('\u{b02}', '\u{b03}', Mc),
+    ('\u{b05}', '\u{b0c}', Lo),
+    ('\u{b0f}', '\u{b10}', Lo),
+    ('\u{b13}', '\u{b28}', Lo),
+    ('\u{b2a}', '\u{b30}', Lo),
+    ('\u{b32}', '\u{b33}', Lo),
+    ('\u{b35}', '\u{b39}', Lo),
+    ('\u{b3c}', '\u{b3c}', Mn),
+    ('\u{b3d}', '\u{b3d}', Lo),
+    ('\u{b3e}', '\u{b3e}', Mc),
+    ('\u{b3f}', '\u{b3f}', Mn),
+    ('\u{b40}', '\u{b40}', Mc),
+    ('\u{b41}', '\u{b44}', Mn),
+    ('\u{b47}', '\u{b48}', Mc),
+    ('\u{b4b}', '\u{b4c}', Mc),
+    ('\u{b4d}', '\u{b4d}', Mn),
+    ('\u{b55}', '\u{b56}', Mn),
+    ('\u{b57}', '\u{b57}', Mc),
+    ('\u{b5c}', '\u{b5d}', Lo),
+    ('\u{b5f}', '\u{b61}', Lo),
+    ('\u{b62}', '\u{b63}', Mn),
+    ('\u{b66}', '\u{b6f}', Nd),
+    ('\u{b70}', '\u{b70}', So),
+    ('\u{b71}', '\u{b71}', Lo),
+    ('\u{b72}', '\u{b77}', No),
+    ('\u{b82}', '\u{b82}', Mn),
+    ('\u{b83}', '\u{b83}', Lo),
+    ('\u{b85}', '\u{b8a}', Lo),
+    ('\u{b8e}', '\u{b90}', Lo),
+    ('\u{b92}', '\u{b95}', Lo),
+    ('\u{b99}', '\u{b9a}', Lo),
+    ('\u{b9c}', '\u{b9c}', Lo),
+    ('\u{b9e}', '\u{b9f}', Lo),
+    ('\u{ba3}', '\u{ba4}', Lo),
+    ('\u{ba8}', '\u{baa}', Lo),
+    ('\u{bae}', '\u{bb9}', Lo),
+    ('\u{bbe}', '\u{bbf}', Mc),
+    ('\u{bc0}', '\u{bc0}', Mn),
+    ('\u{bc1}', '\u{bc2}', Mc),
+    ('\u{bc6}', '\u{bc8}', Mc),
+    ('\u{bca}', '\u{bcc}', Mc),
+    ('\u{bcd}', '\u{bcd}', Mn),
+    ('\u{bd0}', '\u{bd0}', Lo),
+    ('\u{bd7}', '\u{bd7}', Mc),
+    ('\u{be6}', '\u{bef}', Nd),
+    ('\u{bf0}', '\u{bf2}', No),
+    ('\u{bf3}', '\u{bf8}', So),
+    ('\u{bf9}', '\u{bf9}', Sc),
+    ('\u{bfa}', '\u{bfa}', So),
+    ('\u{c00}', '\u{c00}', Mn),
+    ('\u{c01}', '\u{c03}', Mc),
+    ('\u{c04}', '\u{c04}', Mn),
+    ('\u{c05}', '\u{c0c}', Lo),
+    ('\u{c0e}', '\u{c10}', Lo),
+    ('\u{c12}', '\u{c28}', Lo),
+    ('\u{c2a}', '\u{c39}', Lo),
+    ('\u{c3c}', '\u{c3c}', Mn),
+    ('\u{c3d}', '\u{c3d}', Lo),
+    ('\u{c3e}', '\u{c40}', Mn),
+    ('\u{c41}', '\u{c44}', Mc),
+    ('\u{c46}', '\u{c48}', Mn),
+    ('\u{c4a}', '\u{c4d}', Mn),
+    ('\u{c55}', '\u{c56}', Mn),
+    ('\u{c58}', '\u{c5a}', Lo),
+    ('\u{c5d}', '\u{c5d}', Lo),
+    ('\u{c60}', '\u{c61}', Lo),
+    ('\u{c62}', '\u{c63}', Mn),
+    ('\u{c66}', '\u{c6f}', Nd),
+    ('\u{c77}', '\u{c77}', Po),
+    ('\u{c78}', '\u{c7e}', No),
+    ('\u{c7f}', '\u{c7f}', So),
+    ('\u{c80}', '\u{c80}', Lo),
+    ('\u{c81}', '\u{c81}', Mn),
+    ('\u{c82}', '\u{c83}', Mc),
+    ('\u{c84}', '\u{c84}', Po),
+    ('\u{c85}', '\u{c8c}', Lo),
+    ('\u{c8e}', '\u{c90}', Lo),
+    ('\u{c92}', '\u{ca8}', Lo),
+    ('\u{caa}', '\u{cb3}', Lo),
+    ('\u{cb5}', '\u{cb9}', Lo),
+    ('\u{cbc}', '\u{cbc}', Mn),
+    ('\u{cbd}', '\u{cbd}', Lo),
+    ('\u{cbe}', '\u{cbe}', Mc),
+    ('\u{cbf}', '\u{cbf}', Mn),
+    ('\u{cc0}', '\u{cc4}', Mc),
+    ('\u{cc6}', '\u{cc6}', Mn),
+    ('\u{cc7}', '\u{cc8}', Mc),
+    ('\u{cca}', '\u{ccb}', Mc),
+    ('\u{ccc}', '\u{ccd}', Mn),
+    ('\u{cd5}', '\u{cd6}', Mc),
+    ('\u{cdd}', '\u{cde}', Lo),
+    ('\u{ce0}', '\u{ce1}', Lo),
+    ('\u{ce2}', '\u{ce3}', Mn),
+    ('\u{ce6}', '\u{cef}', Nd),
+    ('\u{cf1}', '\u{cf2}', Lo),
+    ('\u{cf3}', '\u{cf3}', Mc),
+    ('\u{d00}', '\u{d01}', Mn),
+    ('\u{d02}', '\u{d03}', Mc),
+    ('\u{d04}', '\u{d0c}', Lo),
+    ('\u{d0e}', '\u{d10}', Lo),
+    ('\u{d12}', '\u{d3a}', Lo),
+    ('\u{d3b}', '\u{d3c}', Mn),
+    ('\u{d3d}', '\u{d3d}', Lo),
+    ('\u{d3e}', '\u{d40}', Mc),
+    ('\u{d41}', '\u{d44}', Mn),
+    ('\u{d46}', '\u{d48}', Mc),
+    ('\u{d4a}', '\u{d4c}', Mc),
+    ('\u{d4d}', '\u{d4d}', Mn),
+    ('\u{d4e}', '\u{d4e}', Lo),
+    ('\u{d4f}', '\u{d4f}', So),
+    ('\u{d54}', '\u{d56}', Lo),
+    ('\u{d57}', '\u{d57}', Mc),
+    ('\u{d58}', '\u{d5e}', No),
+    ('\u{d5f}', '\u{d61}', Lo),
+    ('\u{d62}', '\u{d63}', Mn),
+    ('\u{d66}', '\u{d6f}', Nd),
+    ('\u{d70}', '\u{d78}', No),
+    ('\u{d79}', '\u{d79}', So),
+    ('\u{d7a}', '\u{d7f}', Lo),
+    ('\u{d81}', '\u{d81}', Mn),
+    ('\u{d82}', '\u{d83}', Mc),
+    ('\u{d85}', '\u{d96}', Lo),
+    ('\u{d9a}', '\u{db1}', Lo),
+    ('\u{db3}', '\u{dbb}', Lo),
+    ('\u{dbd}', '\u{dbd}', Lo),
+    ('\u{dc0}', '\u{dc6}', Lo),
+    ('\u{dca}', '\u{dca}', Mn),
+    ('\u{dcf}', '\u{dd1}', Mc),
+    ('\u{dd2}', '\u{dd4}', Mn),
+    ('\u{dd6}', '\u{dd6}', Mn),
+    ('\u{dd8}', '\u{ddf}', Mc),
+    ('\u{de6}', '\u{def}', Nd),
+    ('\u{df2}', '\u{df3}', Mc),
+    ('\u{df4}', '\u{df4}', Po),
+    ('\u{e01}', '\u{e30}', Lo),
+    ('\u{e31}', '\u{e31}', Mn),
+    ('\u{e32}', '\u{e33}', Lo),
+    ('\u{e34}', '\u{e3a}', Mn),
+    ('\u{e3f}', '\u{e3f}', Sc),
+    ('\u{e40}', '\u{e45}', Lo),
+    ('\u{e46}', '\u{e46}', Lm),
+    ('\u{e47}', '\u{e4e}', Mn),
+    ('\u{e4f}', '\u{e4f}', Po),
+    ('\u{e50}', '\u{e59}', Nd),
+    ('\u{e5a}', '\u{e5b}', Po),
+    ('\u{e81}', '\u{e82}', Lo),
+    ('\u{e84}', '\u{e84}', Lo),
+    ('\u{e86}', '\u{e8a}', Lo),
+    ('\u{e8c}', '\u{ea3}', Lo),
+    ('\u{ea5}', '\u{ea5}', Lo),
+    ('\u{ea7}', '\u{eb0}', Lo),
+    ('\u{eb1}', '\u{eb1}', Mn),
+    ('\u{eb2}', '\u{eb3}', Lo),
+    ('\u{eb4}', '\u{ebc}', Mn),
+    ('\u{ebd}', '\u{ebd}', Lo),
+    ('\u{ec0}', '\u{ec4}', Lo),
+    ('\u{ec6}', '\u{ec6}', Lm),
+    ('\u{ec8}', '\u{ece}', Mn),
+    ('\u{ed0}', '\u{ed9}', Nd),
+    ('\u{edc}', '\u{edf}', Lo),
+    ('\u{f00}', '\u{f00}', Lo),
+    ('\u{f01}', '\u{f03}', So),
+    ('\u{f04}', '\u{f12}', Po),
+    ('\u{f13}', '\u{f13}', So),
+    ('\u{f14}', '\u{f14}', Po),
+    ('\u{f15}', '\u{f17}', So),
+    ('\u{f18}', '\u{f19}', Mn),
+    ('\u{f1a}', '\u{f1f}', So),
+    ('\u{f20}', '\u{f29}', Nd),
+    ('\u{f2a}', '\u{f33}', No),
+    ('\u{f34}', '\u{f34}', So),
+    ('\u{f35}', '\u{f35}', Mn),
+    ('\u{f36}', '\u{f36}', So),
+    ('\u{f37}', '\u{f37}', Mn),
+    ('\u{f38}', '\u{f38}', So),
+    ('\u{f39}', '\u{f39}', Mn),
+    ('\u{f3a}', '\u{f3a}', Ps),
+    ('\u{f3b}', '\u{f3b}', Pe),
+    ('\u{f3c}', '\u{f3c}', Ps),
+    ('\u{f3d}', '\u{f3d}', Pe),
+    ('\u{f3e}', '\u{f3f}', Mc),
+    ('\u{f40}', '\u{f47}', Lo),
+    ('\u{f49}', '\u{f6c}', Lo),
+    ('\u{f71}', '\u{f7e}', Mn),
+    ('\u{f7f}', '\u{f7f}', Mc),
+    ('\u{f80}', '\u{f84}', Mn),
+    ('\u{f85}', '\u{f85}', Po),
+    ('\u{f86}', '\u{f87}', Mn),
+    ('\u{f88}', '\u{f8c}', Lo),
+    ('\u{f8d}', '\u{f97}', Mn),
+    ('\u{f99}', '\u{fbc}', Mn),
+    ('\u{fbe}', '\u{fc5}', So),
+    ('\u{fc6}', '\u{fc6}', Mn),
+    ('\u{fc7}', '\u{fcc}', So),
+    ('\u{fce}', '\u{fcf}', So),
+    ('\u{fd0}', '\u{fd4}', Po),
+    ('\u{fd5}', '\u{fd8}', So),
+    ('\u{fd9}', '\u{fda}', Po),
+    ('\u{1000}', '\u{102a}', Lo),
+    ('\u{102b}', '\u{102c}', Mc),
+    ('\u{102d}', '\u{1030}', Mn),
+    ('\u{1031}', '\u{1031}', Mc),
+    ('\u{1032}', '\u{1037}', Mn),
+    ('\u{1038}', '\u{1038}', Mc),
+    ('\u{1039}', '\u{103a}', Mn),
+    ('\u{103b}', '\u{103c}', Mc),
+    ('\u{103d}', '\u{103e}', Mn),
+    ('\u{103f}', '\u{103f}', Lo),
+    ('\u{1040}', '\u{1049}', Nd),
+    ('\u{104a}', '\u{104f}', Po),
+    ('\u{1050}', '\u{1055}', Lo),
+    ('\u{1056}', '\u{1057}', Mc),
+    ('\u{1058}', '\u{1059}', Mn),
+    ('\u{105a}', '\u{105d}', Lo),
+    ('\u{105e}', '\u{1060}', Mn),
+    ('\u{1061}', '\u{1061}', Lo),
+    ('\u{1062}', '\u{1064}', Mc),
+    ('\u{1065}', '\u{1066}', Lo),
+    ('\u{1067}', '\u{106d}', Mc),
+    ('\u{106e}', '\u{1070}', Lo),
+    ('\u{1071}', '\u{1074}', Mn),
+    ('\u{1075}', '\u{1081}', Lo),
+    ('\u{1082}', '\u{1082}', Mn),
+    ('\u{1083}', '\u{1084}', Mc),
+    ('\u{1085}', '\u{1086}', Mn),
+    ('\u{1087}', '\u{108c}', Mc),
+    ('\u{108d}', '\u{108d}', Mn),
+    ('\u{108e}', '\u{108e}', Lo),
+    ('\u{108f}', '\u{108f}', Mc),
+    ('\u{1090}', '\u{1099}', Nd),
+    ('\u{109a}', '\u{109c}', Mc),
+    ('\u{109d}', '\u{109d}', Mn),
+    ('\u{109e}', '\u{109f}', So),
+    ('\u{10a0}', '\u{10c5}', Lu),
+    ('\u{10c7}', '\u{10c7}', Lu),
+    ('\u{10cd}', '\u{10cd}', Lu),
+    ('\u{10d0}', '\u{10fa}', Ll),
+    ('\u{10fb}', '\u{10fb}', Po),
+    ('\u{10fc}', '\u{10fc}', Lm),
+    ('\u{10fd}', '\u{10ff}', Ll),
+    ('\u{1100}', '\u{1248}', Lo),
+    ('\u{124a}', '\u{124d}', Lo),
+    ('\u{1250}', '\u{1256}', Lo),
+    ('\u{1258}', '\u{1258}', Lo),
+    ('\u{125a}', '\u{125d}', Lo),
+    ('\u{1260}', '\u{1288}', Lo),
+    ('\u{128a}', '\u{128d}', Lo),
+    ('\u{1290}', '\u{12b0}', Lo),
+    ('\u{12b2}', '\u{12b5}', Lo),
+    ('\u{12b8}', '\u{12be}', Lo),
+    ('\u{12c0}', '\u{12c0}', Lo),
+    ('\u{12c2}', '\u{12c5}', Lo),
+    ('\u{12c8}', '\u{12d6}', Lo),
+    ('\u{12d8}', '\u{1310}', Lo),
+    ('\u{1312}', '\u{1315}', Lo),
+    ('\u{1318}', '\u{135a}', Lo),
+    ('\u{135d}', '\u{135f}', Mn),
+    ('\u{1360}', '\u{1368}', Po),
+    ('\u{1369}', '\u{137c}', No),
+    ('\u{1380}', '\u{138f}', Lo),
+    ('\u{1390}', '\u{1399}', So),
+    ('\u{13a0}', '\u{13f5}', Lu),
+    ('\u{13f8}', '\u{13fd}', Ll),
+    ('\u{1400}', '\u{1400}', Pd),
+    ('\u{1401}', '\u{166c}', Lo),
+    ('\u{166d}', '\u{166d}', So),
+    ('\u{166e}', '\u{166e}', Po),
+    ('\u{166f}', '\u{167f}', Lo),
+    ('\u{1680}', '\u{1680}', Zs),
+    ('\u{1681}', '\u{169a}', Lo),
+    ('\u{169b}', '\u{169b}', Ps),
+    ('\u{169c}', '\u{169c}', Pe),
+    ('\u{16a0}', '\u{16ea}', Lo),
+    ('\u{16eb}', '\u{16ed}', Po),
+    ('\u{16ee}', '\u{16f0}', Nl),
+    ('\u{16f1}', '\u{16f8}', Lo),
+    ('\u{1700}', '\u{1711}', Lo),
+    ('\u{1712}', '\u{1714}', Mn),
+    ('\u{1715}', '\u{1715}', Mc),
+    ('\u{171f}', '\u{1731}', Lo),
+    ('\u{1732}', '\u{1733}', Mn),
+    ('\u{1734}', '\u{1734}', Mc),
+    ('\u{1735}', '\u{1736}', Po),
+    ('\u{1740}', '\u{1751}', Lo),
+    ('\u{1752}', '\u{1753}', Mn),
+    ('\u{1760}', '\u{176c}', Lo),
+    ('\u{176e}', '\u{1770}', Lo),
+    ('\u{1772}', '\u{1773}', Mn),
+    ('\u{1780}', '\u{17b3}', Lo),
+    ('\u{17b4}', '\u{17b5}', Mn),
+    ('\u{17b6}', '\u{17b6}', Mc),
+    ('\u{17b7}', '\u{17bd}', Mn),
+    ('\u{17be}', '\u{17c5}', Mc),
+    ('\u{17c6}', '\u{17c6}', Mn),
+    ('\u{17c7}', '\u{17c8}', Mc),
+    ('\u{17c9}', '\u{17d3}', Mn),
+    ('\u{17d4}', '\u{17d6}', Po),
+    ('\u{17d7}', '\u{17d7}', Lm),
+    ('\u{17d8}', '\u{17da}', Po),
+    ('\u{17db}', '\u{17db}', Sc),
+    ('\u{17dc}', '\u{17dc}', Lo),
+    ('\u{17dd}', '\u{17dd}', Mn),
+    ('\u{17e0}', '\u{17e9}', Nd),
+    ('\u{17f0}', '\u{17f9}', No),
+    ('\u{1800}', '\u{1805}', Po),
+    ('\u{1806}', '\u{1806}', Pd),
+    ('\u{1807}', '\u{180a}', Po),
+    ('\u{180b}', '\u{180d}', Mn),
+    ('\u{180e}', '\u{180e}', Cf),
+    ('\u{180f}', '\u{180f}', Mn),
+    ('\u{1810}', '\u{1819}', Nd),
+    ('\u{1820}', '\u{1842}', Lo),
+    ('\u{1843}', '\u{1843}', Lm),
+    ('\u{1844}', '\u{1878}', Lo),
+    ('\u{1880}', '\u{1884}', Lo),
+    ('\u{1885}', '\u{1886}', Mn),
+    ('\u{1887}', '\u{18a8}', Lo),
+    ('\u{18a9}', '\u{18a9}', Mn),
+    ('\u{18aa}', '\u{18aa}', Lo),
+    ('\u{18b0}', '\u{18f5}', Lo),
+    ('\u{1900}', '\u{191e}', Lo),
+    ('\u{1920}', '\u{1922}', Mn),
+    ('\u{1923}', '\u{1926}', Mc),
+    ('\u{1927}', '\u{1928}', Mn),
+    ('\u{1929}', '\u{192b}', Mc),
+    ('\u{1930}', '\u{1931}', Mc),
+    ('\u{1932}', '\u{1932}', Mn),
+    ('\u{1933}', '\u{1938}', Mc),
+    ('\u{1939}', '\u{193b}', Mn),
+    ('\u{1940}', '\u{1940}', So),
+    ('\u{1944}', '\u{1945}', Po),
+    ('\u{1946}', '\u{194f}', Nd),
+    ('\u{1950}', '\u{196d}', Lo),
+    ('\u{1970}', '\u{1974}', Lo),
+    ('\u{1980}', '\u{19ab}', Lo),
+    ('\u{19b0}', '\u{19c9}', Lo),
+    ('\u{19d0}', '\u{19d9}', Nd),
+    ('\u{19da}', '\u{19da}', No),
+    ('\u{19de}', '\u{19ff}', So),
+    ('\u{1a00}', '\u{1a16}', Lo),
+    ('\u{1a17}', '\u{1a18}', Mn),
+    ('\u{1a19}', '\u{1a1a}', Mc),
+    ('\u{1a1b}', '\u{1a1b}', Mn),
+    ('\u{1a1e}', '\u{1a1f}', Po),
+    ('\u{1a20}', '\u{1a54}', Lo),
+    ('\u{1a55}', '\u{1a55}', Mc),
+    ('\u{1a56}', '\u{1a56}', Mn),
+    ('\u{1a57}', '\u{1a57}', Mc),
+    ('\u{1a58}', '\u{1a5e}', Mn),
+    ('\u{1a60}', '\u{1a60}', Mn),
+    ('\u{1a61}', '\u{1a61}', Mc),
+    ('\u{1a62}', '\u{1a62}', Mn),
+    ('\u{1a63}', '\u{1a64}', Mc),
+    ('\u{1a65}', '\u{1a6c}', Mn),
+    ('\u{1a6d}', '\u{1a72}', Mc),
+    ('\u{1a73}', '\u{1a7c}', Mn),
+    ('\u{1a7f}', '\u{1a7f}', Mn),
+    ('\u{1a80}', '\u{1a89}', Nd),
+    ('\u{1a90}', '\u{1a99}', Nd),
+    ('\u{1aa0}', '\u{1aa6}', Po),
+    ('\u{1aa7}', '\u{1aa7}', Lm),
+    ('\u{1aa8}', '\u{1aad}', Po),
+    ('\u{1ab0}', '\u{1abd}', Mn),
+    ('\u{1abe}', '\u{1abe}', Me),
+    ('\u{1abf}', '\u{1ace}', Mn),
+    ('\u{1b00}', '\u{1b03}', Mn),
+    ('\u{1b04}', '\u{1b04}', Mc),
+    ('\u{1b05}', '\u{1b33}', Lo),
+    ('\u{1b34}', '\u{1b34}', Mn),
+    ('\u{1b35}', '\u{1b35}', Mc),
+    ('\u{1b36}', '\u{1b3a}', Mn),
+    ('\u{1b3b}', '\u{1b3b}', Mc),
+    ('\u{1b3c}', '\u{1b3c}', Mn),
+    ('\u{1b3d}', '\u{1b41}', Mc),
+    ('\u{1b42}', '\u{1b42}', Mn),
+    ('\u{1b43}', '\u{1b44}', Mc),
+    ('\u{1b45}', '\u{1b4c}', Lo),
+    ('\u{1b4e}', '\u{1b4f}', Po),
+    ('\u{1b50}', '\u{1b59}', Nd),
+    ('\u{1b5a}', '\u{1b60}', Po),
+    ('\u{1b61}', '\u{1b6a}', So),
+    ('\u{1b6b}', '\u{1b73}', Mn),
+    ('\u{1b74}', '\u{1b7c}', So),
+    ('\u{1b7d}', '\u{1b7f}', Po),
+    ('\u{1b80}', '\u{1b81}', Mn),
+    ('\u{1b82}', '\u{1b82}', Mc),
+    ('\u{1b83}', '\u{1ba0}', Lo),
+    ('\u{1ba1}', '\u{1ba1}', Mc),
+    ('\u{1ba2}', '\u{1ba5}', Mn),
+    ('\u{1ba6}', '\u{1ba7}', Mc),
+    ('\u{1ba8}', '\u{1ba9}', Mn),
+    ('\u{1baa}', '\u{1baa}', Mc),
+    ('\u{1bab}', '\u{1bad}', Mn),
+    ('\u{1bae}', '\u{1baf}', Lo),
+    ('\u{1bb0}', '\u{1bb9}', Nd),
+    ('\u{1bba}', '\u{1be5}', Lo),
+    ('\u{1be6}', '\u{1be6}', Mn),
+    ('\u{1be7}', '\u{1be7}', Mc),
+    ('\u{1be8}', '\u{1be9}', Mn),
+    ('\u{1bea}', '\u{1bec}', Mc),
+    ('\u{1bed}', '\u{1bed}', Mn),
+    ('\u{1bee}', '\u{1bee}', Mc),
+    ('\u{1bef}', '\u{1bf1}', Mn),
+    ('\u{1bf2}', '\u{1bf3}', Mc),
+    ('\u{1bfc}', '\u{1bff}', Po),
+    ('\u{1c00}', '\u{1c23}', Lo),
+    ('\u{1c24}', '\u{1c2b}', Mc),
+    ('\u{1c2c}', '\u{1c33}', Mn),
+    ('\u{1c34}', '\u{1c35}', Mc),
+    ('\u{1c36}', '\u{1c37}', Mn),
+    ('\u{1c3b}', '\u{1c3f}', Po),
+    ('\u{1c40}', '\u{1c49}', Nd),
+    ('\u{1c4d}', '\u{1c4f}', Lo),
+    ('\u{1c50}', '\u{1c59}', Nd),
+    ('\u{1c5a}', '\u{1c77}', Lo),
+    ('\u{1c78}', '\u{1c7d}', Lm),
+    ('\u{1c7e}', '\u{1c7f}', Po),
+    ('\u{1c80}', '\u{1c88}', Ll),
+    ('\u{1c89}', '\u{1c89}', Lu),
+    ('\u{1c8a}', '\u{1c8a}', Ll),
+    ('\u{1c90}', '\u{1cba}', Lu),
+    ('\u{1cbd}', '\u{1cbf}', Lu),
+    ('\u{1cc0}', '\u{1cc7}', Po),
+    ('\u{1cd0}', '\u{1cd2}', Mn),
+    ('\u{1cd3}', '\u{1cd3}', Po),
+    ('\u{1cd4}', '\u{1ce0}', Mn),
+    ('\u{1ce1}', '\u{1ce1}', Mc),
+    ('\u{1ce2}', '\u{1ce8}', Mn),
+    ('\u{1ce9}', '\u{1cec}', Lo),
+    ('\u{1ced}', '\u{1ced}', Mn),
+    ('\u{1cee}', '\u{1cf3}', Lo),
+    ('\u{1cf4}', '\u{1cf4}', Mn),
+    ('\u{1cf5}', '\u{1cf6}', Lo),
+    ('\u{1cf7}', '\u{1cf7}', Mc),
+    ('\u{1cf8}', '\u{1cf9}', Mn),
+    ('\u{1cfa}', '\u{1cfa}', Lo),
+    ('\u{1d00}', '\u{1d2b}', Ll),
+    ('\u{1d2c}', '\u{1d6a}', Lm),
+    ('\u{1d6b}', '\u{1d77}', Ll),
+    ('\u{1d78}', '\u{1d78}', Lm),
+    ('\u{1d79}', '\u{1d9a}', Ll),
+    ('\u{1d9b}', '\u{1dbf}', Lm),
+    ('\u{1dc0}', '\u{1dff}', Mn),
+    ('\u{1e00}', '\u{1e00}', Lu),
+    ('\u{1e01}', '\u{1e01}', Ll),
+    ('\u{1e02}', '\u{1e02}', Lu),
+    ('\u{1e03}', '\u{1e03}', Ll),
+    ('\u{1e04}', '\u{1e04}', Lu),
+    ('\u{1e05}', '\u{1e05}', Ll),
+    ('\u{1e06}', '\u{1e06}', Lu),
+    ('\u{1e07}', '\u{1e07}', Ll),
+    ('\u{1e08}', '\u{1e08}', Lu),
+    ('\u{1e09}', '\u{1e09}', Ll),
+    ('\u{1e0a}', '\u{1e0a}', Lu),
+    ('\u{1e0b}', '\u{1e0b}', Ll),
+    ('\u{1e0c}', '\u{1e0c}', Lu),
+    ('\u{1e0d}', '\u{1e0d}', Ll),
+    ('\u{1e0e}', '\u{1e0e}', Lu),
+    ('\u{1e0f}', '\u{1e0f}', Ll),
+    ('\u{1e10}', '\u{1e10}', Lu),
+    ('\u{1e11}', '\u{1e11}', Ll),
+    ('\u{1e12}', '\u{1e12}', Lu),
+    ('\u{1e13}', '\u{1e13}', Ll),
+    ('\u{1e14}', '\u{1e14}', Lu),
+    ('\u{1e15}', '\u{1e15}', Ll),
+    ('\u{1e16}', '\u{1e16}', Lu),
+    ('\u{1e17}', '\u{1e17}', Ll),
+    ('\u{1e18}', '\u{1e18}', Lu),
+    ('\u{1e19}', '\u{1e19}', Ll),
+    ('\u{1e1a}', '\u{1e1a}', Lu),
+    ('\u{1e1b}', '\u{1e1b}', Ll),
+    ('\u{1e1c}', '\u{1e1c}', Lu),
+    ('\u{1e1d}', '\u{1e1d}', Ll),
+    ('\u{1e1e}', '\u{1e1e}', Lu),
+    ('\u{1e1f}', '\u{1e1f}', Ll),
+    ('\u{1e20}', '\u{1e20}', Lu),
+    ('\u{1e21}', '\u{1e21}', Ll),
+    ('\u{1e22}', '\u{1e22}', Lu),
+    ('\u{1e23}', '\u{1e23}', Ll),
+    ('\u{1e24}', '\u{1e24}', Lu),
+    ('\u{1e25}', '\u{1e25}', Ll),
+    ('\u{1e26}', '\u{1e26}', Lu),
+    ('\u{1e27}', '\u{1e27}', Ll),
+    ('\u{1e28}', '\u{1e28}', Lu),
+    ('\u{1e29}', '\u{1e29}', Ll),
+    ('\u{1e2a}', '\u{1e2a}', Lu),
+    ('\u{1e2b}', '\u{1e2b}', Ll),
+    ('\u{1e2c}', '\u{1e2c}', Lu),
+    ('\u{1e2d}', '\u{1e2d}', Ll),
+    ('\u{1e2e}', '\u{1e2e}', Lu),
+    ('\u{1e2f}', '\u{1e2f}', Ll),
+    ('\u{1e30}', '\u{1e30}', Lu),
+    ('\u{1e31}', '\u{1e31}', Ll),
+    ('\u{1e32}', '\u{1e32}', Lu),
+    ('\u{1e33}', '\u{1e33}', Ll),
+    ('\u{1e34}', '\u{1e34}', Lu),
+    ('\u{1e35}', '\u{1e35}', Ll),
+    ('\u{1e36}', '\u{1e36}', Lu),
+    ('\u{1e37}', '\u{1e37}', Ll),
+    ('\u{1e38}', '\u{1e38}', Lu),
+    ('\u{1e39}', '\u{1e39}', Ll),
+    ('\u{1e3a}', '\u{1e3a}', Lu),
+    ('\u{1e3b}', '\u{1e3b}', Ll),
+    ('\u{1e3c}', '\u{1e3c}', Lu),
+    ('\u{1e3d}', '\u{1e3d}', Ll),
+    ('\u{1e3e}', '\u{1e3e}', Lu),
+    ('\u{1e3f}', '\u{1e3f}', Ll),
+    ('\u{1e40}', '\u{1e40}', Lu),
+    ('\u{1e41}', '\u{1e41}', Ll),
+    ('\u{1e42}', '\u{1e42}', Lu),
+    ('\u{1e43}', '\u{1e43}', Ll),
+    ('\u{1e44}', '\u{1e44}', Lu),
+    ('\u{1e45}', '\u{1e45}', Ll),
+    ('\u{1e46}', '\u{1e46}', Lu),
+    ('\u{1e47}', '\u{1e47}', Ll),
+    ('\u{1e48}', '\u{1e48}', Lu),
+    ('\u{1e49}', '\u{1e49}', Ll),
+    ('\u{1e4a}', '\u{1e4a}', Lu),
+    ('\u{1e4b}', '\u{1e4b}', Ll),
+    ('\u{1e4c}', '\u{1e4c}', Lu),
+    ('\u{1e4d}', '\u{1e4d}', Ll),
+    ('\u{1e4e}', '\u{1e4e}', Lu),
+    ('\u{1e4f}', '\u{1e4f}', Ll),
+    ('\u{1e50}', '\u{1e50}', Lu),
+    ('\u{1e51}', '\u{1e51}', Ll),
+    ('\u{1e52}', '\u{1e52}', Lu),
+    ('\u{1e53}', '\u{1e53}', Ll),
+    ('\u{1e54}', '\u{1e54}', Lu),
+    ('\u{1e55}', '\u{1e55}', Ll),
+    ('\u{1e56}', '\u{1e56}', Lu),
+    ('\u{1e57}', '\u{1e57}', Ll),
+    ('\u{1e58}', '\u{1e58}', Lu),
+    ('\u{1e59}', '\u{1e59}', Ll),
+    ('\u{1e5a}', '\u{1e5a}', Lu),
+    ('\u{1e5b}', '\u{1e5b}', Ll),
+    ('\u{1e5c}', '\u{1e5c}', Lu),
+    ('\u{1e5d}', '\u{1e5d}', Ll),
+    ('\u{1e5e}', '\u{1e5e}', Lu),
+    ('\u{1e5f}', '\u{1e5f}', Ll),
+    ('\u{1e60}', '\u{1e60}', Lu),
+    ('\u{1e61}', '\u{1e61}', Ll),
+    ('\u{1e62}', '\u{1e62}', Lu),
+    ('\u{1e63}', '\u{1e63}', Ll),
+    ('\u{1e64}', '\u{1e64}', Lu),
+    ('\u{1e65}', '\u{1e65}', Ll),
+    ('\u{1e66}', '\u{1e66}', Lu),
+    ('\u{1e67}', '\u{1e67}', Ll),
+    ('\u{1e68}', '\u{1e68}', Lu),
+    ('\u{1e69}', '\u{1e69}', Ll),
+    ('\u{1e6a}', '\u{1e6a}', Lu),
+    ('\u{1e6b}', '\u{1e6b}', Ll),
+    ('\u{1e6c}', '\u{1e6c}', Lu),
+    ('\u{1e6d}', '\u{1e6d}', Ll),
+    ('\u{1e6e}', '\u{1e6e}', Lu),
+    ('\u{1e6f}', '\u{1e6f}', Ll),
+    ('\u{1e70}', '\u{1e70}', Lu),
+    ('\u{1e71}', '\u{1e71}', Ll),
+    ('\u{1e72}', '\u{1e72}', Lu),
+    ('\u{1e73}', '\u{1e73}', Ll),
+    ('\u{1e74}', '\u{1e74}', Lu),
+    ('\u{1e75}', '\u{1e75}', Ll),
+    ('\u{1e76}', '\u{1e76}', Lu),
+    ('\u{1e77}', '\u{1e77}', Ll),
+    ('\u{1e78}', '\u{1e78}', Lu),
+    ('\u{1e79}', '\u{1e79}', Ll),
+    ('\u{1e7a}', '\u{1e7a}', Lu),
+    ('\u{1e7b}', '\u{1e7b}', Ll),
+    ('\u{1e7c}', '\u{1e7c}', Lu),
+    ('\u{1e7d}', '\u{1e7d}', Ll),
+    ('\u{1e7e}', '\u{1e7e}', Lu),
+    ('\u{1e7f}', '\u{1e7f}', Ll),
+    ('\u{1e80}', '\u{1e80}', Lu),
+    ('\u{1e81}', '\u{1e81}', Ll),
+    ('\u{1e82}', '\u{1e82}', Lu),
+    ('\u{1e83}', '\u{1e83}', Ll),
+    ('\u{1e84}', '\u{1e84}', Lu),
+    ('\u{1e85}', '\u{1e85}', Ll),
+    ('\u{1e86}', '\u{1e86}', Lu),
+    ('\u{1e87}', '\u{1e87}', Ll),
+    ('\u{1e88}', '\u{1e88}', Lu),
+    ('\u{1e89}', '\u{1e89}', Ll),
+    ('\u{1e8a}', '\u{1e8a}', Lu),
+    ('\u{1e8b}', '\u{1e8b}', Ll),
+    ('\u{1e8c}', '\u{1e8c}', Lu),
+    ('\u{1e8d}', '\u{1e8d}', Ll),
+    ('\u{1e8e}', '\u{1e8e}', Lu),
+    ('\u{1e8f}', '\u{1e8f}', Ll),
+    ('\u{1e90}', '\u{1e90}', Lu),
+    ('\u{1e91}', '\u{1e91}', Ll),
+    ('\u{1e92}', '\u{1e92}', Lu),
+    ('\u{1e93}', '\u{1e93}', Ll),
+    ('\u{1e94}', '\u{1e94}', Lu),
+    ('\u{1e95}', '\u{1e9d}', Ll),
+    ('\u{1e9e}', '\u{1e9e}', Lu),
+    ('\u{1e9f}', '\u{1e9f}', Ll),
+    ('\u{1ea0}', '\u{1ea0}', Lu),
+    ('\u{1ea1}', '\u{1ea1}', Ll),
+    ('\u{1ea2}', '\u{1ea2}', Lu),
+    ('\u{1ea3}', '\u{1ea3}', Ll),
+    ('\u{1ea4}', '\u{1ea4}', Lu),
+    ('\u{1ea5}', '\u{1ea5}', Ll),
+    ('\u{1ea6}', '\u{1ea6}', Lu),
+    ('\u{1ea7}', '\u{1ea7}', Ll),
+    ('\u{1ea8}', '\u{1ea8}', Lu),
+    ('\u{1ea9}', '\u{1ea9}', Ll),
+    ('\u{1eaa}', '\u{1eaa}', Lu),
+    ('\u{1eab}', '\u{1eab}', Ll),
+    ('\u{1eac}', '\u{1eac}', Lu),
+    ('\u{1ead}', '\u{1ead}', Ll),
+    ('\u{1eae}', '\u{1eae}', Lu),
+    ('\u{1eaf}', '\u{1eaf}', Ll),
+    ('\u{1eb0}', '\u{1eb0}', Lu),
+    ('\u{1eb1}', '\u{1eb1}', Ll),
+    ('\u{1eb2}', '\u{1eb2}', Lu),
+    ('\u{1eb3}', '\u{1eb3}', Ll),
+    ('\u{1eb4}', '\u{1eb4}', Lu),
+    ('\u{1eb5}', '\u{1eb5}', Ll),
+    ('\u{1eb6}', '\u{1eb6}', Lu),
+    ('\u{1eb7}', '\u{1eb7}', Ll),
+    ('\u{1eb8}', '\u{1eb8}', Lu),
+    ('\u{1eb9}', '\u{1eb9}', Ll),
+    ('\u{1eba}', '\u{1eba}', Lu),
+    ('\u{1ebb}', '\u{1ebb}', Ll),
+    ('\u{1ebc}', '\u{1ebc}', Lu),
+    ('\u{1ebd}', '\u{1ebd}', Ll),
+    ('\u{1ebe}', '\u{1ebe}', Lu),
+    ('\u{1ebf}', '\u{1ebf}', Ll),
+    ('\u{1ec0}', '\u{1ec0}', Lu),
+    ('\u{1ec1}', '\u{1ec1}', Ll),
+    ('\u{1ec2}', '\u{1ec2}', Lu),
+    ('\u{1ec3}', '\u{1ec3}', Ll),
+    ('\u{1ec4}', '\u{1ec4}', Lu),
+    ('\u{1ec5}', '\u{1ec5}', Ll),
+    ('\u{1ec6}', '\u{1ec6}', Lu),
+    ('\u{1ec7}', '\u{1ec7}', Ll),
+    ('\u{1ec8}', '\u{1ec8}', Lu),
+    ('\u{1ec9}', '\u{1ec9}', Ll),
+    ('\u{1eca}', '\u{1eca}', Lu),
+    ('\u{1ecb}', '\u{1ecb}', Ll),
+    ('\u{1ecc}', '\u{1ecc}', Lu),
+    ('\u{1ecd}', '\u{1ecd}', Ll),
+    ('\u{1ece}', '\u{1ece}', Lu),
+    ('\u{1ecf}', '\u{1ecf}', Ll),
+    ('\u{1ed0}', '\u{1ed0}', Lu),
+    ('\u{1ed1}', '\u{1ed1}', Ll),
+    ('\u{1ed2}', '\u{1ed2}', Lu),
+    ('\u{1ed3}', '\u{1ed3}', Ll),
+    ('\u{1ed4}', '\u{1ed4}', Lu),
+    ('\u{1ed5}', '\u{1ed5}', Ll),
+    ('\u{1ed6}', '\u{1ed6}', Lu),
+    ('\u{1ed7}', '\u{1ed7}', Ll),
+    ('\u{1ed8}', '\u{1ed8}', Lu),
+    ('\u{1ed9}', '\u{1ed9}', Ll),
+    ('\u{1eda}', '\u{1eda}', Lu),
+    ('\u{1edb}', '\u{1edb}', Ll),
+    ('\u{1edc}', '\u{1edc}', Lu),
+    ('\u{1edd}', '\u{1edd}', Ll),
+    ('\u{1ede}', '\u{1ede}', Lu),
+    ('\u{1edf}', '\u{1edf}', Ll),
+    ('\u{1ee0}', '\u{1ee0}', Lu),
+    ('\u{1ee1}', '\u{1ee1}', Ll),
+    ('\u{1ee2}', '\u{1ee2}', Lu),
+    ('\u{1ee3}', '\u{1ee3}', Ll),
+    ('\u{1ee4}', '\u{1ee4}', Lu),
+    ('\u{1ee5}', '\u{1ee5}', Ll),
+    ('\u{1ee6}', '\u{1ee6}', Lu),
+    ('\u{1ee7}', '\u{1ee7}', Ll),
+    ('\u{1ee8}', '\u{1ee8}', Lu),
+    ('\u{1ee9}', '\u{1ee9}', Ll),
+    ('\u{1eea}', '\u{1eea}', Lu),
+    ('\u{1eeb}', '\u{1eeb}', Ll),
+    ('\u{1eec}', '\u{1eec}', Lu),
+    ('\u{1eed}', '\u{1eed}', Ll),
+    ('\u{1eee}', '\u{1eee}', Lu),
+    ('\u{1eef}', '\u{1eef}', Ll),
+    ('\u{1ef0}', '\u{1ef0}', Lu),
+    ('\u{1ef1}', '\u{1ef1}', Ll),
+    ('\u{1ef2}', '\u{1ef2}', Lu),
+    ('\u{1ef3}', '\u{1ef3}', Ll),
+    ('\u{1ef4}', '\u{1ef4}', Lu),
+    ('\u{1ef5}', '\u{1ef5}', Ll),
+    ('\u{1ef6}', '\u{1ef6}', Lu),
+    ('\u{1ef7}', '\u{1ef7}', Ll),
+    ('\u{1ef8}', '\u{1ef8}', Lu),
+    ('\u{1ef9}', '\u{1ef9}', Ll),
+    ('\u{1efa}', '\u{1efa}', Lu),
+    ('\u{1efb}', '\u{1efb}', Ll),
+    ('\u{1efc}', '\u{1efc}', Lu),
+    ('\u{1efd}', '\u{1efd}', Ll),
+    ('\u{1efe}', '\u{1efe}', Lu),
+    ('\u{1eff}', '\u{1f07}', Ll),
+    ('\u{1f08}', '\u{1f0f}', Lu),
+    ('\u{1f10}', '\u{1f15}', Ll),
+    ('\u{1f18}', '\u{1f1d}', Lu),
+    ('\u{1f20}', '\u{1f27}', Ll),
+    ('\u{1f28}', '\u{1f2f}', Lu),
+    ('\u{1f30}', '\u{1f37}', Ll),
+    ('\u{1f38}', '\u{1f3f}', Lu),
+    ('\u{1f40}', '\u{1f45}', Ll),
+    ('\u{1f48}', '\u{1f4d}', Lu),
+    ('\u{1f50}', '\u{1f57}', Ll),
+    ('\u{1f59}', '\u{1f59}', Lu),
+    ('\u{1f5b}', '\u{1f5b}', Lu),
+    ('\u{1f5d}', '\u{1f5d}', Lu),
+    ('\u{1f5f}', '\u{1f5f}', Lu),
+    ('\u{1f60}', '\u{1f67}', Ll),
+    ('\u{1f68}', '\u{1f6f}', Lu),
+    ('\u{1f70}', '\u{1f7d}', Ll),
+    ('\u{1f80}', '\u{1f87}', Ll),
+    ('\u{1f88}', '\u{1f8f}', Lt),
+    ('\u{1f90}', '\u{1f97}', Ll),
+    ('\u{1f98}', '\u{1f9f}', Lt),
+    ('\u{1fa0}', '\u{1fa7}', Ll),
+    ('\u{1fa8}', '\u{1faf}', Lt),
+    ('\u{1fb0}', '\u{1fb4}', Ll),
+    ('\u{1fb6}', '\u{1fb7}', Ll),
+    ('\u{1fb8}', '\u{1fbb}', Lu),
+    ('\u{1fbc}', '\u{1fbc}', Lt),
+    ('\u{1fbd}', '\u{1fbd}', Sk),
+    ('\u{1fbe}', '\u{1fbe}', Ll),
+    ('\u{1fbf}', '\u{1fc1}', Sk),
+    ('\u{1fc2}', '\u{1fc4}', Ll),
+    ('\u{1fc6}', '\u{1fc7}', Ll),
+    ('\u{1fc8}', '\u{1fcb}', Lu),
+    ('\u{1fcc}', '\u{1fcc}', Lt),
+    ('\u{1fcd}', '\u{1fcf}', Sk),
+    ('\u{1fd0}', '\u{1fd3}', Ll),
+    ('\u{1fd6}', '\u{1fd7}', Ll),
+    ('\u{1fd8}', '\u{1fdb}', Lu),
+    ('\u{1fdd}', '\u{1fdf}', Sk),
+    ('\u{1fe0}', '\u{1fe7}', Ll),
+    ('\u{1fe8}', '\u{1fec}', Lu),
+    ('\u{1fed}', '\u{1fef}', Sk),
+    ('\u{1ff2}', '\u{1ff4}', Ll),
+    ('\u{1ff6}', '\u{1ff7}', Ll),
+    ('\u{1ff8}', '\u{1ffb}', Lu),
+    ('\u{1ffc}', '\u{1ffc}', Lt),
+    ('\u{1ffd}', '\u{1ffe}', Sk),
+    ('\u{2000}', '\u{200a}', Zs),
+    ('\u{200b}', '\u{200f}', Cf),
+    ('\u{2010}', '\u{2015}', Pd),
+    ('\u{2016}', '\u{2017}', Po),
+    ('\u{2018}', '\u{2018}', Pi),
+    ('\u{2019}', '\u{2019}', Pf),
+    ('\u{201a}', '\u{201a}', Ps),
+    ('\u{201b}', '\u{201c}', Pi),
+    ('\u{201d}', '\u{201d}', Pf),
+    ('\u{201e}', '\u{201e}', Ps),
+    ('\u{201f}', '\u{201f}', Pi),
+    ('\u{2020}', '\u{2027}', Po),
+    ('\u{2028}', '\u{2028}', Zl),
+    ('\u{2029}', '\u{2029}', Zp),
+    ('\u{202a}', '\u{202e}', Cf),
+    ('\u{202f}', '\u{202f}', Zs),
+    ('\u{2030}', '\u{2038}', Po),
+    ('\u{2039}', '\u{2039}', Pi),
+    ('\u{203a}', '\u{203a}', Pf),
+    ('\u{203b}', '\u{203e}', Po),
+    ('\u{203f}', '\u{2040}', Pc),
+    ('\u{2041}', '\u{2043}', Po),
+    ('\u{2044}', '\u{2044}', Sm),
+    ('\u{2045}', '\u{2045}', Ps),
+    ('\u{2046}', '\u{2046}', Pe),
+    ('\u{2047}', '\u{2051}', Po),
+    ('\u{2052}', '\u{2052}', Sm),
+    ('\u{2053}', '\u{2053}', Po),
+    ('\u{2054}', '\u{2054}', Pc),
+    ('\u{2055}', '\u{205e}', Po),
+    ('\u{205f}', '\u{205f}', Zs),
+    ('\u{2060}', '\u{2064}', Cf),
+    ('\u{2066}', '\u{206f}', Cf),
+    ('\u{2070}', '\u{2070}', No),
+    ('\u{2071}', '\u{2071}', Lm),
+    ('\u{2074}', '\u{2079}', No),
+    ('\u{207a}', '\u{207c}', Sm),
+    ('\u{207d}', '\u{207d}', Ps),
+    ('\u{207e}', '\u{207e}', Pe),
+    ('\u{207f}', '\u{207f}', Lm),
+    ('\u{2080}', '\u{2089}', No),
+    ('\u{208a}', '\u{208c}', Sm),
+    ('\u{208d}', '\u{208d}', Ps),
+    ('\u{208e}', '\u{208e}', Pe),
+    ('\u{2090}', '\u{209c}', Lm),
+    ('\u{20a0}', '\u{20c0}', Sc),
+    ('\u{20d0}', '\u{20dc}', Mn),
+    ('\u{20dd}', '\u{20e0}', Me),
+    ('\u{20e1}', '\u{20e1}', Mn),
+    ('\u{20e2}', '\u{20e4}', Me),
+    ('\u{20e5}', '\u{20f0}', Mn),
+    ('\u{2100}', '\u{2101}', So),
+    ('\u{2102}', '\u{2102}', Lu),
+    ('\u{2103}', '\u{2106}', So),
+    ('\u{2107}', '\u{2107}', Lu),
+    ('\u{2108}', '\u{2109}', So),
+    ('\u{210a}', '\u{210a}', Ll),
+    ('\u{210b}', '\u{210d}', Lu),
+    ('\u{210e}', '\u{210f}', Ll),
+    ('\u{2110}', '\u{2112}', Lu),
+    ('\u{2113}', '\u{2113}', Ll),
+    ('\u{2114}', '\u{2114}', So),
+    ('\u{2115}', '\u{2115}', Lu),
+    ('\u{2116}', '\u{2117}', So),
+    ('\u{2118}', '\u{2118}', Sm),
+    ('\u{2119}', '\u{211d}', Lu),
+    ('\u{211e}', '\u{2123}', So),
+    ('\u{2124}', '\u{2124}', Lu),
+    ('\u{2125}', '\u{2125}', So),
+    ('\u{2126}', '\u{2126}', Lu),
+    ('\u{2127}', '\u{2127}', So),
+    ('\u{2128}', '\u{2128}', Lu),
+    ('\u{2129}', '\u{2129}', So),
+    ('\u{212a}', '\u{212d}', Lu),
+    ('\u{212e}', '\u{212e}', So),
+    ('\u{212f}', '\u{212f}', Ll),
+    ('\u{2130}', '\u{2133}', Lu),
+    ('\u{2134}', '\u{2134}', Ll),
+    ('\u{2135}', '\u{2138}', Lo),
+    ('\u{2139}', '\u{2139}', Ll),
+    ('\u{213a}', '\u{213b}', So),
+    ('\u{213c}', '\u{213d}', Ll),
+    ('\u{213e}', '\u{213f}', Lu),
+    ('\u{2140}', '\u{2144}', Sm),
+    ('\u{2145}', '\u{2145}', Lu),
+    ('\u{2146}', '\u{2149}', Ll),
+    ('\u{214a}', '\u{214a}', So),
+    ('\u{214b}', '\u{214b}', Sm),
+    ('\u{214c}', '\u{214d}', So),
+    ('\u{214e}', '\u{214e}', Ll),
+    ('\u{214f}', '\u{214f}', So),
+    ('\u{2150}', '\u{215f}', No),
+    ('\u{2160}', '\u{2182}', Nl),
+    ('\u{2183}', '\u{2183}', Lu),
+    ('\u{2184}', '\u{2184}', Ll),
+    ('\u{2185}', '\u{2188}', Nl),
+    ('\u{2189}', '\u{2189}', No),
+    ('\u{218a}', '\u{218b}', So),
+    ('\u{2190}', '\u{2194}', Sm),
+    ('\u{2195}', '\u{2199}', So),
+    ('\u{219a}', '\u{219b}', Sm),
+    ('\u{219c}', '\u{219f}', So),
+    ('\u{21a0}', '\u{21a0}', Sm),
+    ('\u{21a1}', '\u{21a2}', So),
+    ('\u{21a3}', '\u{21a3}', Sm),
+    ('\u{21a4}', '\u{21a5}', So),
+    ('\u{21a6}', '\u{21a6}', Sm),
+    ('\u{21a7}', '\u{21ad}', So),
+    ('\u{21ae}', '\u{21ae}', Sm),
+    ('\u{21af}', '\u{21cd}', So),
+    ('\u{21ce}', '\u{21cf}', Sm),
+    ('\u{21d0}', '\u{21d1}', So),
+    ('\u{21d2}', '\u{21d2}', Sm),
+    ('\u{21d3}', '\u{21d3}', So),
+    ('\u{21d4}', '\u{21d4}', Sm),
+    ('\u{21d5}', '\u{21f3}', So),
+    ('\u{21f4}', '\u{22ff}', Sm),
+    ('\u{2300}', '\u{2307}', So),
+    ('\u{2308}', '\u{2308}', Ps),
+    ('\u{2309}', '\u{2309}', Pe),
+    ('\u{230a}', '\u{230a}', Ps),
+    ('\u{230b}', '\u{230b}', Pe),
+    ('\u{230c}', '\u{231f}', So),
+    ('\u{2320}', '\u{2321}', Sm),
+    ('\u{2322}', '\u{2328}', So),
+    ('\u{2329}', '\u{2329}', Ps),
+    ('\u{232a}', '\u{232a}', Pe),
+    ('\u{232b}', '\u{237b}', So),
+    ('\u{237c}', '\u{237c}', Sm),
+    ('\u{237d}', '\u{239a}', So),
+    ('\u{239b}', '\u{23b3}', Sm),
+    ('\u{23b4}', '\u{23db}', So),
+    ('\u{23dc}', '\u{23e1}', Sm),
+    ('\u{23e2}', '\u{2429}', So),
+    ('\u{2440}', '\u{244a}', So),
+    ('\u{2460}', '\u{249b}', No),
+    ('\u{249c}', '\u{24e9}', So),
+    ('\u{24ea}', '\u{24ff}', No),
+    ('\u{2500}', '\u{25b6}', So),
+    ('\u{25b7}', '\u{25b7}', Sm),
+    ('\u{25b8}', '\u{25c0}', So),
+    ('\u{25c1}', '\u{25c1}', Sm),
+    ('\u{25c2}', '\u{25f7}', So),
+    ('\u{25f8}', '\u{25ff}', Sm),
+    ('\u{2600}', '\u{266e}', So),
+    ('\u{266f}', '\u{266f}', Sm),
+    ('\u{2670}', '\u{2767}', So),
+    ('\u{2768}', '\u{2768}', Ps),
+    ('\u{2769}', '\u{2769}', Pe),
+    ('\u{276a}', '\u{276a}', Ps),
+    ('\u{276b}', '\u{276b}', Pe),
+    ('\u{276c}', '\u{276c}', Ps),
+    ('\u{276d}', '\u{276d}', Pe),
+    ('\u{276e}', '\u{276e}', Ps),
+    ('\u{276f}', '\u{276f}', Pe),
+    ('\u{2770}', '\u{2770}', Ps),
+    ('\u{2771}', '\u{2771}', Pe),
+    ('\u{2772}', '\u{2772}', Ps),
+    ('\u{2773}', '\u{2773}', Pe),
+    ('\u{2774}', '\u{2774}', Ps),
+    ('\u{2775}', '\u{2775}', Pe),
+    ('\u{2776}', '\u{2793}', No),
+    ('\u{2794}', '\u{27bf}', So),
+    ('\u{27c0}', '\u{27c4}', Sm),
+    ('\u{27c5}', '\u{27c5}', Ps),
+    ('\u{27c6}', '\u{27c6}', Pe),
+    ('\u{27c7}', '\u{27e5}', Sm),
+    ('\u{27e6}', '\u{27e6}', Ps),
+    ('\u{27e7}', '\u{27e7}', Pe),
+    ('\u{27e8}', '\u{27e8}', Ps),
+    ('\u{27e9}', '\u{27e9}', Pe),
+    ('\u{27ea}', '\u{27ea}', Ps),
+    ('\u{27eb}', '\u{27eb}', Pe),
+    ('\u{27ec}', '\u{27ec}', Ps),
+    ('\u{27ed}', '\u{27ed}', Pe),
+    ('\u{27ee}', '\u{27ee}', Ps),
+    ('\u{27ef}', '\u{27ef}', Pe),
+    ('\u{27f0}', '\u{27ff}', Sm),
+    ('\u{2800}', '\u{28ff}', So),
+    ('\u{2900}', '\u{2982}', Sm),
+    ('\u{2983}', '\u{2983}', Ps),
+    ('\u{2984}', '\u{2984}', Pe),
+    ('\u{2985}', '\u{2985}', Ps),
+    ('\u{2986}', '\u{2986}', Pe),
+    ('\u{2987}', '\u{2987}', Ps),
+    ('\u{2988}', '\u{2988}', Pe),
+    ('\u{2989}', '\u{2989}', Ps),
+    ('\u{298a}', '\u{298a}', Pe),
+    ('\u{298b}', '\u{298b}', Ps),
+    ('\u{298c}', '\u{298c}', Pe),
+    ('\u{298d}', '\u{298d}', Ps),
+    ('\u{298e}', '\u{298e}', Pe),
+    ('\u{298f}', '\u{298f}', Ps),
+    ('\u{2990}', '\u{2990}', Pe),
+    ('\u{2991}', '\u{2991}', Ps),
+    ('\u{2992}', '\u{2992}', Pe),
+    ('\u{2993}', '\u{2993}', Ps),
+    ('\u{2994}', '\u{2994}', Pe),
+    ('\u{2995}', '\u{2995}', Ps),
+    ('\u{2996}', '\u{2996}', Pe),
+    ('\u{2997}', '\u{2997}', Ps),
+    ('\u{2998}', '\u{2998}', Pe),
+    ('\u{2999}', '\u{29d7}', Sm),
+    ('\u{29d8}', '\u{29d8}', Ps),
+    ('\u{29d9}', '\u{29d9}', Pe),
+    ('\u{29da}', '\u{29da}', Ps),
+    ('\u{29db}', '\u{29db}', Pe),
+    ('\u{29dc}', '\u{29fb}', Sm),
+    ('\u{29fc}', '\u{29fc}', Ps),
+    ('\u{29fd}', '\u{29fd}', Pe),
+    ('\u{29fe}', '\u{2aff}', Sm),
+    ('\u{2b00}', '\u{2b2f}', So),
+    ('\u{2b30}', '\u{2b44}', Sm),
+    ('\u{2b45}', '\u{2b46}', So),
+    ('\u{2b47}', '\u{2b4c}', Sm),
+    ('\u{2b4d}', '\u{2b73}', So),
+    ('\u{2b76}', '\u{2b95}', So),
+    ('\u{2b97}', '\u{2bff}', So),
+    ('\u{2c00}', '\u{2c2f}', Lu),
+    ('\u{2c30}', '\u{2c5f}', Ll),
+    ('\u{2c60}', '\u{2c60}', Lu),
+    ('\u{2c61}', '\u{2c61}', Ll),
+    ('\u{2c62}', '\u{2c64}', Lu),
+    ('\u{2c65}', '\u{2c66}', Ll),
+    ('\u{2c67}', '\u{2c67}', Lu),
+    ('\u{2c68}', '\u{2c68}', Ll),
+    ('\u{2c69}', '\u{2c69}', Lu),
+    ('\u{2c6a}', '\u{2c6a}', Ll),
+    ('\u{2c6b}', '\u{2c6b}', Lu),
+    ('\u{2c6c}', '\u{2c6c}', Ll),
+    ('\u{2c6d}', '\u{2c70}', Lu),
+    ('\u{2c71}', '\u{2c71}', Ll),
+    ('\u{2c72}', '\u{2c72}', Lu),
+    ('\u{2c73}', '\u{2c74}', Ll),
+    ('\u{2c75}', '\u{2c75}', Lu),
+    ('\u{2c76}', '\u{2c7b}', Ll),
+    ('\u{2c7c}', '\u{2c7d}', Lm),
+    ('\u{2c7e}', '\u{2c80}', Lu),
+    ('\u{2c81}', '\u{2c81}', Ll),
+    ('\u{2c82}', '\u{2c82}', Lu),
+    ('\u{2c83}', '\u{2c83}', Ll),
+    ('\u{2c84}', '\u{2c84}', Lu),
+    ('\u{2c85}', '\u{2c85}', Ll),
+    ('\u{2c86}', '\u{2c86}', Lu),
+    ('\u{2c87}', '\u{2c87}', Ll),
+    ('\u{2c88}', '\u{2c88}', Lu),
+    ('\u{2c89}', '\u{2c89}', Ll),
+    ('\u{2c8a}', '\u{2c8a}', Lu),
+    ('\u{2c8b}', '\u{2c8b}', Ll),
+    ('\u{2c8c}', '\u{2c8c}', Lu),
+    ('\u{2c8d}', '\u{2c8d}', Ll),
+    ('\u{2c8e}', '\u{2c8e}', Lu),
+    ('\u{2c8f}', '\u{2c8f}', Ll),
+    ('\u{2c90}', '\u{2c90}', Lu),
+    ('\u{2c91}', '\u{2c91}', Ll),
+    ('\u{2c92}', '\u{2c92}', Lu),
+    ('\u{2c93}', '\u{2c93}', Ll),
+    ('\u{2c94}', '\u{2c94}', Lu),
+    ('\u{2c95}', '\u{2c95}', Ll),
+    ('\u{2c96}', '\u{2c96}', Lu),
+    ('\u{2c97}', '\u{2c97}', Ll),
+    ('\u{2c98}', '\u{2c98}', Lu),
+    ('\u{2c99}', '\u{2c99}', Ll),
+    ('\u{2c9a}', '\u{2c9a}', Lu),
+    ('\u{2c9b}', '\u{2c9b}', Ll),
+    ('\u{2c9c}', '\u{2c9c}', Lu),
+    ('\u{2c9d}', '\u{2c9d}', Ll),
+    ('\u{2c9e}', '\u{2c9e}', Lu),
+    ('\u{2c9f}', '\u{2c9f}', Ll),
+    ('\u{2ca0}', '\u{2ca0}', Lu),
+    ('\u{2ca1}', '\u{2ca1}', Ll),
+    ('\u{2ca2}', '\u{2ca2}', Lu),
+    ('\u{2ca3}', '\u{2ca3}', Ll),
+    ('\u{2ca4}', '\u{2ca4}', Lu),
+    ('\u{2ca5}', '\u{2ca5}', Ll),
+    ('\u{2ca6}', '\u{2ca6}', Lu),
+    ('\u{2ca7}', '\u{2ca7}', Ll),
+    ('\u{2ca8}', '\u{2ca8}', Lu),
+    ('\u{2ca9}', '\u{2ca9}', Ll),
+    ('\u{2caa}', '\u{2caa}', Lu),
+    ('\u{2cab}', '\u{2cab}', Ll),
+    ('\u{2cac}', '\u{2cac}', Lu),
+    ('\u{2cad}', '\u{2cad}', Ll),
+    ('\u{2cae}', '\u{2cae}', Lu),
+    ('\u{2caf}', '\u{2caf}', Ll),
+    ('\u{2cb0}', '\u{2cb0}', Lu),
+    ('\u{2cb1}', '\u{2cb1}', Ll),
+    ('\u{2cb2}', '\u{2cb2}', Lu),
+    ('\u{2cb3}', '\u{2cb3}', Ll),
+    ('\u{2cb4}', '\u{2cb4}', Lu),
+    ('\u{2cb5}', '\u{2cb5}', Ll),
+    ('\u{2cb6}', '\u{2cb6}', Lu),
+    ('\u{2cb7}', '\u{2cb7}', Ll),
+    ('\u{2cb8}', '\u{2cb8}', Lu),
+    ('\u{2cb9}', '\u{2cb9}', Ll),
+    ('\u{2cba}', '\u{2cba}', Lu),
+    ('\u{2cbb}', '\u{2cbb}', Ll),
+    ('\u{2cbc}', '\u{2cbc}', Lu),
+    ('\u{2cbd}', '\u{2cbd}', Ll),
+    ('\u{2cbe}', '\u{2cbe}', Lu),
+    ('\u{2cbf}', '\u{2cbf}', Ll),
+    ('\u{2cc0}', '\u{2cc0}', Lu),
+    ('\u{2cc1}', '\u{2cc1}', Ll),
+    ('\u{2cc2}', '\u{2cc2}', Lu),
+    ('\u{2cc3}', '\u{2cc3}', Ll),
+    ('\u{2cc4}', '\u{2cc4}', Lu),
+    ('\u{2cc5}', '\u{2cc5}', Ll),
+    ('\u{2cc6}', '\u{2cc6}', Lu),
+    ('\u{2cc7}', '\u{2cc7}', Ll),
+    ('\u{2cc8}', '\u{2cc8}', Lu),
+    ('\u{2cc9}', '\u{2cc9}', Ll),
+    ('\u{2cca}', '\u{2cca}', Lu),
+    ('\u{2ccb}', '\u{2ccb}', Ll),
+    ('\u{2ccc}', '\u{2ccc}', Lu),
+    ('\u{2ccd}', '\u{2ccd}', Ll),
+    ('\u{2cce}', '\u{2cce}', Lu),
+    ('\u{2ccf}', '\u{2ccf}', Ll),
+    ('\u{2cd0}', '\u{2cd0}', Lu),
+    ('\u{2cd1}', '\u{2cd1}', Ll),
+    ('\u{2cd2}', '\u{2cd2}', Lu),
+    ('\u{2cd3}', '\u{2cd3}', Ll),
+    ('\u{2cd4}', '\u{2cd4}', Lu),
+    ('\u{2cd5}', '\u{2cd5}', Ll),
+    ('\u{2cd6}', '\u{2cd6}', Lu),
+    ('\u{2cd7}', '\u{2cd7}', Ll),
+    ('\u{2cd8}', '\u{2cd8}', Lu),
+    ('\u{2cd9}', '\u{2cd9}', Ll),
+    ('\u{2cda}', '\u{2cda}', Lu),
+    ('\u{2cdb}', '\u{2cdb}', Ll),
+    ('\u{2cdc}', '\u{2cdc}', Lu),
+    ('\u{2cdd}', '\u{2cdd}', Ll),
+    ('\u{2cde}', '\u{2cde}', Lu),
+    ('\u{2cdf}', '\u{2cdf}', Ll),
+    ('\u{2ce0}', '\u{2ce0}', Lu),
+    ('\u{2ce1}', '\u{2ce1}', Ll),
+    ('\u{2ce2}', '\u{2ce2}', Lu),
+    ('\u{2ce3}', '\u{2ce4}', Ll),
+    ('\u{2ce5}', '\u{2cea}', So),
+    ('\u{2ceb}', '\u{2ceb}', Lu),
+    ('\u{2cec}', '\u{2cec}', Ll),
+    ('\u{2ced}', '\u{2ced}', Lu),
+    ('\u{2cee}', '\u{2cee}', Ll),
+    ('\u{2cef}', '\u{2cf1}', Mn),
+    ('\u{2cf2}', '\u{2cf2}', Lu),
+    ('\u{2cf3}', '\u{2cf3}', Ll),
+    ('\u{2cf9}', '\u{2cfc}', Po),
+    ('\u{2cfd}', '\u{2cfd}', No),
+    ('\u{2cfe}', '\u{2cff}', Po),
+    ('\u{2d00}', '\u{2d25}', Ll),
+    ('\u{2d27}', '\u{2d27}', Ll),
+    ('\u{2d2d}', '\u{2d2d}', Ll),
+    ('\u{2d30}', '\u{2d67}', Lo),
+    ('\u{2d6f}', '\u{2d6f}', Lm),
+    ('\u{2d70}', '\u{2d70}', Po),
+    ('\u{2d7f}', '\u{2d7f}', Mn),
+    ('\u{2d80}', '\u{2d96}', Lo),
+    ('\u{2da0}', '\u{2da6}', Lo),
+    ('\u{2da8}', '\u{2dae}', Lo),
+    ('\u{2db0}', '\u{2db6}', Lo),
+    ('\u{2db8}', '\u{2dbe}', Lo),
+    ('\u{2dc0}', '\u{2dc6}', Lo),
+    ('\u{2dc8}', '\u{2dce}', Lo),
+    ('\u{2dd0}', '\u{2dd6}', Lo),
+    ('\u{2dd8}', '\u{2dde}', Lo),
+    ('\u{2de0}', '\u{2dff}', Mn),
+    ('\u{2e00}', '\u{2e01}', Po),
+    ('\u{2e02}', '\u{2e02}', Pi),
+    ('\u{2e03}', '\u{2e03}', Pf),
+    ('\u{2e04}', '\u{2e04}', Pi),
+    ('\u{2e05}', '\u{2e05}', Pf),
+    ('\u{2e06}', '\u{2e08}', Po),
+    ('\u{2e09}', '\u{2e09}', Pi),
+    ('\u{2e0a}', '\u{2e0a}', Pf),
+    ('\u{2e0b}', '\u{2e0b}', Po),
+    ('\u{2e0c}', '\u{2e0c}', Pi),
+    ('\u{2e0d}', '\u{2e0d}', Pf),
+    ('\u{2e0e}', '\u{2e16}', Po),
+    ('\u{2e17}', '\u{2e17}', Pd),
+    ('\u{2e18}', '\u{2e19}', Po),
+    ('\u{2e1a}', '\u{2e1a}', Pd),
+    ('\u{2e1b}', '\u{2e1b}', Po),
+    ('\u{2e1c}', '\u{2e1c}', Pi),
+    ('\u{2e1d}', '\u{2e1d}', Pf),
+    ('\u{2e1e}', '\u{2e1f}', Po),
+    ('\u{2e20}', '\u{2e20}', Pi),
+    ('\u{2e21}', '\u{2e21}', Pf),
+    ('\u{2e22}', '\u{2e22}', Ps),
+    ('\u{2e23}', '\u{2e23}', Pe),
+    ('\u{2e24}', '\u{2e24}', Ps),
+    ('\u{2e25}', '\u{2e25}', Pe),
+    ('\u{2e26}', '\u{2e26}', Ps),
+    ('\u{2e27}', '\u{2e27}', Pe),
+    ('\u{2e28}', '\u{2e28}', Ps),
+    ('\u{2e29}', '\u{2e29}', Pe),
+    ('\u{2e2a}', '\u{2e2e}', Po),
+    ('\u{2e2f}', '\u{2e2f}', Lm),
+    ('\u{2e30}', '\u{2e39}', Po),
+    ('\u{2e3a}', '\u{2e3b}', Pd),
+    ('\u{2e3c}', '\u{2e3f}', Po),
+    ('\u{2e40}', '\u{2e40}', Pd),
+    ('\u{2e41}', '\u{2e41}', Po),
+    ('\u{2e42}', '\u{2e42}', Ps),
+    ('\u{2e43}', '\u{2e4f}', Po),
+    ('\u{2e50}', '\u{2e51}', So),
+    ('\u{2e52}', '\u{2e54}', Po),
+    ('\u{2e55}', '\u{2e55}', Ps),
+    ('\u{2e56}', '\u{2e56}', Pe),
+    ('\u{2e57}', '\u{2e57}', Ps),
+    ('\u{2e58}', '\u{2e58}', Pe),
+    ('\u{2e59}', '\u{2e59}', Ps),
+    ('\u{2e5a}', '\u{2e5a}', Pe),
+    ('\u{2e5b}', '\u{2e5b}', Ps),
+    ('\u{2e5c}', '\u{2e5c}', Pe),
+    ('\u{2e5d}', '\u{2e5d}', Pd),
+    ('\u{2e80}', '\u{2e99}', So),
+    ('\u{2e9b}', '\u{2ef3}', So),
+    ('\u{2f00}', '\u{2fd5}', So),
+    ('\u{2ff0}', '\u{2fff}', So),
+    ('\u{3000}', '\u{3000}', Zs),
+    ('\u{3001}', '\u{3003}', Po),
+    ('\u{3004}', '\u{3004}', So),
+    ('\u{3005}', '\u{3005}', Lm),
+    ('\u{3006}', '\u{3006}', Lo),
+    ('\u{3007}', '\u{3007}', Nl),
+    ('\u{3008}', '\u{3008}', Ps),
+    ('\u{3009}', '\u{3009}', Pe),
+    ('\u{300a}', '\u{300a}', Ps),
+    ('\u{300b}', '\u{300b}', Pe),
+    ('\u{300c}', '\u{300c}', Ps),
+    ('\u{300d}', '\u{300d}', Pe),
+    ('\u{300e}', '\u{300e}', Ps),
+    ('\u{300f}', '\u{300f}', Pe),
+    ('\u{3010}', '\u{3010}', Ps),
+    ('\u{3011}', '\u{3011}', Pe),
+    ('\u{3012}', '\u{3013}', So),
+    ('\u{3014}', '\u{3014}', Ps),
+    ('\u{3015}', '\u{3015}', Pe),
+    ('\u{3016}', '\u{3016}', Ps),
+    ('\u{3017}', '\u{3017}', Pe),
+    ('\u{3018}', '\u{3018}', Ps),
+    ('\u{3019}', '\u{3019}', Pe),
+    ('\u{301a}', '\u{301a}', Ps),
+    ('\u{301b}', '\u{301b}', Pe),
+    ('\u{301c}', '\u{301c}', Pd),
+    ('\u{301d}', '\u{301d}', Ps),
+    ('\u{301e}', '\u{301f}', Pe),
+    ('\u{3020}', '\u{3020}', So),
+    ('\u{3021}', '\u{3029}', Nl),
+    ('\u{302a}', '\u{302d}', Mn),
+    ('\u{302e}', '\u{302f}', Mc),
+    ('\u{3030}', '\u{3030}', Pd),
+    ('\u{3031}', '\u{3035}', Lm),
+    ('\u{3036}', '\u{3037}', So),
+    ('\u{3038}', '\u{303a}', Nl),
+    ('\u{303b}', '\u{303b}', Lm),
+    ('\u{303c}', '\u{303c}', Lo),
+    ('\u{303d}', '\u{303d}', Po),
+    ('\u{303e}', '\u{303f}', So),
+    ('\u{3041}', '\u{3096}', Lo),
+    ('\u{3099}', '\u{309a}', Mn),
+    ('\u{309b}', '\u{309c}', Sk),
+    ('\u{309d}', '\u{309e}', Lm),
+    ('\u{309f}', '\u{309f}', Lo),
+    ('\u{30a0}', '\u{30a0}', Pd),
+    ('\u{30a1}', '\u{30fa}', Lo),
+    ('\u{30fb}', '\u{30fb}', Po),
+    ('\u{30fc}', '\u{30fe}', Lm),
+    ('\u{30ff}', '\u{30ff}', Lo),
+    ('\u{3105}', '\u{312f}', Lo),
+    ('\u{3131}', '\u{318e}', Lo),
+    ('\u{3190}', '\u{3191}', So),
+    ('\u{3192}', '\u{3195}', No),
+    ('\u{3196}', '\u{319f}', So),
+    ('\u{31a0}', '\u{31bf}', Lo),
+    ('\u{31c0}', '\u{31e5}', So),
+    ('\u{31ef}', '\u{31ef}', So),
+    ('\u{31f0}', '\u{31ff}', Lo),
+    ('\u{3200}', '\u{321e}', So),
+    ('\u{3220}', '\u{3229}', No),
+    ('\u{322a}', '\u{3247}', So),
+    ('\u{3248}', '\u{324f}', No),
+    ('\u{3250}', '\u{3250}', So),
+    ('\u{3251}', '\u{325f}', No),
+    ('\u{3260}', '\u{327f}', So),
+    ('\u{3280}', '\u{3289}', No),
+    ('\u{328a}', '\u{32b0}', So),
+    ('\u{32b1}', '\u{32bf}', No),
+    ('\u{32c0}', '\u{33ff}', So),
+    ('\u{3400}', '\u{4dbf}', Lo),
+    ('\u{4dc0}', '\u{4dff}', So),
+    ('\u{4e00}', '\u{a014}', Lo),
+    ('\u{a015}', '\u{a015}', Lm),
+    ('\u{a016}', '\u{a48c}', Lo),
+    ('\u{a490}', '\u{a4c6}', So),
+    ('\u{a4d0}', '\u{a4f7}', Lo),
+    ('\u{a4f8}', '\u{a4fd}', Lm),
+    ('\u{a4fe}', '\u{a4ff}', Po),
+    ('\u{a500}', '\u{a60b}', Lo),
+    ('\u{a60c}', '\u{a60c}', Lm),
+    ('\u{a60d}', '\u{a60f}', Po),
+    ('\u{a610}', '\u{a61f}', Lo),
+    ('\u{a620}', '\u{a629}', Nd),
+    ('\u{a62a}', '\u{a62b}', Lo),
+    ('\u{a640}', '\u{a640}', Lu),
+    ('\u{a641}', '\u{a641}', Ll),
+    ('\u{a642}', '\u{a642}', Lu),
+    ('\u{a643}', '\u{a643}', Ll),
+    ('\u{a644}', '\u{a644}', Lu),
+    ('\u{a645}', '\u{a645}', Ll),
+    ('\u{a646}', '\u{a646}', Lu),
+    ('\u{a647}', '\u{a647}', Ll),
+    ('\u{a648}', '\u{a648}', Lu),
+    ('\u{a649}', '\u{a649}', Ll),
+    ('\u{a64a}', '\u{a64a}', Lu),
+    ('\u{a64b}', '\u{a64b}', Ll),
+    ('\u{a64c}', '\u{a64c}', Lu),
+    ('\u{a64d}', '\u{a64d}', Ll),
+    ('\u{a64e}', '\u{a64e}', Lu),
+    ('\u{a64f}', '\u{a64f}', Ll),
+    ('\u{a650}', '\u{a650}', Lu),
+    ('\u{a651}', '\u{a651}', Ll),
+    ('\u{a652}', '\u{a652}', Lu),
+    ('\u{a653}', '\u{a653}', Ll),
+    ('\u{a654}', '\u{a654}', Lu),
+    ('\u{a655}', '\u{a655}', Ll),
+    ('\u{a656}', '\u{a656}', Lu),
+    ('\u{a657}', '\u{a657}', Ll),
+    ('\u{a658}', '\u{a658}', Lu),
+    ('\u{a659}', '\u{a659}', Ll),
+    ('\u{a65a}', '\u{a65a}', Lu),
+    ('\u{a65b}', '\u{a65b}', Ll),
+    ('\u{a65c}', '\u{a65c}', Lu),
+    ('\u{a65d}', '\u{a65d}', Ll),
+    ('\u{a65e}', '\u{a65e}', Lu),
+    ('\u{a65f}', '\u{a65f}', Ll),
+    ('\u{a660}', '\u{a660}', Lu),
+    ('\u{a661}', '\u{a661}', Ll),
+    ('\u{a662}', '\u{a662}', Lu),
+    ('\u{a663}', '\u{a663}', Ll),
+    ('\u{a664}', '\u{a664}', Lu),
+    ('\u{a665}', '\u{a665}', Ll),
+    ('\u{a666}', '\u{a666}', Lu),
+    ('\u{a667}', '\u{a667}', Ll),
+    ('\u{a668}', '\u{a668}', Lu),
+    ('\u{a669}', '\u{a669}', Ll),
+    ('\u{a66a}', '\u{a66a}', Lu),
+    ('\u{a66b}', '\u{a66b}', Ll),
+    ('\u{a66c}', '\u{a66c}', Lu),
+    ('\u{a66d}', '\u{a66d}', Ll),
+    ('\u{a66e}', '\u{a66e}', Lo),
+    ('\u{a66f}', '\u{a66f}', Mn),
+    ('\u{a670}', '\u{a672}', Me),
+    ('\u{a673}', '\u{a673}', Po),
+    ('\u{a674}', '\u{a67d}', Mn),
+    ('\u{a67e}', '\u{a67e}', Po),
+    ('\u{a67f}', '\u{a67f}', Lm),
+    ('\u{a680}', '\u{a680}', Lu),
+    ('\u{a681}', '\u{a681}', Ll),
+    ('\u{a682}', '\u{a682}', Lu),
+    ('\u{a683}', '\u{a683}', Ll),
+    ('\u{a684}', '\u{a684}', Lu),
+    ('\u{a685}', '\u{a685}', Ll),
+    ('\u{a686}', '\u{a686}', Lu),
+    ('\u{a687}', '\u{a687}', Ll),
+    ('\u{a688}', '\u{a688}', Lu),
+    ('\u{a689}', '\u{a689}', Ll),
+    ('\u{a68a}', '\u{a68a}', Lu),
+    ('\u{a68b}', '\u{a68b}', Ll),
+    ('\u{a68c}', '\u{a68c}', Lu),
+    ('\u{a68d}', '\u{a68d}', Ll),
+    ('\u{a68e}', '\u{a68e}', Lu),
+    ('\u{a68f}', '\u{a68f}', Ll),
+    ('\u{a690}', '\u{a690}', Lu),
+    ('\u{a691}', '\u{a691}', Ll),
+    ('\u{a692}', '\u{a692}', Lu),
+    ('\u{a693}', '\u{a693}', Ll),
+    ('\u{a694}', '\u{a694}', Lu),
+    ('\u{a695}', '\u{a695}', Ll),
+    ('\u{a696}', '\u{a696}', Lu),
+    ('\u{a697}', '\u{a697}', Ll),
+    ('\u{a698}', '\u{a698}', Lu),
+    ('\u{a699}', '\u{a699}', Ll),
+    ('\u{a69a}', '\u{a69a}', Lu),
+    ('\u{a69b}', '\u{a69b}', Ll),
+    ('\u{a69c}', '\u{a69d}', Lm),
+    ('\u{a69e}', '\u{a69f}', Mn),
+    ('\u{a6a0}', '\u{a6e5}', Lo),
+    ('\u{a6e6}', '\u{a6ef}', Nl),
+    ('\u{a6f0}', '\u{a6f1}', Mn),
+    ('\u{a6f2}', '\u{a6f7}', Po),
+    ('\u{a700}', '\u{a716}', Sk),
+    ('\u{a717}', '\u{a71f}', Lm),
+    ('\u{a720}', '\u{a721}', Sk),
+    ('\u{a722}', '\u{a722}', Lu),
+    ('\u{a723}', '\u{a723}', Ll),
+    ('\u{a724}', '\u{a724}', Lu),
+    ('\u{a725}', '\u{a725}', Ll),
+    ('\u{a726}', '\u{a726}', Lu),
+    ('\u{a727}', '\u{a727}', Ll),
+    ('\u{a728}', '\u{a728}', Lu),
+    ('\u{a729}', '\u{a729}', Ll),
+    ('\u{a72a}', '\u{a72a}', Lu),
+    ('\u{a72b}', '\u{a72b}', Ll),
+    ('\u{a72c}', '\u{a72c}', Lu),
+    ('\u{a72d}', '\u{a72d}', Ll),
+    ('\u{a72e}', '\u{a72e}', Lu),
+    ('\u{a72f}', '\u{a731}', Ll),
+    ('\u{a732}', '\u{a732}', Lu),
+    ('\u{a733}', '\u{a733}', Ll),
+    ('\u{a734}', '\u{a734}', Lu),
+    ('\u{a735}', '\u{a735}', Ll),
+    ('\u{a736}', '\u{a736}', Lu),
+    ('\u{a737}', '\u{a737}', Ll),
+    ('\u{a738}', '\u{a738}', Lu),
+    ('\u{a739}', '\u{a739}', Ll),
+    ('\u{a73a}', '\u{a73a}', Lu),
+    ('\u{a73b}', '\u{a73b}', Ll),
+    ('\u{a73c}', '\u{a73c}', Lu),
+    ('\u{a73d}', '\u{a73d}', Ll),
+    ('\u{a73e}', '\u{a73e}', Lu),
+    ('\u{a73f}', '\u{a73f}', Ll),
+    ('\u{a740}', '\u{a740}', Lu),
+    ('\u{a741}', '\u{a741}', Ll),
+    ('\u{a742}', '\u{a742}', Lu),
+    ('\u{a743}', '\u{a743}', Ll),
+    ('\u{a744}', '\u{a744}', Lu),
+    ('\u{a745}', '\u{a745}', Ll),
+    ('\u{a746}', '\u{a746}', Lu),
+    ('\u{a747}', '\u{a747}', Ll),
+    ('\u{a748}', '\u{a748}', Lu),
+    ('\u{a749}', '\u{a749}', Ll),
+    ('\u{a74a}', '\u{a74a}', Lu),
+    ('\u{a74b}', '\u{a74b}', Ll),
+    ('\u{a74c}', '\u{a74c}', Lu),
+    ('\u{a74d}', '\u{a74d}', Ll),
+    ('\u{a74e}', '\u{a74e}', Lu),
+    ('\u{a74f}', '\u{a74f}', Ll),
+    ('\u{a750}', '\u{a750}', Lu),
+    ('\u{a751}', '\u{a751}', Ll),
+    ('\u{a752}', '\u{a752}', Lu),
+    ('\u{a753}', '\u{a753}', Ll),
+    ('\u{a754}', '\u{a754}', Lu),
+    ('\u{a755}', '\u{a755}', Ll),
+    ('\u{a756}', '\u{a756}', Lu),
+    ('\u{a757}', '\u{a757}', Ll),
+    ('\u{a758}', '\u{a758}', Lu),
+    ('\u{a759}', '\u{a759}', Ll),
+    ('\u{a75a}', '\u{a75a}', Lu),
+    ('\u{a75b}', '\u{a75b}', Ll),
+    ('\u{a75c}', '\u{a75c}', Lu),
+    ('\u{a75d}', '\u{a75d}', Ll),
+    ('\u{a75e}', '\u{a75e}', Lu),
+    ('\u{a75f}', '\u{a75f}', Ll),
+    ('\u{a760}', '\u{a760}', Lu),
+    ('\u{a761}', '\u{a761}', Ll),
+    ('\u{a762}', '\u{a762}', Lu),
+    ('\u{a763}', '\u{a763}', Ll),
+    ('\u{a764}', '\u{a764}', Lu),
+    ('\u{a765}', '\u{a765}', Ll),
+    ('\u{a766}', '\u{a766}', Lu),
+    ('\u{a767}', '\u{a767}', Ll),
+    ('\u{a768}', '\u{a768}', Lu),
+    ('\u{a769}', '\u{a769}', Ll),
+    ('\u{a76a}', '\u{a76a}', Lu),
+    ('\u{a76b}', '\u{a76b}', Ll),
+    ('\u{a76c}', '\u{a76c}', Lu),
+    ('\u{a76d}', '\u{a76d}', Ll),
+    ('\u{a76e}', '\u{a76e}', Lu),
+    ('\u{a76f}', '\u{a76f}', Ll),
+    ('\u{a770}', '\u{a770}', Lm),
+    ('\u{a771}', '\u{a778}', Ll),
+    ('\u{a779}', '\u{a779}', Lu),
+    ('\u{a77a}', '\u{a77a}', Ll),
+    ('\u{a77b}', '\u{a77b}', Lu),
+    ('\u{a77c}', '\u{a77c}', Ll),
+    ('\u{a77d}', '\u{a77e}', Lu),
+    ('\u{a77f}', '\u{a77f}', Ll),
+    ('\u{a780}', '\u{a780}', Lu),
+    ('\u{a781}', '\u{a781}', Ll),
+    ('\u{a782}', '\u{a782}', Lu),
+    ('\u{a783}', '\u{a783}', Ll),
+    ('\u{a784}', '\u{a784}', Lu),
+    ('\u{a785}', '\u{a785}', Ll),
+    ('\u{a786}', '\u{a786}', Lu),
+    ('\u{a787}', '\u{a787}', Ll),
+    ('\u{a788}', '\u{a788}', Lm),
+    ('\u{a789}', '\u{a78a}', Sk),
+    ('\u{a78b}', '\u{a78b}', Lu),
+    ('\u{a78c}', '\u{a78c}', Ll),
+    ('\u{a78d}', '\u{a78d}', Lu),
+    ('\u{a78e}', '\u{a78e}', Ll),
+    ('\u{a78f}', '\u{a78f}', Lo),
+    ('\u{a790}', '\u{a790}', Lu),
+    ('\u{a791}', '\u{a791}', Ll),
+    ('\u{a792}', '\u{a792}', Lu),
+    ('\u{a793}', '\u{a795}', Ll),
+    ('\u{a796}', '\u{a796}', Lu),
+    ('\u{a797}', '\u{a797}', Ll),
+    ('\u{a798}', '\u{a798}', Lu),
+    ('\u{a799}', '\u{a799}', Ll),
+    ('\u{a79a}', '\u{a79a}', Lu),
+    ('\u{a79b}', '\u{a79b}', Ll),
+    ('\u{a79c}', '\u{a79c}', Lu),
+    ('\u{a79d}', '\u{a79d}', Ll),
+    ('\u{a79e}', '\u{a79e}', Lu),
+    ('\u{a79f}', '\u{a79f}', Ll),
+    ('\u{a7a0}', '\u{a7a0}', Lu),
+    ('\u{a7a1}', '\u{a7a1}', Ll),
+    ('\u{a7a2}', '\u{a7a2}', Lu),
+    ('\u{a7a3}', '\u{a7a3}', Ll),
+    ('\u{a7a4}', '\u{a7a4}', Lu),
+    ('\u{a7a5}', '\u{a7a5}', Ll),
+    ('\u{a7a6}', '\u{a7a6}', Lu),
+    ('\u{a7a7}', '\u{a7a7}', Ll),
+    ('\u{a7a8}', '\u{a7a8}', Lu),
+    ('\u{a7a9}', '\u{a7a9}', Ll),
+    ('\u{a7aa}', '\u{a7ae}', Lu),
+    ('\u{a7af}', '\u{a7af}', Ll),
+    ('\u{a7b0}', '\u{a7b4}', Lu),
+    ('\u{a7b5}', '\u{a7b5}', Ll),
+    ('\u{a7b6}', '\u{a7b6}', Lu),
+    ('\u{a7b7}', '\u{a7b7}', Ll),
+    ('\u{a7b8}', '\u{a7b8}', Lu),
+    ('\u{a7b9}', '\u{a7b9}', Ll),
+    ('\u{a7ba}', '\u{a7ba}', Lu),
+    ('\u{a7bb}', '\u{a7bb}', Ll),
+    ('\u{a7bc}', '\u{a7bc}', Lu),
+    ('\u{a7bd}', '\u{a7bd}', Ll),
+    ('\u{a7be}', '\u{a7be}', Lu),
+    ('\u{a7bf}', '\u{a7bf}', Ll),
+    ('\u{a7c0}', '\u{a7c0}', Lu),
+    ('\u{a7c1}', '\u{a7c1}', Ll),
+    ('\u{a7c2}', '\u{a7c2}', Lu),
+    ('\u{a7c3}', '\u{a7c3}', Ll),
+    ('\u{a7c4}', '\u{a7c7}', Lu),
+    ('\u{a7c8}', '\u{a7c8}', Ll),
+    ('\u{a7c9}', '\u{a7c9}', Lu),
+    ('\u{a7ca}', '\u{a7ca}', Ll),
+    ('\u{a7cb}', '\u{a7cc}', Lu),
+    ('\u{a7cd}', '\u{a7cd}', Ll),
+    ('\u{a7d0}', '\u{a7d0}', Lu),
+    ('\u{a7d1}', '\u{a7d1}', Ll),
+    ('\u{a7d3}', '\u{a7d3}', Ll),
+    ('\u{a7d5}', '\u{a7d5}', Ll),
+    ('\u{a7d6}', '\u{a7d6}', Lu),
+    ('\u{a7d7}', '\u{a7d7}', Ll),
+    ('\u{a7d8}', '\u{a7d8}', Lu),
+    ('\u{a7d9}', '\u{a7d9}', Ll),
+    ('\u{a7da}', '\u{a7da}', Lu),
+    ('\u{a7db}', '\u{a7db}', Ll),
+    ('\u{a7dc}', '\u{a7dc}', Lu),
+    ('\u{a7f2}', '\u{a7f4}', Lm),
+    ('\u{a7f5}', '\u{a7f5}', Lu),
+    ('\u{a7f6}', '\u{a7f6}', Ll),
+    ('\u{a7f7}', '\u{a7f7}', Lo),
+    ('\u{a7f8}', '\u{a7f9}', Lm),
+    ('\u{a7fa}', '\u{a7fa}', Ll),
+    ('\u{a7fb}', '\u{a801}', Lo),
+    ('\u{a802}', '\u{a802}', Mn),
+    ('\u{a803}', '\u{a805}', Lo),
+    ('\u{a806}', '\u{a806}', Mn),
+    ('\u{a807}', '\u{a80a}', Lo),
+    ('\u{a80b}', '\u{a80b}', Mn),
+    ('\u{a80c}', '\u{a822}', Lo),
+    ('\u{a823}', '\u{a824}', Mc),
+    ('\u{a825}', '\u{a826}', Mn),
+    ('\u{a827}', '\u{a827}', Mc),
+    ('\u{a828}', '\u{a82b}', So),
+    ('\u{a82c}', '\u{a82c}', Mn),
+    ('\u{a830}', '\u{a835}', No),
+    ('\u{a836}', '\u{a837}', So),
+    ('\u{a838}', '\u{a838}', Sc),
+    ('\u{a839}', '\u{a839}', So),
+    ('\u{a840}', '\u{a873}', Lo),
+    ('\u{a874}', '\u{a877}', Po),
+    ('\u{a880}', '\u{a881}', Mc),
+    ('\u{a882}', '\u{a8b3}', Lo),
+    ('\u{a8b4}', '\u{a8c3}', Mc),
+    ('\u{a8c4}', '\u{a8c5}', Mn),
+    ('\u{a8ce}', '\u{a8cf}', Po),
+    ('\u{a8d0}', '\u{a8d9}', Nd),
+    ('\u{a8e0}', '\u{a8f1}', Mn),
+    ('\u{a8f2}', '\u{a8f7}', Lo),
+    ('\u{a8f8}', '\u{a8fa}', Po),
+    ('\u{a8fb}', '\u{a8fb}', Lo),
+    ('\u{a8fc}', '\u{a8fc}', Po),
+    ('\u{a8fd}', '\u{a8fe}', Lo),
+    ('\u{a8ff}', '\u{a8ff}', Mn),
+    ('\u{a900}', '\u{a909}', Nd),
+    ('\u{a90a}', '\u{a925}', Lo),
+    ('\u{a926}', '\u{a92d}', Mn),
+    ('\u{a92e}', '\u{a92f}', Po),
+    ('\u{a930}', '\u{a946}', Lo),
+    ('\u{a947}', '\u{a951}', Mn),
+    ('\u{a952}', '\u{a953}', Mc),
+    ('\u{a95f}', '\u{a95f}', Po),
+    ('\u{a960}', '\u{a97c}', Lo),
+    ('\u{a980}', '\u{a982}', Mn),
+    ('\u{a983}', '\u{a983}', Mc),
+    ('\u{a984}', '\u{a9b2}', Lo),
+    ('\u{a9b3}', '\u{a9b3}', Mn),
+    ('\u{a9b4}', '\u{a9b5}', Mc),
+    ('\u{a9b6}', '\u{a9b9}', Mn),
+    ('\u{a9ba}', '\u{a9bb}', Mc),
+    ('\u{a9bc}', '\u{a9bd}', Mn),
+    ('\u{a9be}', '\u{a9c0}', Mc),
+    ('\u{a9c1}', '\u{a9cd}', Po),
+    ('\u{a9cf}', '\u{a9cf}', Lm),
+    ('\u{a9d0}', '\u{a9d9}', Nd),
+    ('\u{a9de}', '\u{a9df}', Po),
+    ('\u{a9e0}', '\u{a9e4}', Lo),
+    ('\u{a9e5}', '\u{a9e5}', Mn),
+    ('\u{a9e6}', '\u{a9e6}', Lm),
+    ('\u{a9e7}', '\u{a9ef}', Lo),
+    ('\u{a9f0}', '\u{a9f9}', Nd),
+    ('\u{a9fa}', '\u{a9fe}', Lo),
+    ('\u{aa00}', '\u{aa28}', Lo),
+    ('\u{aa29}', '\u{aa2e}', Mn),
+    ('\u{aa2f}', '\u{aa30}', Mc),
+    ('\u{aa31}', '\u{aa32}', Mn),
+    ('\u{aa33}', '\u{aa34}', Mc),
+    ('\u{aa35}', '\u{aa36}', Mn),
+    ('\u{aa40}', '\u{aa42}', Lo),
+    ('\u{aa43}', '\u{aa43}', Mn),
+    ('\u{aa44}', '\u{aa4b}', Lo),
+    ('\u{aa4c}', '\u{aa4c}', Mn),
+    ('\u{aa4d}', '\u{aa4d}', Mc),
+    ('\u{aa50}', '\u{aa59}', Nd),
+    ('\u{aa5c}', '\u{aa5f}', Po),
+    ('\u{aa60}', '\u{aa6f}', Lo),
+    ('\u{aa70}', '\u{aa70}', Lm),
+    ('\u{aa71}', '\u{aa76}', Lo),
+    ('\u{aa77}', '\u{aa79}', So),
+    ('\u{aa7a}', '\u{aa7a}', Lo),
+    ('\u{aa7b}', '\u{aa7b}', Mc),
+    ('\u{aa7c}', '\u{aa7c}', Mn),
+    ('\u{aa7d}', '\u{aa7d}', Mc),
+    ('\u{aa7e}', '\u{aaaf}', Lo),
+    ('\u{aab0}', '\u{aab0}', Mn),
+    ('\u{aab1}', '\u{aab1}', Lo),
+    ('\u{aab2}', '\u{aab4}', Mn),
+    ('\u{aab5}', '\u{aab6}', Lo),
+    ('\u{aab7}', '\u{aab8}', Mn),
+    ('\u{aab9}', '\u{aabd}', Lo),
+    ('\u{aabe}', '\u{aabf}', Mn),
+    ('\u{aac0}', '\u{aac0}', Lo),
+    ('\u{aac1}', '\u{aac1}', Mn),
+    ('\u{aac2}', '\u{aac2}', Lo),
+    ('\u{aadb}', '\u{aadc}', Lo),
+    ('\u{aadd}', '\u{aadd}', Lm),
+    ('\u{aade}', '\u{aadf}', Po),
+    ('\u{aae0}', '\u{aaea}', Lo),
+    ('\u{aaeb}', '\u{aaeb}', Mc),
+    ('\u{aaec}', '\u{aaed}', Mn),
+    ('\u{aaee}', '\u{aaef}', Mc),
+    ('\u{aaf0}', '\u{aaf1}', Po),
+    ('\u{aaf2}', '\u{aaf2}', Lo),
+    ('\u{aaf3}', '\u{aaf4}', Lm),
+    ('\u{aaf5}', '\u{aaf5}', Mc),
+    ('\u{aaf6}', '\u{aaf6}', Mn),
+    ('\u{ab01}', '\u{ab06}', Lo),
+    ('\u{ab09}', '\u{ab0e}', Lo),
+    ('\u{ab11}', '\u{ab16}', Lo),
+    ('\u{ab20}', '\u{ab26}', Lo),
+    ('\u{ab28}', '\u{ab2e}', Lo),
+    ('\u{ab30}', '\u{ab5a}', Ll),
+    ('\u{ab5b}', '\u{ab5b}', Sk),
+    ('\u{ab5c}', '\u{ab5f}', Lm),
+    ('\u{ab60}', '\u{ab68}', Ll),
+    ('\u{ab69}', '\u{ab69}', Lm),
+    ('\u{ab6a}', '\u{ab6b}', Sk),
+    ('\u{ab70}', '\u{abbf}', Ll),
+    ('\u{abc0}', '\u{abe2}', Lo),
+    ('\u{abe3}', '\u{abe4}', Mc),
+    ('\u{abe5}', '\u{abe5}', Mn),
+    ('\u{abe6}', '\u{abe7}', Mc),
+    ('\u{abe8}', '\u{abe8}', Mn),
+    ('\u{abe9}', '\u{abea}', Mc),
+    ('\u{abeb}', '\u{abeb}', Po),
+    ('\u{abec}', '\u{abec}', Mc),
+    ('\u{abed}', '\u{abed}', Mn),
+    ('\u{abf0}', '\u{abf9}', Nd),
+    ('\u{ac00}', '\u{d7a3}', Lo),
+    ('\u{d7b0}', '\u{d7c6}', Lo),
+    ('\u{d7cb}', '\u{d7fb}', Lo),
+    ('\u{e000}', '\u{f8ff}', Co),
+    ('\u{f900}', '\u{fa6d}', Lo),
+    ('\u{fa70}', '\u{fad9}', Lo),
+    ('\u{fb00}', '\u{fb06}', Ll),
+    ('\u{fb13}', '\u{fb17}', Ll),
+    ('\u{fb1d}', '\u{fb1d}', Lo),
+    ('\u{fb1e}', '\u{fb1e}', Mn),
+    ('\u{fb1f}', '\u{fb28}', Lo),
+    ('\u{fb29}', '\u{fb29}', Sm),
+    ('\u{fb2a}', '\u{fb36}', Lo),
+    ('\u{fb38}', '\u{fb3c}', Lo),
+    ('\u{fb3e}', '\u{fb3e}', Lo),
+    ('\u{fb40}', '\u{fb41}', Lo),
+    ('\u{fb43}', '\u{fb44}', Lo),
+    ('\u{fb46}', '\u{fbb1}', Lo),
+    ('\u{fbb2}', '\u{fbc2}', Sk),
+    ('\u{fbd3}', '\u{fd3d}', Lo),
+    ('\u{fd3e}', '\u{fd3e}', Pe),
+    ('\u{fd3f}', '\u{fd3f}', Ps),
+    ('\u{fd40}', '\u{fd4f}', So),
+    ('\u{fd50}', '\u{fd8f}', Lo),
+    ('\u{fd92}', '\u{fdc7}', Lo),
+    ('\u{fdcf}', '\u{fdcf}', So),
+    ('\u{fdf0}', '\u{fdfb}', Lo),
+    ('\u{fdfc}', '\u{fdfc}', Sc),
+    ('\u{fdfd}', '\u{fdff}', So),
+    ('\u{fe00}', '\u{fe0f}', Mn),
+    ('\u{fe10}', '\u{fe16}', Po),
+    ('\u{fe17}', '\u{fe17}', Ps),
+    ('\u{fe18}', '\u{fe18}', Pe),
+    ('\u{fe19}', '\u{fe19}', Po),
+    ('\u{fe20}', '\u{fe2f}', Mn),
+    ('\u{fe30}', '\u{fe30}', Po),
+    ('\u{fe31}', '\u{fe32}', Pd),
+    ('\u{fe33}', '\u{fe34}', Pc),
+    ('\u{fe35}', '\u{fe35}', Ps),
+    ('\u{fe36}', '\u{fe36}', Pe),
+    ('\u{fe37}', '\u{fe37}', Ps),
+    ('\u{fe38}', '\u{fe38}', Pe),
+    ('\u{fe39}', '\u{fe39}', Ps),
+    ('\u{fe3a}', '\u{fe3a}', Pe),
+    ('\u{fe3b}', '\u{fe3b}', Ps),
+    ('\u{fe3c}', '\u{fe3c}', Pe),
+    ('\u{fe3d}', '\u{fe3d}', Ps),
+    ('\u{fe3e}', '\u{fe3e}', Pe),
+    ('\u{fe3f}', '\u{fe3f}', Ps),
+    ('\u{fe40}', '\u{fe40}', Pe),
+    ('\u{fe41}', '\u{fe41}', Ps),
+    ('\u{fe42}', '\u{fe42}', Pe),
+    ('\u{fe43}', '\u{fe43}', Ps),
+    ('\u{fe44}', '\u{fe44}', Pe),
+    ('\u{fe45}', '\u{fe46}', Po),
+    ('\u{fe47}', '\u{fe47}', Ps),
+    ('\u{fe48}', '\u{fe48}', Pe),
+    ('\u{fe49}', '\u{fe4c}', Po),
+    ('\u{fe4d}', '\u{fe4f}', Pc),
+    ('\u{fe50}', '\u{fe52}', Po),
+    ('\u{fe54}', '\u{fe57}', Po),
+    ('\u{fe58}', '\u{fe58}', Pd),
+    ('\u{fe59}', '\u{fe59}', Ps),
+    ('\u{fe5a}', '\u{fe5a}', Pe),
+    ('\u{fe5b}', '\u{fe5b}', Ps),
+    ('\u{fe5c}', '\u{fe5c}', Pe),
+    ('\u{fe5d}', '\u{fe5d}', Ps),
+    ('\u{fe5e}', '\u{fe5e}', Pe),
+    ('\u{fe5f}', '\u{fe61}', Po),
+    ('\u{fe62}', '\u{fe62}', Sm),
+    ('\u{fe63}', '\u{fe63}', Pd),
+    ('\u{fe64}', '\u{fe66}', Sm),
+    ('\u{fe68}', '\u{fe68}', Po),
+    ('\u{fe69}', '\u{fe69}', Sc),
+    ('\u{fe6a}', '\u{fe6b}', Po),
+    ('\u{fe70}', '\u{fe74}', Lo),
+    ('\u{fe76}', '\u{fefc}', Lo),
+    ('\u{feff}', '\u{feff}', Cf),
+    ('\u{ff01}', '\u{ff03}', Po),
+    ('\u{ff04}', '\u{ff04}', Sc),
+    ('\u{ff05}', '\u{ff07}', Po),
+    ('\u{ff08}', '\u{ff08}', Ps),
+    ('\u{ff09}', '\u{ff09}', Pe),
+    ('\u{ff0a}', '\u{ff0a}', Po),
+    ('\u{ff0b}', '\u{ff0b}', Sm),
+    ('\u{ff0c}', '\u{ff0c}', Po),
+    ('\u{ff0d}', '\u{ff0d}', Pd),
+    ('\u{ff0e}', '\u{ff0f}', Po),
+    ('\u{ff10}', '\u{ff19}', Nd),
+    ('\u{ff1a}', '\u{ff1b}', Po),
+    ('\u{ff1c}', '\u{ff1e}', Sm),
+    ('\u{ff1f}', '\u{ff20}', Po),
+    ('\u{ff21}', '\u{ff3a}', Lu),
+    ('\u{ff3b}', '\u{ff3b}', Ps),
+    ('\u{ff3c}', '\u{ff3c}', Po),
+    ('\u{ff3d}', '\u{ff3d}', Pe),
+    ('\u{ff3e}', '\u{ff3e}', Sk),
+    ('\u{ff3f}', '\u{ff3f}', Pc),
+    ('\u{ff40}', '\u{ff40}', Sk),
+    ('\u{ff41}', '\u{ff5a}', Ll),
+    ('\u{ff5b}', '\u{ff5b}', Ps),
+    ('\u{ff5c}', '\u{ff5c}', Sm),
+    ('\u{ff5d}', '\u{ff5d}', Pe),
+    ('\u{ff5e}', '\u{ff5e}', Sm),
+    ('\u{ff5f}', '\u{ff5f}', Ps),
+    ('\u{ff60}', '\u{ff60}', Pe),
+    ('\u{ff61}', '\u{ff61}', Po),
+    ('\u{ff62}', '\u{ff62}', Ps),
+    ('\u{ff63}', '\u{ff63}', Pe),
+    ('\u{ff64}', '\u{ff65}', Po),
+    ('\u{ff66}', '\u{ff6f}', Lo),
+    ('\u{ff70}', '\u{ff70}', Lm),
+    ('\u{ff71}', '\u{ff9d}', Lo),
+    ('\u{ff9e}', '\u{ff9f}', Lm),
+    ('\u{ffa0}', '\u{ffbe}', Lo),
+    ('\u{ffc2}', '\u{ffc7}', Lo),
+    ('\u{ffca}', '\u{ffcf}', Lo),
+    ('\u{ffd2}', '\u{ffd7}', Lo),
+    ('\u{ffda}', '\u{ffdc}', Lo),
+    ('\u{ffe0}', '\u{ffe1}', Sc),
+    ('\u{ffe2}', '\u{ffe2}', Sm),
+    ('\u{ffe3}', '\u{ffe3}', Sk),
+    ('\u{ffe4}', '\u{ffe4}', So),
+    ('\u{ffe5}', '\u{ffe6}', Sc),
+    ('\u{ffe8}', '\u{ffe8}', So),
+    ('\u{ffe9}', '\u{ffec}', Sm),
+    ('\u{ffed}', '\u{ffee}', So),
+    ('\u{fff9}', '\u{fffb}', Cf),
+    ('\u{fffc}', '\u{fffd}', So),
+    ('\u{10000}', '\u{1000b}', Lo),
+    ('\u{1000d}', '\u{10026}', Lo),
+    ('\u{10028}', '\u{1003a}', Lo),
+    ('\u{1003c}', '\u{1003d}', Lo),
+    ('\u{1003f}', '\u{1004d}', Lo),
+    ('\u{10050}', '\u{1005d}', Lo),
+    ('\u{10080}', '\u{100fa}', Lo),
+    ('\u{10100}', '\u{10102}', Po),
+    ('\u{10107}', '\u{10133}', No),
+    ('\u{10137}', '\u{1013f}', So),
+    ('\u{10140}', '\u{10174}', Nl),
+    ('\u{10175}', '\u{10178}', No),
+    ('\u{10179}', '\u{10189}', So),
+    ('\u{1018a}', '\u{1018b}', No),
+    ('\u{1018c}', '\u{1018e}', So),
+    ('\u{10190}', '\u{1019c}', So),
+    ('\u{101a0}', '\u{101a0}', So),
+    ('\u{101d0}', '\u{101fc}', So),
+    ('\u{101fd}', '\u{101fd}', Mn),
+    ('\u{10280}', '\u{1029c}', Lo),
+    ('\u{102a0}', '\u{102d0}', Lo),
+    ('\u{102e0}', '\u{102e0}', Mn),
+    ('\u{102e1}', '\u{102fb}', No),
+    ('\u{10300}', '\u{1031f}', Lo),
+    ('\u{10320}', '\u{10323}', No),
+    ('\u{1032d}', '\u{10340}', Lo),
+    ('\u{10341}', '\u{10341}', Nl),
+    ('\u{10342}', '\u{10349}', Lo),
+    ('\u{1034a}', '\u{1034a}', Nl),
+    ('\u{10350}', '\u{10375}', Lo),
+    ('\u{10376}', '\u{1037a}', Mn),
+    ('\u{10380}', '\u{1039d}', Lo),
+    ('\u{1039f}', '\u{1039f}', Po),
+    ('\u{103a0}', '\u{103c3}', Lo),
+    ('\u{103c8}', '\u{103cf}', Lo),
+    ('\u{103d0}', '\u{103d0}', Po),
+    ('\u{103d1}', '\u{103d5}', Nl),
+    ('\u{10400}', '\u{10427}', Lu),
+    ('\u{10428}', '\u{1044f}', Ll),
+    ('\u{10450}', '\u{1049d}', Lo),
+    ('\u{104a0}', '\u{104a9}', Nd),
+    ('\u{104b0}', '\u{104d3}', Lu),
+    ('\u{104d8}', '\u{104fb}', Ll),
+    ('\u{10500}', '\u{10527}', Lo),
+    ('\u{10530}', '\u{10563}', Lo),
+    ('\u{1056f}', '\u{1056f}', Po),
+    ('\u{10570}', '\u{1057a}', Lu),
+    ('\u{1057c}', '\u{1058a}', Lu),
+    ('\u{1058c}', '\u{10592}', Lu),
+    ('\u{10594}', '\u{10595}', Lu),
+    ('\u{10597}', '\u{105a1}', Ll),
+    ('\u{105a3}', '\u{105b1}', Ll),
+    ('\u{105b3}', '\u{105b9}', Ll),
+    ('\u{105bb}', '\u{105bc}', Ll),
+    ('\u{105c0}', '\u{105f3}', Lo),
+    ('\u{10600}', '\u{10736}', Lo),
+    ('\u{10740}', '\u{10755}', Lo),
+    ('\u{10760}', '\u{10767}', Lo),
+    ('\u{10780}', '\u{10785}', Lm),
+    ('\u{10787}', '\u{107b0}', Lm),
+    ('\u{107b2}', '\u{107ba}', Lm),
+    ('\u{10800}', '\u{10805}', Lo),
+    ('\u{10808}', '\u{10808}', Lo),
+    ('\u{1080a}', '\u{10835}', Lo),
+    ('\u{10837}', '\u{10838}', Lo),
+    ('\u{1083c}', '\u{1083c}', Lo),
+    ('\u{1083f}', '\u{10855}', Lo),
+    ('\u{10857}', '\u{10857}', Po),
+    ('\u{10858}', '\u{1085f}', No),
+    ('\u{10860}', '\u{10876}', Lo),
+    ('\u{10877}', '\u{10878}', So),
+    ('\u{10879}', '\u{1087f}', No),
+    ('\u{10880}', '\u{1089e}', Lo),
+    ('\u{108a7}', '\u{108af}', No),
+    ('\u{108e0}', '\u{108f2}', Lo),
+    ('\u{108f4}', '\u{108f5}', Lo),
+    ('\u{108fb}', '\u{108ff}', No),
+    ('\u{10900}', '\u{10915}', Lo),
+    ('\u{10916}', '\u{1091b}', No),
+    ('\u{1091f}', '\u{1091f}', Po),
+    ('\u{10920}', '\u{10939}', Lo),
+    ('\u{1093f}', '\u{1093f}', Po),
+    ('\u{10980}', '\u{109b7}', Lo),
+    ('\u{109bc}', '\u{109bd}', No),
+    ('\u{109be}', '\u{109bf}', Lo),
+    ('\u{109c0}', '\u{109cf}', No),
+    ('\u{109d2}', '\u{109ff}', No),
+    ('\u{10a00}', '\u{10a00}', Lo),
+    ('\u{10a01}', '\u{10a03}', Mn),
+    ('\u{10a05}', '\u{10a06}', Mn),
+    ('\u{10a0c}', '\u{10a0f}', Mn),
+    ('\u{10a10}', '\u{10a13}', Lo),
+    ('\u{10a15}', '\u{10a17}', Lo),
+    ('\u{10a19}', '\u{10a35}', Lo),
+    ('\u{10a38}', '\u{10a3a}', Mn),
+    ('\u{10a3f}', '\u{10a3f}', Mn),
+    ('\u{10a40}', '\u{10a48}', No),
+    ('\u{10a50}', '\u{10a58}', Po),
+    ('\u{10a60}', '\u{10a7c}', Lo),
+    ('\u{10a7d}', '\u{10a7e}', No),
+    ('\u{10a7f}', '\u{10a7f}', Po),
+    ('\u{10a80}', '\u{10a9c}', Lo),
+    ('\u{10a9d}', '\u{10a9f}', No),
+    ('\u{10ac0}', '\u{10ac7}', Lo),
+    ('\u{10ac8}', '\u{10ac8}', So),
+    ('\u{10ac9}', '\u{10ae4}', Lo),
+    ('\u{10ae5}', '\u{10ae6}', Mn),
+    ('\u{10aeb}', '\u{10aef}', No),
+    ('\u{10af0}', '\u{10af6}', Po),
+    ('\u{10b00}', '\u{10b35}', Lo),
+    ('\u{10b39}', '\u{10b3f}', Po),
+    ('\u{10b40}', '\u{10b55}', Lo),
+    ('\u{10b58}', '\u{10b5f}', No),
+    ('\u{10b60}', '\u{10b72}', Lo),
+    ('\u{10b78}', '\u{10b7f}', No),
+    ('\u{10b80}', '\u{10b91}', Lo),
+    ('\u{10b99}', '\u{10b9c}', Po),
+    ('\u{10ba9}', '\u{10baf}', No),
+    ('\u{10c00}', '\u{10c48}', Lo),
+    ('\u{10c80}', '\u{10cb2}', Lu),
+    ('\u{10cc0}', '\u{10cf2}', Ll),
+    ('\u{10cfa}', '\u{10cff}', No),
+    ('\u{10d00}', '\u{10d23}', Lo),
+    ('\u{10d24}', '\u{10d27}', Mn),
+    ('\u{10d30}', '\u{10d39}', Nd),
+    ('\u{10d40}', '\u{10d49}', Nd),
+    ('\u{10d4a}', '\u{10d4d}', Lo),
+    ('\u{10d4e}', '\u{10d4e}', Lm),
+    ('\u{10d4f}', '\u{10d4f}', Lo),
+    ('\u{10d50}', '\u{10d65}', Lu),
+    ('\u{10d69}', '\u{10d6d}', Mn),
+    ('\u{10d6e}', '\u{10d6e}', Pd),
+    ('\u{10d6f}', '\u{10d6f}', Lm),
+    ('\u{10d70}', '\u{10d85}', Ll),
+    ('\u{10d8e}', '\u{10d8f}', Sm),
+    ('\u{10e60}', '\u{10e7e}', No),
+    ('\u{10e80}', '\u{10ea9}', Lo),
+    ('\u{10eab}', '\u{10eac}', Mn),
+    ('\u{10ead}', '\u{10ead}', Pd),
+    ('\u{10eb0}', '\u{10eb1}', Lo),
+    ('\u{10ec2}', '\u{10ec4}', Lo),
+    ('\u{10efc}', '\u{10eff}', Mn),
+    ('\u{10f00}', '\u{10f1c}', Lo),
+    ('\u{10f1d}', '\u{10f26}', No),
+    ('\u{10f27}', '\u{10f27}', Lo),
+    ('\u{10f30}', '\u{10f45}', Lo),
+    ('\u{10f46}', '\u{10f50}', Mn),
+    ('\u{10f51}', '\u{10f54}', No),
+    ('\u{10f55}', '\u{10f59}', Po),
+    ('\u{10f70}', '\u{10f81}', Lo),
+    ('\u{10f82}', '\u{10f85}', Mn),
+    ('\u{10f86}', '\u{10f89}', Po),
+    ('\u{10fb0}', '\u{10fc4}', Lo),
+    ('\u{10fc5}', '\u{10fcb}', No),
+    ('\u{10fe0}', '\u{10ff6}', Lo),
+    ('\u{11000}', '\u{11000}', Mc),
+    ('\u{11001}', '\u{11001}', Mn),
+    ('\u{11002}', '\u{11002}', Mc),
+    ('\u{11003}', '\u{11037}', Lo),
+    ('\u{11038}', '\u{11046}', Mn),
+    ('\u{11047}', '\u{1104d}', Po),
+    ('\u{11052}', '\u{11065}', No),
+    ('\u{11066}', '\u{1106f}', Nd),
+    ('\u{11070}', '\u{11070}', Mn),
+    ('\u{11071}', '\u{11072}', Lo),
+    ('\u{11073}', '\u{11074}', Mn),
+    ('\u{11075}', '\u{11075}', Lo),
+    ('\u{1107f}', '\u{11081}', Mn),
+    ('\u{11082}', '\u{11082}', Mc),
+    ('\u{11083}', '\u{110af}', Lo),
+    ('\u{110b0}', '\u{110b2}', Mc),
+    ('\u{110b3}', '\u{110b6}', Mn),
+    ('\u{110b7}', '\u{110b8}', Mc),
+    ('\u{110b9}', '\u{110ba}', Mn),
+    ('\u{110bb}', '\u{110bc}', Po),
+    ('\u{110bd}', '\u{110bd}', Cf),
+    ('\u{110be}', '\u{110c1}', Po),
+    ('\u{110c2}', '\u{110c2}', Mn),
+    ('\u{110cd}', '\u{110cd}', Cf),
+    ('\u{110d0}', '\u{110e8}', Lo),
+    ('\u{110f0}', '\u{110f9}', Nd),
+    ('\u{11100}', '\u{11102}', Mn),
+    ('\u{11103}', '\u{11126}', Lo),
+    ('\u{11127}', '\u{1112b}', Mn),
+    ('\u{1112c}', '\u{1112c}', Mc),
+    ('\u{1112d}', '\u{11134}', Mn),
+    ('\u{11136}', '\u{1113f}', Nd),
+    ('\u{11140}', '\u{11143}', Po),
+    ('\u{11144}', '\u{11144}', Lo),
+    ('\u{11145}', '\u{11146}', Mc),
+    ('\u{11147}', '\u{11147}', Lo),
+    ('\u{11150}', '\u{11172}', Lo),
+    ('\u{11173}', '\u{11173}', Mn),
+    ('\u{11174}', '\u{11175}', Po),
+    ('\u{11176}', '\u{11176}', Lo),
+    ('\u{11180}', '\u{11181}', Mn),
+    ('\u{11182}', '\u{11182}', Mc),
+    ('\u{11183}', '\u{111b2}', Lo),
+    ('\u{111b3}', '\u{111b5}', Mc),
+    ('\u{111b6}', '\u{111be}', Mn),
+    ('\u{111bf}', '\u{111c0}', Mc),
+    ('\u{111c1}', '\u{111c4}', Lo),
+    ('\u{111c5}', '\u{111c8}', Po),
+    ('\u{111c9}', '\u{111cc}', Mn),
+    ('\u{111cd}', '\u{111cd}', Po),
+    ('\u{111ce}', '\u{111ce}', Mc),
+    ('\u{111cf}', '\u{111cf}', Mn),
+    ('\u{111d0}', '\u{111d9}', Nd),
+    ('\u{111da}', '\u{111da}', Lo),
+    ('\u{111db}', '\u{111db}', Po),
+    ('\u{111dc}', '\u{111dc}', Lo),
+    ('\u{111dd}', '\u{111df}', Po),
+    ('\u{111e1}', '\u{111f4}', No),
+    ('\u{11200}', '\u{11211}', Lo),
+    ('\u{11213}', '\u{1122b}', Lo),
+    ('\u{1122c}', '\u{1122e}', Mc),
+    ('\u{1122f}', '\u{11231}', Mn),
+    ('\u{11232}', '\u{11233}', Mc),
+    ('\u{11234}', '\u{11234}', Mn),
+    ('\u{11235}', '\u{11235}', Mc),
+    ('\u{11236}', '\u{11237}', Mn),
+    ('\u{11238}', '\u{1123d}', Po),
+    ('\u{1123e}', '\u{1123e}', Mn),
+    ('\u{1123f}', '\u{11240}', Lo),
+    ('\u{11241}', '\u{11241}', Mn),
+    ('\u{11280}', '\u{11286}', Lo),
+    ('\u{11288}', '\u{11288}', Lo),
+    ('\u{1128a}', '\u{1128d}', Lo),
+    ('\u{1128f}', '\u{1129d}', Lo),
+    ('\u{1129f}', '\u{112a8}', Lo),
+    ('\u{112a9}', '\u{112a9}', Po),
+    ('\u{112b0}', '\u{112de}', Lo),
+    ('\u{112df}', '\u{112df}', Mn),
+    ('\u{112e0}', '\u{112e2}', Mc),
+    ('\u{112e3}', '\u{112ea}', Mn),
+    ('\u{112f0}', '\u{112f9}', Nd),
+    ('\u{11300}', '\u{11301}', Mn),
+    ('\u{11302}', '\u{11303}', Mc),
+    ('\u{11305}', '\u{1130c}', Lo),
+    ('\u{1130f}', '\u{11310}', Lo),
+    ('\u{11313}', '\u{11328}', Lo),
+    ('\u{1132a}', '\u{11330}', Lo),
+    ('\u{11332}', '\u{11333}', Lo),
+    ('\u{11335}', '\u{11339}', Lo),
+    ('\u{1133b}', '\u{1133c}', Mn),
+    ('\u{1133d}', '\u{1133d}', Lo),
+    ('\u{1133e}', '\u{1133f}', Mc),
+    ('\u{11340}', '\u{11340}', Mn),
+    ('\u{11341}', '\u{11344}', Mc),
+    ('\u{11347}', '\u{11348}', Mc),
+    ('\u{1134b}', '\u{1134d}', Mc),
+    ('\u{11350}', '\u{11350}', Lo),
+    ('\u{11357}', '\u{11357}', Mc),
+    ('\u{1135d}', '\u{11361}', Lo),
+    ('\u{11362}', '\u{11363}', Mc),
+    ('\u{11366}', '\u{1136c}', Mn),
+    ('\u{11370}', '\u{11374}', Mn),
+    ('\u{11380}', '\u{11389}', Lo),
+    ('\u{1138b}', '\u{1138b}', Lo),
+    ('\u{1138e}', '\u{1138e}', Lo),
+    ('\u{11390}', '\u{113b5}', Lo),
+    ('\u{113b7}', '\u{113b7}', Lo),
+    ('\u{113b8}', '\u{113ba}', Mc),
+    ('\u{113bb}', '\u{113c0}', Mn),
+    ('\u{113c2}', '\u{113c2}', Mc),
+    ('\u{113c5}', '\u{113c5}', Mc),
+    ('\u{113c7}', '\u{113ca}', Mc),
+    ('\u{113cc}', '\u{113cd}', Mc),
+    ('\u{113ce}', '\u{113ce}', Mn),
+    ('\u{113cf}', '\u{113cf}', Mc),
+    ('\u{113d0}', '\u{113d0}', Mn),
+    ('\u{113d1}', '\u{113d1}', Lo),
+    ('\u{113d2}', '\u{113d2}', Mn),
+    ('\u{113d3}', '\u{113d3}', Lo),
+    ('\u{113d4}', '\u{113d5}', Po),
+    ('\u{113d7}', '\u{113d8}', Po),
+    ('\u{113e1}', '\u{113e2}', Mn),
+    ('\u{11400}', '\u{11434}', Lo),
+    ('\u{11435}', '\u{11437}', Mc),
+    ('\u{11438}', '\u{1143f}', Mn),
+    ('\u{11440}', '\u{11441}', Mc),
+    ('\u{11442}', '\u{11444}', Mn),
+    ('\u{11445}', '\u{11445}', Mc),
+    ('\u{11446}', '\u{11446}', Mn),
+    ('\u{11447}', '\u{1144a}', Lo),
+    ('\u{1144b}', '\u{1144f}', Po),
+    ('\u{11450}', '\u{11459}', Nd),
+    ('\u{1145a}', '\u{1145b}', Po),
+    ('\u{1145d}', '\u{1145d}', Po),
+    ('\u{1145e}', '\u{1145e}', Mn),
+    ('\u{1145f}', '\u{11461}', Lo),
+    ('\u{11480}', '\u{114af}', Lo),
+    ('\u{114b0}', '\u{114b2}', Mc),
+    ('\u{114b3}', '\u{114b8}', Mn),
+    ('\u{114b9}', '\u{114b9}', Mc),
+    ('\u{114ba}', '\u{114ba}', Mn),
+    ('\u{114bb}', '\u{114be}', Mc),
+    ('\u{114bf}', '\u{114c0}', Mn),
+    ('\u{114c1}', '\u{114c1}', Mc),
+    ('\u{114c2}', '\u{114c3}', Mn),
+    ('\u{114c4}', '\u{114c5}', Lo),
+    ('\u{114c6}', '\u{114c6}', Po),
+    ('\u{114c7}', '\u{114c7}', Lo),
+    ('\u{114d0}', '\u{114d9}', Nd),
+    ('\u{11580}', '\u{115ae}', Lo),
+    ('\u{115af}', '\u{115b1}', Mc),
+    ('\u{115b2}', '\u{115b5}', Mn),
+    ('\u{115b8}', '\u{115bb}', Mc),
+    ('\u{115bc}', '\u{115bd}', Mn),
+    ('\u{115be}', '\u{115be}', Mc),
+    ('\u{115bf}', '\u{115c0}', Mn),
+    ('\u{115c1}', '\u{115d7}', Po),
+    ('\u{115d8}', '\u{115db}', Lo),
+    ('\u{115dc}', '\u{115dd}', Mn),
+    ('\u{11600}', '\u{1162f}', Lo),
+    ('\u{11630}', '\u{11632}', Mc),
+    ('\u{11633}', '\u{1163a}', Mn),
+    ('\u{1163b}', '\u{1163c}', Mc),
+    ('\u{1163d}', '\u{1163d}', Mn),
+    ('\u{1163e}', '\u{1163e}', Mc),
+    ('\u{1163f}', '\u{11640}', Mn),
+    ('\u{11641}', '\u{11643}', Po),
+    ('\u{11644}', '\u{11644}', Lo),
+    ('\u{11650}', '\u{11659}', Nd),
+    ('\u{11660}', '\u{1166c}', Po),
+    ('\u{11680}', '\u{116aa}', Lo),
+    ('\u{116ab}', '\u{116ab}', Mn),
+    ('\u{116ac}', '\u{116ac}', Mc),
+    ('\u{116ad}', '\u{116ad}', Mn),
+    ('\u{116ae}', '\u{116af}', Mc),
+    ('\u{116b0}', '\u{116b5}', Mn),
+    ('\u{116b6}', '\u{116b6}', Mc),
+    ('\u{116b7}', '\u{116b7}', Mn),
+    ('\u{116b8}', '\u{116b8}', Lo),
+    ('\u{116b9}', '\u{116b9}', Po),
+    ('\u{116c0}', '\u{116c9}', Nd),
+    ('\u{116d0}', '\u{116e3}', Nd),
+    ('\u{11700}', '\u{1171a}', Lo),
+    ('\u{1171d}', '\u{1171d}', Mn),
+    ('\u{1171e}', '\u{1171e}', Mc),
+    ('\u{1171f}', '\u{1171f}', Mn),
+    ('\u{11720}', '\u{11721}', Mc),
+    ('\u{11722}', '\u{11725}', Mn),
+    ('\u{11726}', '\u{11726}', Mc),
+    ('\u{11727}', '\u{1172b}', Mn),
+    ('\u{11730}', '\u{11739}', Nd),
+    ('\u{1173a}', '\u{1173b}', No),
+    ('\u{1173c}', '\u{1173e}', Po),
+    ('\u{1173f}', '\u{1173f}', So),
+    ('\u{11740}', '\u{11746}', Lo),
+    ('\u{11800}', '\u{1182b}', Lo),
+    ('\u{1182c}', '\u{1182e}', Mc),
+    ('\u{1182f}', '\u{11837}', Mn),
+    ('\u{11838}', '\u{11838}', Mc),
+    ('\u{11839}', '\u{1183a}', Mn),
+    ('\u{1183b}', '\u{1183b}', Po),
+    ('\u{118a0}', '\u{118bf}', Lu),
+    ('\u{118c0}', '\u{118df}', Ll),
+    ('\u{118e0}', '\u{118e9}', Nd),
+    ('\u{118ea}', '\u{118f2}', No),
+    ('\u{118ff}', '\u{11906}', Lo),
+    ('\u{11909}', '\u{11909}', Lo),
+    ('\u{1190c}', '\u{11913}', Lo),
+    ('\u{11915}', '\u{11916}', Lo),
+    ('\u{11918}', '\u{1192f}', Lo),
+    ('\u{11930}', '\u{11935}', Mc),
+    ('\u{11937}', '\u{11938}', Mc),
+    ('\u{1193b}', '\u{1193c}', Mn),
+    ('\u{1193d}', '\u{1193d}', Mc),
+    ('\u{1193e}', '\u{1193e}', Mn),
+    ('\u{1193f}', '\u{1193f}', Lo),
+    ('\u{11940}', '\u{11940}', Mc),
+    ('\u{11941}', '\u{11941}', Lo),
+    ('\u{11942}', '\u{11942}', Mc),
+    ('\u{11943}', '\u{11943}', Mn),
+    ('\u{11944}', '\u{11946}', Po),
+    ('\u{11950}', '\u{11959}', Nd),
+    ('\u{119a0}', '\u{119a7}', Lo),
+    ('\u{119aa}', '\u{119d0}', Lo),
+    ('\u{119d1}', '\u{119d3}', Mc),
+    ('\u{119d4}', '\u{119d7}', Mn),
+    ('\u{119da}', '\u{119db}', Mn),
+    ('\u{119dc}', '\u{119df}', Mc),
+    ('\u{119e0}', '\u{119e0}', Mn),
+    ('\u{119e1}', '\u{119e1}', Lo),
+    ('\u{119e2}', '\u{119e2}', Po),
+    ('\u{119e3}', '\u{119e3}', Lo),
+    ('\u{119e4}', '\u{119e4}', Mc),
+    ('\u{11a00}', '\u{11a00}', Lo),
+    ('\u{11a01}', '\u{11a0a}', Mn),
+    ('\u{11a0b}', '\u{11a32}', Lo),
+    ('\u{11a33}', '\u{11a38}', Mn),
+    ('\u{11a39}', '\u{11a39}', Mc),
+    ('\u{11a3a}', '\u{11a3a}', Lo),
+    ('\u{11a3b}', '\u{11a3e}', Mn),
+    ('\u{11a3f}', '\u{11a46}', Po),
+    ('\u{11a47}', '\u{11a47}', Mn),
+    ('\u{11a50}', '\u{11a50}', Lo),
+    ('\u{11a51}', '\u{11a56}', Mn),
+    ('\u{11a57}', '\u{11a58}', Mc),
+    ('\u{11a59}', '\u{11a5b}', Mn),
+    ('\u{11a5c}', '\u{11a89}', Lo),
+    ('\u{11a8a}', '\u{11a96}', Mn),
+    ('\u{11a97}', '\u{11a97}', Mc),
+    ('\u{11a98}', '\u{11a99}', Mn),
+    ('\u{11a9a}', '\u{11a9c}', Po),
+    ('\u{11a9d}', '\u{11a9d}', Lo),
+    ('\u{11a9e}', '\u{11aa2}', Po),
+    ('\u{11ab0}', '\u{11af8}', Lo),
+    ('\u{11b00}', '\u{11b09}', Po),
+    ('\u{11bc0}', '\u{11be0}', Lo),
+    ('\u{11be1}', '\u{11be1}', Po),
+    ('\u{11bf0}', '\u{11bf9}', Nd),
+    ('\u{11c00}', '\u{11c08}', Lo),
+    ('\u{11c0a}', '\u{11c2e}', Lo),
+    ('\u{11c2f}', '\u{11c2f}', Mc),
+    ('\u{11c30}', '\u{11c36}', Mn),
+    ('\u{11c38}', '\u{11c3d}', Mn),
+    ('\u{11c3e}', '\u{11c3e}', Mc),
+    ('\u{11c3f}', '\u{11c3f}', Mn),
+    ('\u{11c40}', '\u{11c40}', Lo),
+    ('\u{11c41}', '\u{11c45}', Po),
+    ('\u{11c50}', '\u{11c59}', Nd),
+    ('\u{11c5a}', '\u{11c6c}', No),
+    ('\u{11c70}', '\u{11c71}', Po),
+    ('\u{11c72}', '\u{11c8f}', Lo),
+    ('\u{11c92}', '\u{11ca7}', Mn),
+    ('\u{11ca9}', '\u{11ca9}', Mc),
+    ('\u{11caa}', '\u{11cb0}', Mn),
+    ('\u{11cb1}', '\u{11cb1}', Mc),
+    ('\u{11cb2}', '\u{11cb3}', Mn),
+    ('\u{11cb4}', '\u{11cb4}', Mc),
+    ('\u{11cb5}', '\u{11cb6}', Mn),
+    ('\u{11d00}', '\u{11d06}', Lo),
+    ('\u{11d08}', '\u{11d09}', Lo),
+    ('\u{11d0b}', '\u{11d30}', Lo),
+    ('\u{11d31}', '\u{11d36}', Mn),
+    ('\u{11d3a}', '\u{11d3a}', Mn),
+    ('\u{11d3c}', '\u{11d3d}', Mn),
+    ('\u{11d3f}', '\u{11d45}', Mn),
+    ('\u{11d46}', '\u{11d46}', Lo),
+    ('\u{11d47}', '\u{11d47}', Mn),
+    ('\u{11d50}', '\u{11d59}', Nd),
+    ('\u{11d60}', '\u{11d65}', Lo),
+    ('\u{11d67}', '\u{11d68}', Lo),
+    ('\u{11d6a}', '\u{11d89}', Lo),
+    ('\u{11d8a}', '\u{11d8e}', Mc),
+    ('\u{11d90}', '\u{11d91}', Mn),
+    ('\u{11d93}', '\u{11d94}', Mc),
+    ('\u{11d95}', '\u{11d95}', Mn),
+    ('\u{11d96}', '\u{11d96}', Mc),
+    ('\u{11d97}', '\u{11d97}', Mn),
+    ('\u{11d98}', '\u{11d98}', Lo),
+    ('\u{11da0}', '\u{11da9}', Nd),
+    ('\u{11ee0}', '\u{11ef2}', Lo),
+    ('\u{11ef3}', '\u{11ef4}', Mn),
+    ('\u{11ef5}', '\u{11ef6}', Mc),
+    ('\u{11ef7}', '\u{11ef8}', Po),
+    ('\u{11f00}', '\u{11f01}', Mn),
+    ('\u{11f02}', '\u{11f02}', Lo),
+    ('\u{11f03}', '\u{11f03}', Mc),
+    ('\u{11f04}', '\u{11f10}', Lo),
+    ('\u{11f12}', '\u{11f33}', Lo),
+    ('\u{11f34}', '\u{11f35}', Mc),
+    ('\u{11f36}', '\u{11f3a}', Mn),
+    ('\u{11f3e}', '\u{11f3f}', Mc),
+    ('\u{11f40}', '\u{11f40}', Mn),
+    ('\u{11f41}', '\u{11f41}', Mc),
+    ('\u{11f42}', '\u{11f42}', Mn),
+    ('\u{11f43}', '\u{11f4f}', Po),
+    ('\u{11f50}', '\u{11f59}', Nd),
+    ('\u{11f5a}', '\u{11f5a}', Mn),
+    ('\u{11fb0}', '\u{11fb0}', Lo),
+    ('\u{11fc0}', '\u{11fd4}', No),
+    ('\u{11fd5}', '\u{11fdc}', So),
+    ('\u{11fdd}', '\u{11fe0}', Sc),
+    ('\u{11fe1}', '\u{11ff1}', So),
+    ('\u{11fff}', '\u{11fff}', Po),
+    ('\u{12000}', '\u{12399}', Lo),
+    ('\u{12400}', '\u{1246e}', Nl),
+    ('\u{12470}', '\u{12474}', Po),
+    ('\u{12480}', '\u{12543}', Lo),
+    ('\u{12f90}', '\u{12ff0}', Lo),
+    ('\u{12ff1}', '\u{12ff2}', Po),
+    ('\u{13000}', '\u{1342f}', Lo),
+    ('\u{13430}', '\u{1343f}', Cf),
+    ('\u{13440}', '\u{13440}', Mn),
+    ('\u{13441}', '\u{13446}', Lo),
+    ('\u{13447}', '\u{13455}', Mn),
+    ('\u{13460}', '\u{143fa}', Lo),
+    ('\u{14400}', '\u{14646}', Lo),
+    ('\u{16100}', '\u{1611d}', Lo),
+    ('\u{1611e}', '\u{16129}', Mn),
+    ('\u{1612a}', '\u{1612c}', Mc),
+    ('\u{1612d}', '\u{1612f}', Mn),
+    ('\u{16130}', '\u{16139}', Nd),
+    ('\u{16800}', '\u{16a38}', Lo),
+    ('\u{16a40}', '\u{16a5e}', Lo),
+    ('\u{16a60}', '\u{16a69}', Nd),
+    ('\u{16a6e}', '\u{16a6f}', Po),
+    ('\u{16a70}', '\u{16abe}', Lo),
+    ('\u{16ac0}', '\u{16ac9}', Nd),
+    ('\u{16ad0}', '\u{16aed}', Lo),
+    ('\u{16af0}', '\u{16af4}', Mn),
+    ('\u{16af5}', '\u{16af5}', Po),
+    ('\u{16b00}', '\u{16b2f}', Lo),
+    ('\u{16b30}', '\u{16b36}', Mn),
+    ('\u{16b37}', '\u{16b3b}', Po),
+    ('\u{16b3c}', '\u{16b3f}', So),
+    ('\u{16b40}', '\u{16b43}', Lm),
+    ('\u{16b44}', '\u{16b44}', Po),
+    ('\u{16b45}', '\u{16b45}', So),
+    ('\u{16b50}', '\u{16b59}', Nd),
+    ('\u{16b5b}', '\u{16b61}', No),
+    ('\u{16b63}', '\u{16b77}', Lo),
+    ('\u{16b7d}', '\u{16b8f}', Lo),
+    ('\u{16d40}', '\u{16d42}', Lm),
+    ('\u{16d43}', '\u{16d6a}', Lo),
+    ('\u{16d6b}', '\u{16d6c}', Lm),
+    ('\u{16d6d}', '\u{16d6f}', Po),
+    ('\u{16d70}', '\u{16d79}', Nd),
+    ('\u{16e40}', '\u{16e5f}', Lu),
+    ('\u{16e60}', '\u{16e7f}', Ll),
+    ('\u{16e80}', '\u{16e96}', No),
+    ('\u{16e97}', '\u{16e9a}', Po),
+    ('\u{16f00}', '\u{16f4a}', Lo),
+    ('\u{16f4f}', '\u{16f4f}', Mn),
+    ('\u{16f50}', '\u{16f50}', Lo),
+    ('\u{16f51}', '\u{16f87}', Mc),
+    ('\u{16f8f}', '\u{16f92}', Mn),
+    ('\u{16f93}', '\u{16f9f}', Lm),
+    ('\u{16fe0}', '\u{16fe1}', Lm),
+    ('\u{16fe2}', '\u{16fe2}', Po),
+    ('\u{16fe3}', '\u{16fe3}', Lm),
+    ('\u{16fe4}', '\u{16fe4}', Mn),
+    ('\u{16ff0}', '\u{16ff1}', Mc),
+    ('\u{17000}', '\u{187f7}', Lo),
+    ('\u{18800}', '\u{18cd5}', Lo),
+    ('\u{18cff}', '\u{18d08}', Lo),
+    ('\u{1aff0}', '\u{1aff3}', Lm),
+    ('\u{1aff5}', '\u{1affb}', Lm),
+    ('\u{1affd}', '\u{1affe}', Lm),
+    ('\u{1b000}', '\u{1b122}', Lo),
+    ('\u{1b132}', '\u{1b132}', Lo),
+    ('\u{1b150}', '\u{1b152}', Lo),
+    ('\u{1b155}', '\u{1b155}', Lo),
+    ('\u{1b164}', '\u{1b167}', Lo),
+    ('\u{1b170}', '\u{1b2fb}', Lo),
+    ('\u{1bc00}', '\u{1bc6a}', Lo),
+    ('\u{1bc70}', '\u{1bc7c}', Lo),
+    ('\u{1bc80}', '\u{1bc88}', Lo),
+    ('\u{1bc90}', '\u{1bc99}', Lo),
+    ('\u{1bc9c}', '\u{1bc9c}', So),
+    ('\u{1bc9d}', '\u{1bc9e}', Mn),
+    ('\u{1bc9f}', '\u{1bc9f}', Po),
+    ('\u{1bca0}', '\u{1bca3}', Cf),
+    ('\u{1cc00}', '\u{1ccef}', So),
+    ('\u{1ccf0}', '\u{1ccf9}', Nd),
+    ('\u{1cd00}', '\u{1ceb3}', So),
+    ('\u{1cf00}', '\u{1cf2d}', Mn),
+    ('\u{1cf30}', '\u{1cf46}', Mn),
+    ('\u{1cf50}', '\u{1cfc3}', So),
+    ('\u{1d000}', '\u{1d0f5}', So),
+    ('\u{1d100}', '\u{1d126}', So),
+    ('\u{1d129}', '\u{1d164}', So),
+    ('\u{1d165}', '\u{1d166}', Mc),
+    ('\u{1d167}', '\u{1d169}', Mn),
+    ('\u{1d16a}', '\u{1d16c}', So),
+    ('\u{1d16d}', '\u{1d172}', Mc),
+    ('\u{1d173}', '\u{1d17a}', Cf),
+    ('\u{1d17b}', '\u{1d182}', Mn),
+    ('\u{1d183}', '\u{1d184}', So),
+    ('\u{1d185}', '\u{1d18b}', Mn),
+    ('\u{1d18c}', '\u{1d1a9}', So),
+    ('\u{1d1aa}', '\u{1d1ad}', Mn),
+    ('\u{1d1ae}', '\u{1d1ea}', So),
+    ('\u{1d200}', '\u{1d241}', So),
+    ('\u{1d242}', '\u{1d244}', Mn),
+    ('\u{1d245}', '\u{1d245}', So),
+    ('\u{1d2c0}', '\u{1d2d3}', No),
+    ('\u{1d2e0}', '\u{1d2f3}', No),
+    ('\u{1d300}', '\u{1d356}', So),
+    ('\u{1d360}', '\u{1d378}', No),
+    ('\u{1d400}', '\u{1d419}', Lu),
+    ('\u{1d41a}', '\u{1d433}', Ll),
+    ('\u{1d434}', '\u{1d44d}', Lu),
+    ('\u{1d44e}', '\u{1d454}', Ll),
+    ('\u{1d456}', '\u{1d467}', Ll),
+    ('\u{1d468}', '\u{1d481}', Lu),
+    ('\u{1d482}', '\u{1d49b}', Ll),
+    ('\u{1d49c}', '\u{1d49c}', Lu),
+    ('\u{1d49e}', '\u{1d49f}', Lu),
+    ('\u{1d4a2}', '\u{1d4a2}', Lu),
+    ('\u{1d4a5}', '\u{1d4a6}', Lu),
+    ('\u{1d4a9}', '\u{1d4ac}', Lu),
+    ('\u{1d4ae}', '\u{1d4b5}', Lu),
+    ('\u{1d4b6}', '\u{1d4b9}', Ll),
+    ('\u{1d4bb}', '\u{1d4bb}', Ll),
+    ('\u{1d4bd}', '\u{1d4c3}', Ll),
+    ('\u{1d4c5}', '\u{1d4cf}', Ll),
+    ('\u{1d4d0}', '\u{1d4e9}', Lu),
+    ('\u{1d4ea}', '\u{1d503}', Ll),
+    ('\u{1d504}', '\u{1d505}', Lu),
+    ('\u{1d507}', '\u{1d50a}', Lu),
+    ('\u{1d50d}', '\u{1d514}', Lu),
+    ('\u{1d516}', '\u{1d51c}', Lu),
+    ('\u{1d51e}', '\u{1d537}', Ll),
+    ('\u{1d538}', '\u{1d539}', Lu),
+    ('\u{1d53b}', '\u{1d53e}', Lu),
+    ('\u{1d540}', '\u{1d544}', Lu),
+    ('\u{1d546}', '\u{1d546}', Lu),
+    ('\u{1d54a}', '\u{1d550}', Lu),
+    ('\u{1d552}', '\u{1d56b}', Ll),
+    ('\u{1d56c}', '\u{1d585}', Lu),
+    ('\u{1d586}', '\u{1d59f}', Ll),
+    ('\u{1d5a0}', '\u{1d5b9}', Lu),
+    ('\u{1d5ba}', '\u{1d5d3}', Ll),
+    ('\u{1d5d4}', '\u{1d5ed}', Lu),
+    ('\u{1d5ee}', '\u{1d607}', Ll),
+    ('\u{1d608}', '\u{1d621}', Lu),
+    ('\u{1d622}', '\u{1d63b}', Ll),
+    ('\u{1d63c}', '\u{1d655}', Lu),
+    ('\u{1d656}', '\u{1d66f}', Ll),
+    ('\u{1d670}', '\u{1d689}', Lu),
+    ('\u{1d68a}', '\u{1d6a5}', Ll),
+    ('\u{1d6a8}', '\u{1d6c0}', Lu),
+    ('\u{1d6c1}', '\u{1d6c1}', Sm),
+    ('\u{1d6c2}', '\u{1d6da}', Ll),
+    ('\u{1d6db}', '\u{1d6db}', Sm),
+    ('\u{1d6dc}', '\u{1d6e1}', Ll),
+    ('\u{1d6e2}', '\u{1d6fa}', Lu),
+    ('\u{1d6fb}', '\u{1d6fb}', Sm),
+    ('\u{1d6fc}', '\u{1d714}', Ll),
+    ('\u{1d715}', '\u{1d715}', Sm),
+    ('\u{1d716}', '\u{1d71b}', Ll),
+    ('\u{1d71c}', '\u{1d734}', Lu),
+    ('\u{1d735}', '\u{1d735}', Sm),
+    ('\u{1d736}', '\u{1d74e}', Ll),
+    ('\u{1d74f}', '\u{1d74f}', Sm),
+    ('\u{1d750}', '\u{1d755}', Ll),
+    ('\u{1d756}', '\u{1d76e}', Lu),
+    ('\u{1d76f}', '\u{1d76f}', Sm),
+    ('\u{1d770}', '\u{1d788}', Ll),
+    ('\u{1d789}', '\u{1d789}', Sm),
+    ('\u{1d78a}', '\u{1d78f}', Ll),
+    ('\u{1d790}', '\u{1d7a8}', Lu),
+    ('\u{1d7a9}', '\u{1d7a9}', Sm),
+    ('\u{1d7aa}', '\u{1d7c2}', Ll),
+    ('\u{1d7c3}', '\u{1d7c3}', Sm),
+    ('\u{1d7c4}', '\u{1d7c9}', Ll),
+    ('\u{1d7ca}', '\u{1d7ca}', Lu),
+    ('\u{1d7cb}', '\u{1d7cb}', Ll),
+    ('\u{1d7ce}', '\u{1d7ff}', Nd),
+    ('\u{1d800}', '\u{1d9ff}', So),
+    ('\u{1da00}', '\u{1da36}', Mn),
+    ('\u{1da37}', '\u{1da3a}', So),
+    ('\u{1da3b}', '\u{1da6c}', Mn),
+    ('\u{1da6d}', '\u{1da74}', So),
+    ('\u{1da75}', '\u{1da75}', Mn),
+    ('\u{1da76}', '\u{1da83}', So),
+    ('\u{1da84}', '\u{1da84}', Mn),
+    ('\u{1da85}', '\u{1da86}', So),
+    ('\u{1da87}', '\u{1da8b}', Po),
+    ('\u{1da9b}', '\u{1da9f}', Mn),
+    ('\u{1daa1}', '\u{1daaf}', Mn),
+    ('\u{1df00}', '\u{1df09}', Ll),
+    ('\u{1df0a}', '\u{1df0a}', Lo),
+    ('\u{1df0b}', '\u{1df1e}', Ll),
+    ('\u{1df25}', '\u{1df2a}', Ll),
+    ('\u{1e000}', '\u{1e006}', Mn),
+    ('\u{1e008}', '\u{1e018}', Mn),
+    ('\u{1e01b}', '\u{1e021}', Mn),
+    ('\u{1e023}', '\u{1e024}', Mn),
+    ('\u{1e026}', '\u{1e02a}', Mn),
+    ('\u{1e030}', '\u{1e06d}', Lm),
+    ('\u{1e08f}', '\u{1e08f}', Mn),
+    ('\u{1e100}', '\u{1e12c}', Lo),
+    ('\u{1e130}', '\u{1e136}', Mn),
+    ('\u{1e137}', '\u{1e13d}', Lm),
+    ('\u{1e140}', '\u{1e149}', Nd),
+    ('\u{1e14e}', '\u{1e14e}', Lo),
+    ('\u{1e14f}', '\u{1e14f}', So),
+    ('\u{1e290}', '\u{1e2ad}', Lo),
+    ('\u{1e2ae}', '\u{1e2ae}', Mn),
+    ('\u{1e2c0}', '\u{1e2eb}', Lo),
+    ('\u{1e2ec}', '\u{1e2ef}', Mn),
+    ('\u{1e2f0}', '\u{1e2f9}', Nd),
+    ('\u{1e2ff}', '\u{1e2ff}', Sc),
+    ('\u{1e4d0}', '\u{1e4ea}', Lo),
+    ('\u{1e4eb}', '\u{1e4eb}', Lm),
+    ('\u{1e4ec}', '\u{1e4ef}', Mn),
+    ('\u{1e4f0}', '\u{1e4f9}', Nd),
+    ('\u{1e5d0}', '\u{1e5ed}', Lo),
+    ('\u{1e5ee}', '\u{1e5ef}', Mn),
+    ('\u{1e5f0}', '\u{1e5f0}', Lo),
+    ('\u{1e5f1}', '\u{1e5fa}', Nd),
+    ('\u{1e5ff}', '\u{1e5ff}', Po),
+    ('\u{1e7e0}', '\u{1e7e6}', Lo),
+    ('\u{1e7e8}', '\u{1e7eb}', Lo),
+    ('\u{1e7ed}', '\u{1e7ee}', Lo),
+    ('\u{1e7f0}', '\u{1e7fe}', Lo),
+    ('\u{1e800}', '\u{1e8c4}', Lo),
+    ('\u{1e8c7}', '\u{1e8cf}', No),
+    ('\u{1e8d0}', '\u{1e8d6}', Mn),
+    ('\u{1e900}', '\u{1e921}', Lu),
+    ('\u{1e922}', '\u{1e943}', Ll),
+    ('\u{1e944}', '\u{1e94a}', Mn),
+    ('\u{1e94b}', '\u{1e94b}', Lm),
+    ('\u{1e950}', '\u{1e959}', Nd),
+    ('\u{1e95e}', '\u{1e95f}', Po),
+    ('\u{1ec71}', '\u{1ecab}', No),
+    ('\u{1ecac}', '\u{1ecac}', So),
+    ('\u{1ecad}', '\u{1ecaf}', No),
+    ('\u{1ecb0}', '\u{1ecb0}', Sc),
+    ('\u{1ecb1}', '\u{1ecb4}', No),
+    ('\u{1ed01}', '\u{1ed2d}', No),
+    ('\u{1ed2e}', '\u{1ed2e}', So),
+    ('\u{1ed2f}', '\u{1ed3d}', No),
+    ('\u{1ee00}', '\u{1ee03}', Lo),
+    ('\u{1ee05}', '\u{1ee1f}', Lo),
+    ('\u{1ee21}', '\u{1ee22}', Lo),
+    ('\u{1ee24}', '\u{1ee24}', Lo),
+    ('\u{1ee27}', '\u{1ee27}', Lo),
+    ('\u{1ee29}', '\u{1ee32}', Lo),
+    ('\u{1ee34}', '\u{1ee37}', Lo),
+    ('\u{1ee39}', '\u{1ee39}', Lo),
+    ('\u{1ee3b}', '\u{1ee3b}', Lo),
+    ('\u{1ee42}', '\u{1ee42}', Lo),
+    ('\u{1ee47}', '\u{1ee47}', Lo),
+    ('\u{1ee49}', '\u{1ee49}', Lo),
+    ('\u{1ee4b}', '\u{1ee4b}', Lo),
+    ('\u{1ee4d}', '\u{1ee4f}', Lo),
+    ('\u{1ee51}', '\u{1ee52}', Lo),
+    ('\u{1ee54}', '\u{1ee54}', Lo),
+    ('\u{1ee57}', '\u{1ee57}', Lo),
+    ('\u{1ee59}', '\u{1ee59}', Lo),
+    ('\u{1ee5b}', '\u{1ee5b}', Lo),
+    ('\u{1ee5d}', '\u{1ee5d}', Lo),
+    ('\u{1ee5f}', '\u{1ee5f}', Lo),
+    ('\u{1ee61}', '\u{1ee62}', Lo),
+    ('\u{1ee64}', '\u{1ee64}', Lo),
+    ('\u{1ee67}', '\u{1ee6a}', Lo),
+    ('\u{1ee6c}', '\u{1ee72}', Lo),
+    ('\u{1ee74}', '\u{1ee77}', Lo),
+    ('\u{1ee79}', '\u{1ee7c}', Lo),
+    ('\u{1ee7e}', '\u{1ee7e}', Lo),
+    ('\u{1ee80}', '\u{1ee89}', Lo),
+    ('\u{1ee8b}', '\u{1ee9b}', Lo),
+    ('\u{1eea1}', '\u{1eea3}', Lo),
+    ('\u{1eea5}', '\u{1eea9}', Lo),
+    ('\u{1eeab}', '\u{1eebb}', Lo),
+    ('\u{1eef0}', '\u{1eef1}', Sm),
+    ('\u{1f000}', '\u{1f02b}', So),
+    ('\u{1f030}', '\u{1f093}', So),
+    ('\u{1f0a0}', '\u{1f0ae}', So),
+    ('\u{1f0b1}', '\u{1f0bf}', So),
+    ('\u{1f0c1}', '\u{1f0cf}', So),
+    ('\u{1f0d1}', '\u{1f0f5}', So),
+    ('\u{1f100}', '\u{1f10c}', No),
+    ('\u{1f10d}', '\u{1f1ad}', So),
+    ('\u{1f1e6}', '\u{1f202}', So),
+    ('\u{1f210}', '\u{1f23b}', So),
+    ('\u{1f240}', '\u{1f248}', So),
+    ('\u{1f250}', '\u{1f251}', So),
+    ('\u{1f260}', '\u{1f265}', So),
+    ('\u{1f300}', '\u{1f3fa}', So),
+    ('\u{1f3fb}', '\u{1f3ff}', Sk),
+    ('\u{1f400}', '\u{1f6d7}', So),
+    ('\u{1f6dc}', '\u{1f6ec}', So),
+    ('\u{1f6f0}', '\u{1f6fc}', So),
+    ('\u{1f700}', '\u{1f776}', So),
+    ('\u{1f77b}', '\u{1f7d9}', So),
+    ('\u{1f7e0}', '\u{1f7eb}', So),
+    ('\u{1f7f0}', '\u{1f7f0}', So),
+    ('\u{1f800}', '\u{1f80b}', So),
+    ('\u{1f810}', '\u{1f847}', So),
+    ('\u{1f850}', '\u{1f859}', So),
+    ('\u{1f860}', '\u{1f887}', So),
+    ('\u{1f890}', '\u{1f8ad}', So),
+    ('\u{1f8b0}', '\u{1f8bb}', So),
+    ('\u{1f8c0}', '\u{1f8c1}', So),
+    ('\u{1f900}', '\u{1fa53}', So),
+    ('\u{1fa60}', '\u{1fa6d}', So),
+    ('\u{1fa70}', '\u{1fa7c}', So),
+    ('\u{1fa80}', '\u{1fa89}', So),
+    ('\u{1fa8f}', '\u{1fac6}', So),
+    ('\u{1face}', '\u{1fadc}', So),
+    ('\u{1fadf}', '\u{1fae9}', So),
+    ('\u{1faf0}', '\u{1faf8}', So),
+    ('\u{1fb00}', '\u{1fb92}', So),
+    ('\u{1fb94}', '\u{1fbef}', So),
+    ('\u{1fbf0}', '\u{1fbf9}', Nd),
+    ('\u{20000}', '\u{2a6df}', Lo),
+    ('\u{2a700}', '\u{2b739}', Lo),
+    ('\u{2b740}', '\u{2b81d}', Lo),
+    ('\u{2b820}', '\u{2cea1}', Lo),
+    ('\u{2ceb0}', '\u{2ebe0}', Lo),
+    ('\u{2ebf0}', '\u{2ee5d}', Lo),
+    ('\u{2f800}', '\u{2fa1d}', Lo),
+    ('\u{30000}', '\u{3134a}', Lo),
+    ('\u{31350}', '\u{323af}', Lo),
+    ('\u{e0001}', '\u{e0001}', Cf),
+    ('\u{e0020}', '\u{e007f}', Cf),
+    ('\u{e0100}', '\u{e01ef}', Mn),
+    ('\u{f0000}', '\u{ffffd}', Co),
+    ('\u{100000}', '\u{10fffd}', Co),
+];
@@ -0,0 +1,1335 @@
+// Unicode 16.0.0 Script.
+// Generated by tools/ucd_tables.py from regex-syntax 0.8.11, do not edit by hand.
+// Unicode data is subject to the Unicode License v3,
+// see https://www.unicode.org/license.txt.
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Script {
+    Adlam,
+    Ahom,
+    AnatolianHieroglyphs,
+    Arabic,
+    Armenian,
+    Avestan,
+    Balinese,
+    Bamum,
+    BassaVah,
+    Batak,
+    Bengali,
+    Bhaiksuki,
+    Bopomofo,
+    Brahmi,
+    Braille,
+    Buginese,
+    Buhid,
+    CanadianAboriginal,
+    Carian,
+    CaucasianAlbanian,
+    Chakma,
+    Cham,
+    Cherokee,
+    Chorasmian,
+    Common,
+    Coptic,
+    Cuneiform,
+    Cypriot,
+    CyproMinoan,
+    Cyrillic,
+    Deseret,
+    Devanagari,
+    DivesAkuru,
+    Dogra,
+    Duployan,
+    EgyptianHieroglyphs,
+    Elbasan,
+    Elymaic,
+    Ethiopic,
+    Garay,
+    Georgian,
+    Glagolitic,
+    Gothic,
+    Grantha,
+    Greek,
+    Gujarati,
+    GunjalaGondi,
+    Gurmukhi,
+    GurungKhema,
+    Han,
+    Hangul,
+    HanifiRohingya,
+    Hanunoo,
+    Hatran,
+    Hebrew,
+    Hiragana,
+    ImperialAramaic,
+    Inherited,
+    InscriptionalPahlavi,
+    InscriptionalParthian,
+    Javanese,
+    Kaithi,
+    Kannada,
+    Katakana,
+    Kawi,
+    KayahLi,
+    Kharoshthi,
+    KhitanSmallScript,
+    Khmer,
+    Khojki,
+    Khudawadi,
+    KiratRai,
+    Lao,
+    Latin,
+    Lepcha,
+    Limbu,
+    LinearA,
+    LinearB,
+    Lisu,
+    Lycian,
+    Lydian,
+    Mahajani,
+    Makasar,
+    Malayalam,
+    Mandaic,
+    Manichaean,
+    Marchen,
+    MasaramGondi,
+    Medefaidrin,
+    MeeteiMayek,
+    MendeKikakui,
+    MeroiticCursive,
+    MeroiticHieroglyphs,
+    Miao,
+    Modi,
+    Mongolian,
+    Mro,
+    Multani,
+    Myanmar,
+    Nabataean,
+    NagMundari,
+    Nandinagari,
+    NewTaiLue,
+    Newa,
+    Nko,
+    Nushu,
+    NyiakengPuachueHmong,
+    Ogham,
+    OlChiki,
+    OlOnal,
+    OldHungarian,
+    OldItalic,
+    OldNorthArabian,
+    OldPermic,
+    OldPersian,
+    OldSogdian,
+    OldSouthArabian,
+    OldTurkic,
+    OldUyghur,
+    Oriya,
+    Osage,
+    Osmanya,
+    PahawhHmong,
+    Palmyrene,
+    PauCinHau,
+    PhagsPa,
+    Phoenician,
+    PsalterPahlavi,
+    Rejang,
+    Runic,
+    Samaritan,
+    Saurashtra,
+    Sharada,
+    Shavian,
+    Siddham,
+    SignWriting,
+    Sinhala,
+    Sogdian,
+    SoraSompeng,
+    Soyombo,
+    Sundanese,
+    Sunuwar,
+    SylotiNagri,
+    Syriac,
+    Tagalog,
+    Tagbanwa,
+    TaiLe,
+    TaiTham,
+    TaiViet,
+    Takri,
+    Tamil,
+    Tangsa,
+    Tangut,
+    Telugu,
+    Thaana,
+    Thai,
+    Tibetan,
+    Tifinagh,
+    Tirhuta,
+    Todhri,
+    Toto,
+    TuluTigalari,
+    Ugaritic,
+    Vai,
+    Vithkuqi,
+    Wancho,
+    WarangCiti,
+    Yezidi,
+    Yi,
+    ZanabazarSquare,
+    Unknown,
+}
+
+pub(crate) const SCRIPT_NAMES: &[(&str, Script)] = &[
+    ("Adlam", Script::Adlam),
+    ("Ahom", Script::Ahom),
+    ("Anatolian_Hieroglyphs", Script::AnatolianHieroglyphs),
+    ("Arabic", Script::Arabic),
+    ("Armenian", Script::Armenian),
+    ("Avestan", Script::Avestan),
+    ("Balinese", Script::Balinese),
+    ("Bamum", Script::Bamum),
+    ("Bassa_Vah", Script::BassaVah),
+    ("Batak", Script::Batak),
+    ("Bengali", Script::Bengali),
+    ("Bhaiksuki", Script::Bhaiksuki),
+    ("Bopomofo", Script::Bopomofo),
+    ("Brahmi", Script::Brahmi),
+    ("Braille", Script::Braille),
+    ("Buginese", Script::Buginese),
+    ("Buhid", Script::Buhid),
+    ("Canadian_Aboriginal", Script::CanadianAboriginal),
+    ("Carian", Script::Carian),
+    ("Caucasian_Albanian", Script::CaucasianAlbanian),
+    ("Chakma", Script::Chakma),
+    ("Cham", Script::Cham),
+    ("Cherokee", Script::Cherokee),
+    ("Chorasmian", Script::Chorasmian),
+    ("Common", Script::Common),
+    ("Coptic", Script::Coptic),
+    ("Cuneiform", Script::Cuneiform),
+    ("Cypriot", Script::Cypriot),
+    ("Cypro_Minoan", Script::CyproMinoan),
+    ("Cyrillic", Script::Cyrillic),
+    ("Deseret", Script::Deseret),
+    ("Devanagari", Script::Devanagari),
+    ("Dives_Akuru", Script::DivesAkuru),
+    ("Dogra", Script::Dogra),
+    ("Duployan", Script::Duployan),
+    ("Egyptian_Hieroglyphs", Script::EgyptianHieroglyphs),
+    ("Elbasan", Script::Elbasan),
+    ("Elymaic", Script::Elymaic),
+    ("Ethiopic", Script::Ethiopic),
+    ("Garay", Script::Garay),
+    ("Georgian", Script::Georgian),
+    ("Glagolitic", Script::Glagolitic),
+    ("Gothic", Script::Gothic),
+    ("Grantha", Script::Grantha),
+    ("Greek", Script::Greek),
+    ("Gujarati", Script::Gujarati),
+    ("Gunjala_Gondi", Script::GunjalaGondi),
+    ("Gurmukhi", Script::Gurmukhi),
+    ("Gurung_Khema", Script::GurungKhema),
+    ("Han", Script::Han),
+    ("Hangul", Script::Hangul),
+    ("Hanifi_Rohingya", Script::HanifiRohingya),
+    ("Hanunoo", Script::Hanunoo),
+    ("Hatran", Script::Hatran),
+    ("Hebrew", Script::Hebrew),
+    ("Hiragana", Script::Hiragana),
+    ("Imperial_Aramaic", Script::ImperialAramaic),
+    ("Inherited", Script::Inherited),
+    ("Inscriptional_Pahlavi", Script::InscriptionalPahlavi),
+    ("Inscriptional_Parthian", Script::InscriptionalParthian),
+    ("Javanese", Script::Javanese),
+    ("Kaithi", Script::Kaithi),
+    ("Kannada", Script::Kannada),
+    ("Katakana", Script::Katakana),
+    ("Kawi", Script::Kawi),
+    ("Kayah_Li", Script::KayahLi),
+    ("Kharoshthi", Script::Kharoshthi),
+    ("Khitan_Small_Script", Script::KhitanSmallScript),
+    ("Khmer", Script::Khmer),
+    ("Khojki", Script::Khojki),
+    ("Khudawadi", Script::Khudawadi),
+    ("Kirat_Rai", Script::KiratRai),
+    ("Lao", Script::Lao),
+    ("Latin", Script::Latin),
+    ("Lepcha", Script::Lepcha),
+    ("Limbu", Script::Limbu),
+    ("Linear_A", Script::LinearA),
+    ("Linear_B", Script::LinearB),
+    ("Lisu", Script::Lisu),
+    ("Lycian", Script::Lycian),
+    ("Lydian", Script::Lydian),
+    ("Mahajani", Script::Mahajani),
+    ("Makasar", Script::Makasar),
+    ("Malayalam", Script::Malayalam),
+    ("Mandaic", Script::Mandaic),
+    ("Manichaean", Script::Manichaean),
+    ("Marchen", Script::Marchen),
+    ("Masaram_Gondi", Script::MasaramGondi),
+    ("Medefaidrin", Script::Medefaidrin),
+    ("Meetei_Mayek", Script::MeeteiMayek),
+    ("Mende_Kikakui", Script::MendeKikakui),
+    ("Meroitic_Cursive", Script::MeroiticCursive),
+    ("Meroitic_Hieroglyphs", Script::MeroiticHieroglyphs),
+    ("Miao", Script::Miao),
+    ("Modi", Script::Modi),
+    ("Mongolian", Script::Mongolian),
+    ("Mro", Script::Mro),
+    ("Multani", Script::Multani),
+    ("Myanmar", Script::Myanmar),
+    ("Nabataean", Script::Nabataean),
+    ("Nag_Mundari", Script::NagMundari),
+    ("Nandinagari", Script::Nandinagari),
+    ("New_Tai_Lue", Script::NewTaiLue),
+    ("Newa", Script::Newa),
+    ("Nko", Script::Nko),
+    ("Nushu", Script::Nushu),
+    ("Nyiakeng_Puachue_Hmong", Script::NyiakengPuachueHmong),
+    ("Ogham", Script::Ogham),
+    ("Ol_Chiki", Script::OlChiki),
+    ("Ol_Onal", Script::OlOnal),
+    ("Old_Hungarian", Script::OldHungarian),
+    ("Old_Italic", Script::OldItalic),
+    ("Old_North_Arabian", Script::OldNorthArabian),
+    ("Old_Permic", Script::OldPermic),
+    ("Old_Persian", Script::OldPersian),
+    ("Old_Sogdian", Script::OldSogdian),
+    ("Old_South_Arabian", Script::OldSouthArabian),
+    ("Old_Turkic", Script::OldTurkic),
+    ("Old_Uyghur", Script::OldUyghur),
+    ("Oriya", Script::Oriya),
+    ("Osage", Script::Osage),
+    ("Osmanya", Script::Osmanya),
+    ("Pahawh_Hmong", Script::PahawhHmong),
+    ("Palmyrene", Script::Palmyrene),
+    ("Pau_Cin_Hau", Script::PauCinHau),
+    ("Phags_Pa", Script::PhagsPa),
+    ("Phoenician", Script::Phoenician),
+    ("Psalter_Pahlavi", Script::PsalterPahlavi),
+    ("Rejang", Script::Rejang),
+    ("Runic", Script::Runic),
+    ("Samaritan", Script::Samaritan),
+    ("Saurashtra", Script::Saurashtra),
+    ("Sharada", Script::Sharada),
+    ("Shavian", Script::Shavian),
+    ("Siddham", Script::Siddham),
+    ("SignWriting", Script::SignWriting),
+    ("Sinhala", Script::Sinhala),
+    ("Sogdian", Script::Sogdian),
+    ("Sora_Sompeng", Script::SoraSompeng),
+    ("Soyombo", Script::Soyombo),
+    ("Sundanese", Script::Sundanese),
+    ("Sunuwar", Script::Sunuwar),
+    ("Syloti_Nagri", Script::SylotiNagri),
+    ("Syriac", Script::Syriac),
+    ("Tagalog", Script::Tagalog),
+    ("Tagbanwa", Script::Tagbanwa),
+    ("Tai_Le", Script::TaiLe),
+    ("Tai_Tham", Script::TaiTham),
+    ("Tai_Viet", Script::TaiViet),
+    ("Takri", Script::Takri),
+    ("Tamil", Script::Tamil),
+    ("Tangsa", Script::Tangsa),
+    ("Tangut", Script::Tangut),
+    ("Telugu", Script::Telugu),
+    ("Thaana", Script::Thaana),
+    ("Thai", Script::Thai),
+    ("Tibetan", Script::Tibetan),
+    ("Tifinagh", Script::Tifinagh),
+    ("Tirhuta", Script::Tirhuta),
+    ("Todhri", Script::Todhri),
+    ("Toto", Script::Toto),
+    ("Tulu_Tigalari", Script::TuluTigalari),
+    ("Ugaritic", Script::Ugaritic),
+    ("Unknown", Script::Unknown),
+    ("Vai", Script::Vai),
+    ("Vithkuqi", Script::Vithkuqi),
+    ("Wancho", Script::Wancho),
+    ("Warang_Citi", Script::WarangCiti),
+    ("Yezidi", Script::Yezidi),
+    ("Yi", Script::Yi),
+    ("Zanabazar_Square", Script::ZanabazarSquare),
+];
+
+pub(crate) const SCRIPT: &[(char, char, Script)] = &[
+    ('\u{0}', '@', Script::Common),
+    ('A', 'Z', Script::Latin),
+    ('[', '`', Script::Common),
+    ('a', 'z', Script::Latin),
+    ('{', '\u{a9}', Script::Common),
+    ('\u{aa}', '\u{aa}', Script::Latin),
+    ('\u{ab}', '\u{b9}', Script::Common),
+    ('\u{ba}', '\u{ba}', Script::Latin),
+    ('\u{bb}', '\u{bf}', Script::Common),
+    ('\u{c0}', '\u{d6}', Script::Latin),
+    ('\u{d7}', '\u{d7}', Script::Common),
+    ('\u{d8}', '\u{f6}', Script::Latin),
+    ('\u{f7}', '\u{f7}', Script::Common),
+    ('\u{f8}', '\u{2b8}', Script::Latin),
+    ('\u{2b9}', '\u{2df}', Script::Common),
+    ('\u{2e0}', '\u{2e4}', Script::Latin),
+    ('\u{2e5}', '\u{2e9}', Script::Common),
+    ('\u{2ea}', '\u{2eb}', Script::Bopomofo),
+    ('\u{2ec}', '\u{2ff}', Script::Common),
+    ('\u{300}', '\u{36f}', Script::Inherited),
+    ('\u{370}', '\u{373}', Script::Greek),
+    ('\u{374}', '\u{374}', Script::Common),
+    ('\u{375}', '\u{377}', Script::Greek),
+    ('\u{37a}', '\u{37d}', Script::Greek),
+    ('\u{37e}', '\u{37e}', Script::Common),
+    ('\u{37f}', '\u{37f}', Script::Greek),
+    ('\u{384}', '\u{384}', Script::Greek),
+    ('\u{385}', '\u{385}', Script::Common),
+    ('\u{386}', '\u{386}', Script::Greek),
+    ('\u{387}', '\u{387}', Script::Common),
+    ('\u{388}', '\u{38a}', Script::Greek),
+    ('\u{38c}', '\u{38c}', Script::Greek),
+    ('\u{38e}', '\u{3a1}', Script::Greek),
+    ('\u{3a3}', '\u{3e1}', Script::Greek),
+    ('\u{3e2}', '\u{3ef}', Script::Coptic),
+    ('\u{3f0}', '\u{3ff}', Script::Greek),
+    ('\u{400}', '\u{484}', Script::Cyrillic),
+    ('\u{485}', '\u{486}', Script::Inherited),
+    ('\u{487}', '\u{52f}', Script::Cyrillic),
+    ('\u{531}', '\u{556}', Script::Armenian),
+    ('\u{559}', '\u{58a}', Script::Armenian),
+    ('\u{58d}', '\u{58f}', Script::Armenian),
+    ('\u{591}', '\u{5c7}', Script::Hebrew),
+    ('\u{5d0}', '\u{5ea}', Script::Hebrew),
+    ('\u{5ef}', '\u{5f4}', Script::Hebrew),
+    ('\u{600}', '\u{604}', Script::Arabic),
+    ('\u{605}', '\u{605}', Script::Common),
+    ('\u{606}', '\u{60b}', Script::Arabic),
+    ('\u{60c}', '\u{60c}', Script::Common),
+    ('\u{60d}', '\u{61a}', Script::Arabic),
+    ('\u{61b}', '\u{61b}', Script::Common),
+    ('\u{61c}', '\u{61e}', Script::Arabic),
+    ('\u{61f}', '\u{61f}', Script::Common),
+    ('\u{620}', '\u{63f}', Script::Arabic),
+    ('\u{640}', '\u{640}', Script::Common),
+    ('\u{641}', '\u{64a}', Script::Arabic),
+    ('\u{64b}', '\u{655}', Script::Inherited),
+    ('\u{656}', '\u{66f}', Script::Arabic),
+    ('\u{670}', '\u{670}', Script::Inherited),
+    ('\u{671}', '\u{6dc}', Script::Arabic),
+    ('\u{6dd}', '\u{6dd}', Script::Common),
+    ('\u{6de}', '\u{6ff}', Script::Arabic),
+    ('\u{700}', '\u{70d}', Script::Syriac),
+    ('\u{70f}', '\u{74a}', Script::Syriac),
+    ('\u{74d}', '\u{74f}', Script::Syriac),
+    ('\u{750}', '\u{77f}', Script::Arabic),
+    ('\u{780}', '\u{7b1}', Script::Thaana),
+    ('\u{7c0}', '\u{7fa}', Script::Nko),
+    ('\u{7fd}', '\u{7ff}', Script::Nko),
+    ('\u{800}', '\u{82d}', Script::Samaritan),
+    ('\u{830}', '\u{83e}', Script::Samaritan),
+    ('\u{840}', '\u{85b}', Script::Mandaic),
+    ('\u{85e}', '\u{85e}', Script::Mandaic),
+    ('\u{860}', '\u{86a}', Script::Syriac),
+    ('\u{870}', '\u{88e}', Script::Arabic),
+    ('\u{890}', '\u{891}', Script::Arabic),
+    ('\u{897}', '\u{8e1}', Script::Arabic),
+    ('\u{8e2}', '\u{8e2}', Script::Common),
+    ('\u{8e3}', '\u{8ff}', Script::Arabic),
+    ('\u{900}', '\u{950}', Script::Devanagari),
+    ('\u{951}', '\u{954}', Script::Inherited),
+    ('\u{955}', '\u{963}', Script::Devanagari),
+    ('\u{964}', '\u{965}', Script::Common),
+    ('\u{966}', '\u{97f}', Script::Devanagari),
+    ('\u{980}', '\u{983}', Script::Bengali),
+    ('\u{985}', '\u{98c}', Script::Bengali),
+    ('\u{98f}', '\u{990}', Script::Bengali),
+    ('\u{993}', '\u{9a8}', Script::Bengali),
+    ('\u{9aa}', '\u{9b0}', Script::Bengali),
+    ('\u{9b2}', '\u{9b2}', Script::Bengali),
+    ('\u{9b6}', '\u{9b9}', Script::Bengali),
+    ('\u{9bc}', '\u{9c4}', Script::Bengali),
+    ('\u{9c7}', '\u{9c8}', Script::Bengali),
+    ('\u{9cb}', '\u{9ce}', Script::Bengali),
+    ('\u{9d7}', '\u{9d7}', Script::Bengali),
+    ('\u{9dc}', '\u{9dd}', Script::Bengali),
+    ('\u{9df}', '\u{9e3}', Script::Bengali),
+    ('\u{9e6}', '\u{9fe}', Script::Bengali),
+    ('\u{a01}', '\u{a03}', Script::Gurmukhi),
+    ('\u{a05}', '\u{a0a}', Script::Gurmukhi),
+    ('\u{a0f}', '\u{a10}', Script::Gurmukhi),
+    ('\u{a13}', '\u{a28}', Script::Gurmukhi),
+    ('\u{a2a}', '\u{a30}', Script::Gurmukhi),
+    ('\u{a32}', '\u{a33}', Script::Gurmukhi),
+    ('\u{a35}', '\u{a36}', Script::Gurmukhi),
+    ('\u{a38}', '\u{a39}', Script::Gurmukhi),
+    ('\u{a3c}', '\u{a3c}', Script::Gurmukhi),
+    ('\u{a3e}', '\u{a42}', Script::Gurmukhi),
+    ('\u{a47}', '\u{a48}', Script::Gurmukhi),
+    ('\u{a4b}', '\u{a4d}', Script::Gurmukhi),
+    ('\u{a51}', '\u{a51}', Script::Gurmukhi),
+    ('\u{a59}', '\u{a5c}', Script::Gurmukhi),
+    ('\u{a5e}', '\u{a5e}', Script::Gurmukhi),
+    ('\u{a66}', '\u{a76}', Script::Gurmukhi),
+    ('\u{a81}', '\u{a83}', Script::Gujarati),
+    ('\u{a85}', '\u{a8d}', Script::Gujarati),
+    ('\u{a8f}', '\u{a91}', Script::Gujarati),
+    ('\u{a93}', '\u{aa8}', Script::Gujarati),
+    ('\u{aaa}', '\u{ab0}', Script::Gujarati),
+    ('\u{ab2}', '\u{ab3}', Script::Gujarati),
+    ('\u{ab5}', '\u{ab9}', Script::Gujarati),
+    ('\u{abc}', '\u{ac5}', Script::Gujarati),
+    ('\u{ac7}', '\u{ac9}', Script::Gujarati),
+    ('\u{acb}', '\u{acd}', Script::Gujarati),
+    ('\u{ad0}', '\u{ad0}', Script::Gujarati),
+    ('\u{ae0}', '\u{ae3}', Script::Gujarati),
+    ('\u{ae6}', '\u{af1}', Script::Gujarati),
+    ('\u{af9}', '\u{aff}', Script::Gujarati),
+    ('\u{b01}', '\u{b03}', Script::Oriya),
+    ('\u{b05}', '\u{b0c}', Script::Oriya),
+    ('\u{b0f}', '\u{b10}', Script::Oriya),
+    ('\u{b13}', '\u{b28}', Script::Oriya),
+    ('\u{b2a}', '\u{b30}', Script::Oriya),
+    ('\u{b32}', '\u{b33}', Script::Oriya),
+    ('\u{b35}', '\u{b39}', Script::Oriya),
+    ('\u{b3c}', '\u{b44}', Script::Oriya),
+    ('\u{b47}', '\u{b48}', Script::Oriya),
+    ('\u{b4b}', '\u{b4d}', Script::Oriya),
+    ('\u{b55}', '\u{b57}', Script::Oriya),
+    ('\u{b5c}', '\u{b5d}', Script::Oriya),
+    ('\u{b5f}', '\u{b63}', Script::Oriya),
+    ('\u{b66}', '\u{b77}', Script::Oriya),
+    ('\u{b82}', '\u{b83}', Script::Tamil),
+    ('\u{b85}', '\u{b8a}', Script::Tamil),
+    ('\u{b8e}', '\u{b90}', Script::Tamil),
+    ('\u{b92}', '\u{b95}', Script::Tamil),
+    ('\u{b99}', '\u{b9a}', Script::Tamil),
+    ('\u{b9c}', '\u{b9c}', Script::Tamil),
+    ('\u{b9e}', '\u{b9f}', Script::Tamil),
+    ('\u{ba3}', '\u{ba4}', Script::Tamil),
+    ('\u{ba8}', '\u{baa}', Script::Tamil),
+    ('\u{bae}', '\u{bb9}', Script::Tamil),
+    ('\u{bbe}', '\u{bc2}', Script::Tamil),
+    ('\u{bc6}', '\u{bc8}', Script::Tamil),
+    ('\u{bca}', '\u{bcd}', Script::Tamil),
+    ('\u{bd0}', '\u{bd0}', Script::Tamil),
+    ('\u{bd7}', '\u{bd7}', Script::Tamil),
+    ('\u{be6}', '\u{bfa}', Script::Tamil),
+    ('\u{c00}', '\u{c0c}', Script::Telugu),
+    ('\u{c0e}', '\u{c10}', Script::Telugu),
+    ('\u{c12}', '\u{c28}', Script::Telugu),
+    ('\u{c2a}', '\u{c39}', Script::Telugu),
+    ('\u{c3c}', '\u{c44}', Script::Telugu),
+    ('\u{c46}', '\u{c48}', Script::Telugu),
+    ('\u{c4a}', '\u{c4d}', Script::Telugu),
+    ('\u{c55}', '\u{c56}', Script::Telugu),
+    ('\u{c58}', '\u{c5a}', Script::Telugu),
+    ('\u{c5d}', '\u{c5d}', Script::Telugu),
+    ('\u{c60}', '\u{c63}', Script::Telugu),
+    ('\u{c66}', '\u{c6f}', Script::Telugu),
+    ('\u{c77}', '\u{c7f}', Script::Telugu),
+    ('\u{c80}', '\u{c8c}', Script::Kannada),
+    ('\u{c8e}', '\u{c90}', Script::Kannada),
+    ('\u{c92}', '\u{ca8}', Script::Kannada),
+    ('\u{caa}', '\u{cb3}', Script::Kannada),
+    ('\u{cb5}', '\u{cb9}', Script::Kannada),
+    ('\u{cbc}', '\u{cc4}', Script::Kannada),
+    ('\u{cc6}', '\u{cc8}', Script::Kannada),
+    ('\u{cca}', '\u{ccd}', Script::Kannada),
+    ('\u{cd5}', '\u{cd6}', Script::Kannada),
+    ('\u{cdd}', '\u{cde}', Script::Kannada),
+    ('\u{ce0}', '\u{ce3}', Script::Kannada),
+    ('\u{ce6}', '\u{cef}', Script::Kannada),
+    ('\u{cf1}', '\u{cf3}', Script::Kannada),
+    ('\u{d00}', '\u{d0c}', Script::Malayalam),
+    ('\u{d0e}', '\u{d10}', Script::Malayalam),
+    ('\u{d12}', '\u{d44}', Script::Malayalam),
+    ('\u{d46}', '\u{d48}', Script::Malayalam),
+    ('\u{d4a}', '\u{d4f}', Script::Malayalam),
+    ('\u{d54}', '\u{d63}', Script::Malayalam),
+    ('\u{d66}', '\u{d7f}', Script::Malayalam),
+    ('\u{d81}', '\u{d83}', Script::Sinhala),
+    ('\u{d85}', '\u{d96}', Script::Sinhala),
+    ('\u{d9a}', '\u{db1}', Script::Sinhala),
+    ('\u{db3}', '\u{dbb}', Script::Sinhala),
+    ('\u{dbd}', '\u{dbd}', Script::Sinhala),
+    ('\u{dc0}', '\u{dc6}', Script::Sinhala),
+    ('\u{dca}', '\u{dca}', Script::Sinhala),
+    ('\u{dcf}', '\u{dd4}', Script::Sinhala),
+    ('\u{dd6}', '\u{dd6}', Script::Sinhala),
+    ('\u{dd8}', '\u{ddf}', Script::Sinhala),
+    ('\u{de6}', '\u{def}', Script::Sinhala),
+    ('\u{df2}', '\u{df4}', Script::Sinhala),
+    ('\u{e01}', '\u{e3a}', Script::Thai),
+    ('\u{e3f}', '\u{e3f}', Script::Common),
+    ('\u{e40}', '\u{e5b}', Script::Thai),
+    ('\u{e81}', '\u{e82}', Script::Lao),
+    ('\u{e84}', '\u{e84}', Script::Lao),
+    ('\u{e86}', '\u{e8a}', Script::Lao),
+    ('\u{e8c}', '\u{ea3}', Script::Lao),
+    ('\u{ea5}', '\u{ea5}', Script::Lao),
+    ('\u{ea7}', '\u{ebd}', Script::Lao),
+    ('\u{ec0}', '\u{ec4}', Script::Lao),
+    ('\u{ec6}', '\u{ec6}', Script::Lao),
+    ('\u{ec8}', '\u{ece}', Script::Lao),
+    ('\u{ed0}', '\u{ed9}', Script::Lao),
+    ('\u{edc}', '\u{edf}', Script::Lao),
+    ('\u{f00}', '\u{f47}', Script::Tibetan),
+    ('\u{f49}', '\u{f6c}', Script::Tibetan),
+    ('\u{f71}', '\u{f97}', Script::Tibetan),
+    ('\u{f99}', '\u{fbc}', Script::Tibetan),
+    ('\u{fbe}', '\u{fcc}', Script::Tibetan),
+    ('\u{fce}', '\u{fd4}', Script::Tibetan),
+    ('\u{fd5}', '\u{fd8}', Script::Common),
+    ('\u{fd9}', '\u{fda}', Script::Tibetan),
+    ('\u{1000}', '\u{109f}', Script::Myanmar),
+    ('\u{10a0}', '\u{10c5}', Script::Georgian),
+    ('\u{10c7}', '\u{10c7}', Script::Georgian),
+    ('\u{10cd}', '\u{10cd}', Script::Georgian),
+    ('\u{10d0}', '\u{10fa}', Script::Georgian),
+    ('\u{10fb}', '\u{10fb}', Script::Common),
+    ('\u{10fc}', '\u{10ff}', Script::Georgian),
+    ('\u{1100}', '\u{11ff}', Script::Hangul),
+    ('\u{1200}', '\u{1248}', Script::Ethiopic),
+    ('\u{124a}', '\u{124d}', Script::Ethiopic),
+    ('\u{1250}', '\u{1256}', Script::Ethiopic),
+    ('\u{1258}', '\u{1258}', Script::Ethiopic),
+    ('\u{125a}', '\u{125d}', Script::Ethiopic),
+    ('\u{1260}', '\u{1288}', Script::Ethiopic),
+    ('\u{128a}', '\u{128d}', Script::Ethiopic),
+    ('\u{1290}', '\u{12b0}', Script::Ethiopic),
+    ('\u{12b2}', '\u{12b5}', Script::Ethiopic),
+    ('\u{12b8}', '\u{12be}', Script::Ethiopic),
+    ('\u{12c0}', '\u{12c0}', Script::Ethiopic),
+    ('\u{12c2}', '\u{12c5}', Script::Ethiopic),
+    ('\u{12c8}', '\u{12d6}', Script::Ethiopic),
+    ('\u{12d8}', '\u{1310}', Script::Ethiopic),
+    ('\u{1312}', '\u{1315}', Script::Ethiopic),
+    ('\u{1318}', '\u{135a}', Script::Ethiopic),
+    ('\u{135d}', '\u{137c}', Script::Ethiopic),
+    ('\u{1380}', '\u{1399}', Script::Ethiopic),
+    ('\u{13a0}', '\u{13f5}', Script::Cherokee),
+    ('\u{13f8}', '\u{13fd}', Script::Cherokee),
+    ('\u{1400}', '\u{167f}', Script::CanadianAboriginal),
+    ('\u{1680}', '\u{169c}', Script::Ogham),
+    ('\u{16a0}', '\u{16ea}', Script::Runic),
+    ('\u{16eb}', '\u{16ed}', Script::Common),
+    ('\u{16ee}', '\u{16f8}', Script::Runic),
+    ('\u{1700}', '\u{1715}', Script::Tagalog),
+    ('\u{171f}', '\u{171f}', Script::Tagalog),
+    ('\u{1720}', '\u{1734}', Script::Hanunoo),
+    ('\u{1735}', '\u{1736}', Script::Common),
+    ('\u{1740}', '\u{1753}', Script::Buhid),
+    ('\u{1760}', '\u{176c}', Script::Tagbanwa),
+    ('\u{176e}', '\u{1770}', Script::Tagbanwa),
+    ('\u{1772}', '\u{1773}', Script::Tagbanwa),
+    ('\u{1780}', '\u{17dd}', Script::Khmer),
+    ('\u{17e0}', '\u{17e9}', Script::Khmer),
+    ('\u{17f0}', '\u{17f9}', Script::Khmer),
+    ('\u{1800}', '\u{1801}', Script::Mongolian),
+    ('\u{1802}', '\u{1803}', Script::Common),
+    ('\u{1804}', '\u{1804}', Script::Mongolian),
+    ('\u{1805}', '\u{1805}', Script::Common),
+    ('\u{1806}', '\u{1819}', Script::Mongolian),
+    ('\u{1820}', '\u{1878}', Script::Mongolian),
+    ('\u{1880}', '\u{18aa}', Script::Mongolian),
+    ('\u{18b0}', '\u{18f5}', Script::CanadianAboriginal),
+    ('\u{1900}', '\u{191e}', Script::Limbu),
+    ('\u{1920}', '\u{192b}', Script::Limbu),
+    ('\u{1930}', '\u{193b}', Script::Limbu),
+    ('\u{1940}', '\u{1940}', Script::Limbu),
+    ('\u{1944}', '\u{194f}', Script::Limbu),
+    ('\u{1950}', '\u{196d}', Script::TaiLe),
+    ('\u{1970}', '\u{1974}', Script::TaiLe),
+    ('\u{1980}', '\u{19ab}', Script::NewTaiLue),
+    ('\u{19b0}', '\u{19c9}', Script::NewTaiLue),
+    ('\u{19d0}', '\u{19da}', Script::NewTaiLue),
+    ('\u{19de}', '\u{19df}', Script::NewTaiLue),
+    ('\u{19e0}', '\u{19ff}', Script::Khmer),
+    ('\u{1a00}', '\u{1a1b}', Script::Buginese),
+    ('\u{1a1e}', '\u{1a1f}', Script::Buginese),
+    ('\u{1a20}', '\u{1a5e}', Script::TaiTham),
+    ('\u{1a60}', '\u{1a7c}', Script::TaiTham),
+    ('\u{1a7f}', '\u{1a89}', Script::TaiTham),
+    ('\u{1a90}', '\u{1a99}', Script::TaiTham),
+    ('\u{1aa0}', '\u{1aad}', Script::TaiTham),
+    ('\u{1ab0}', '\u{1ace}', Script::Inherited),
+    ('\u{1b00}', '\u{1b4c}', Script::Balinese),
+    ('\u{1b4e}', '\u{1b7f}', Script::Balinese),
+    ('\u{1b80}', '\u{1bbf}', Script::Sundanese),
+    ('\u{1bc0}', '\u{1bf3}', Script::Batak),
+    ('\u{1bfc}', '\u{1bff}', Script::Batak),
+    ('\u{1c00}', '\u{1c37}', Script::Lepcha),
+    ('\u{1c3b}', '\u{1c49}', Script::Lepcha),
+    ('\u{1c4d}', '\u{1c4f}', Script::Lepcha),
+    ('\u{1c50}', '\u{1c7f}', Script::OlChiki),
+    ('\u{1c80}', '\u{1c8a}', Script::Cyrillic),
+    ('\u{1c90}', '\u{1cba}', Script::Georgian),
+    ('\u{1cbd}', '\u{1cbf}', Script::Georgian),
+    ('\u{1cc0}', '\u{1cc7}', Script::Sundanese),
+    ('\u{1cd0}', '\u{1cd2}', Script::Inherited),
+    ('\u{1cd3}', '\u{1cd3}', Script::Common),
+    ('\u{1cd4}', '\u{1ce0}', Script::Inherited),
+    ('\u{1ce1}', '\u{1ce1}', Script::Common),
+    ('\u{1ce2}', '\u{1ce8}', Script::Inherited),
+    ('\u{1ce9}', '\u{1cec}', Script::Common),
+    ('\u{1ced}', '\u{1ced}', Script::Inherited),
+    ('\u{1cee}', '\u{1cf3}', Script::Common),
+    ('\u{1cf4}', '\u{1cf4}', Script::Inherited),
+    ('\u{1cf5}', '\u{1cf7}', Script::Common),
+    ('\u{1cf8}', '\u{1cf9}', Script::Inherited),
+    ('\u{1cfa}', '\u{1cfa}', Script::Common),
+    ('\u{1d00}', '\u{1d25}', Script::Latin),
+    ('\u{1d26}', '\u{1d2a}', Script::Greek),
+    ('\u{1d2b}', '\u{1d2b}', Script::Cyrillic),
+    ('\u{1d2c}', '\u{1d5c}', Script::Latin),
+    ('\u{1d5d}', '\u{1d61}', Script::Greek),
+    ('\u{1d62}', '\u{1d65}', Script::Latin),
+    ('\u{1d66}', '\u{1d6a}', Script::Greek),
+    ('\u{1d6b}', '\u{1d77}', Script::Latin),
+    ('\u{1d78}', '\u{1d78}', Script::Cyrillic),
+    ('\u{1d79}', '\u{1dbe}', Script::Latin),
+    ('\u{1dbf}', '\u{1dbf}', Script::Greek),
+    ('\u{1dc0}', '\u{1dff}', Script::Inherited),
+    ('\u{1e00}', '\u{1eff}', Script::Latin),
+    ('\u{1f00}', '\u{1f15}', Script::Greek),
+    ('\u{1f18}', '\u{1f1d}', Script::Greek),
+    ('\u{1f20}', '\u{1f45}', Script::Greek),
+    ('\u{1f48}', '\u{1f4d}', Script::Greek),
+    ('\u{1f50}', '\u{1f57}', Script::Greek),
+    ('\u{1f59}', '\u{1f59}', Script::Greek),
+    ('\u{1f5b}', '\u{1f5b}', Script::Greek),
+    ('\u{1f5d}', '\u{1f5d}', Script::Greek),
+    ('\u{1f5f}', '\u{1f7d}', Script::Greek),
+    ('\u{1f80}', '\u{1fb4}', Script::Greek),
+    ('\u{1fb6}', '\u{1fc4}', Script::Greek),
+    ('\u{1fc6}', '\u{1fd3}', Script::Greek),
+    ('\u{1fd6}', '\u{1fdb}', Script::Greek),
+    ('\u{1fdd}', '\u{1fef}', Script::Greek),
+    ('\u{1ff2}', '\u{1ff4}', Script::Greek),
+    ('\u{1ff6}', '\u{1ffe}', Script::Greek),
+    ('\u{2000}', '\u{200b}', Script::Common),
+    ('\u{200c}', '\u{200d}', Script::Inherited),
+    ('\u{200e}', '\u{2064}', Script::Common),
+    ('\u{2066}', '\u{2070}', Script::Common),
+    ('\u{2071}', '\u{2071}', Script::Latin),
+    ('\u{2074}', '\u{207e}', Script::Common),
+    ('\u{207f}', '\u{207f}', Script::Latin),
+    ('\u{2080}', '\u{208e}', Script::Common),
+    ('\u{2090}', '\u{209c}', Script::Latin),
+    ('\u{20a0}', '\u{20c0}', Script::Common),
+    ('\u{20d0}', '\u{20f0}', Script::Inherited),
+    ('\u{2100}', '\u{2125}', Script::Common),
+    ('\u{2126}', '\u{2126}', Script::Greek),
+    ('\u{2127}', '\u{2129}', Script::Common),
+    ('\u{212a}', '\u{212b}', Script::Latin),
+    ('\u{212c}', '\u{2131}', Script::Common),
+    ('\u{2132}', '\u{2132}', Script::Latin),
+    ('\u{2133}', '\u{214d}', Script::Common),
+    ('\u{214e}', '\u{214e}', Script::Latin),
+    ('\u{214f}', '\u{215f}', Script::Common),
+    ('\u{2160}', '\u{2188}', Script::Latin),
+    ('\u{2189}', '\u{218b}', Script::Common),
+    ('\u{2190}', '\u{2429}', Script::Common),
+    ('\u{2440}', '\u{244a}', Script::Common),
+    ('\u{2460}', '\u{27ff}', Script::Common),
+    ('\u{2800}', '\u{28ff}', Script::Braille),
+    ('\u{2900}', '\u{2b73}', Script::Common),
+    ('\u{2b76}', '\u{2b95}', Script::Common),
+    ('\u{2b97}', '\u{2bff}', Script::Common),
+    ('\u{2c00}', '\u{2c5f}', Script::Glagolitic),
+    ('\u{2c60}', '\u{2c7f}', Script::Latin),
+    ('\u{2c80}', '\u{2cf3}', Script::Coptic),
+    ('\u{2cf9}', '\u{2cff}', Script::Coptic),
+    ('\u{2d00}', '\u{2d25}', Script::Georgian),
+    ('\u{2d27}', '\u{2d27}', Script::Georgian),
+    ('\u{2d2d}', '\u{2d2d}', Script::Georgian),
+    ('\u{2d30}', '\u{2d67}', Script::Tifinagh),
+    ('\u{2d6f}', '\u{2d70}', Script::Tifinagh),
+    ('\u{2d7f}', '\u{2d7f}', Script::Tifinagh),
+    ('\u{2d80}', '\u{2d96}', Script::Ethiopic),
+    ('\u{2da0}', '\u{2da6}', Script::Ethiopic),
+    ('\u{2da8}', '\u{2dae}', Script::Ethiopic),
+    ('\u{2db0}', '\u{2db6}', Script::Ethiopic),
+    ('\u{2db8}', '\u{2dbe}', Script::Ethiopic),
+    ('\u{2dc0}', '\u{2dc6}', Script::Ethiopic),
+    ('\u{2dc8}', '\u{2dce}', Script::Ethiopic),
+    ('\u{2dd0}', '\u{2dd6}', Script::Ethiopic),
+    ('\u{2dd8}', '\u{2dde}', Script::Ethiopic),
+    ('\u{2de0}', '\u{2dff}', Script::Cyrillic),
+    ('\u{2e00}', '\u{2e5d}', Script::Common),
+    ('\u{2e80}', '\u{2e99}', Script::Han),
+    ('\u{2e9b}', '\u{2ef3}', Script::Han),
+    ('\u{2f00}', '\u{2fd5}', Script::Han),
+    ('\u{2ff0}', '\u{3004}', Script::Common),
+    ('\u{3005}', '\u{3005}', Script::Han),
+    ('\u{3006}', '\u{3006}', Script::Common),
+    ('\u{3007}', '\u{3007}', Script::Han),
+    ('\u{3008}', '\u{3020}', Script::Common),
+    ('\u{3021}', '\u{3029}', Script::Han),
+    ('\u{302a}', '\u{302d}', Script::Inherited),
+    ('\u{302e}', '\u{302f}', Script::Hangul),
+    ('\u{3030}', '\u{3037}', Script::Common),
+    ('\u{3038}', '\u{303b}', Script::Han),
+    ('\u{303c}', '\u{303f}', Script::Common),
+    ('\u{3041}', '\u{3096}', Script::Hiragana),
+    ('\u{3099}', '\u{309a}', Script::Inherited),
+    ('\u{309b}', '\u{309c}', Script::Common),
+    ('\u{309d}', '\u{309f}', Script::Hiragana),
+    ('\u{30a0}', '\u{30a0}', Script::Common),
+    ('\u{30a1}', '\u{30fa}', Script::Katakana),
+    ('\u{30fb}', '\u{30fc}', Script::Common),
+    ('\u{30fd}', '\u{30ff}', Script::Katakana),
+    ('\u{3105}', '\u{312f}', Script::Bopomofo),
+    ('\u{3131}', '\u{318e}', Script::Hangul),
+    ('\u{3190}', '\u{319f}', Script::Common),
+    ('\u{31a0}', '\u{31bf}', Script::Bopomofo),
+    ('\u{31c0}', '\u{31e5}', Script::Common),
+    ('\u{31ef}', '\u{31ef}', Script::Common),
+    ('\u{31f0}', '\u{31ff}', Script::Katakana),
+    ('\u{3200}', '\u{321e}', Script::Hangul),
+    ('\u{3220}', '\u{325f}', Script::Common),
+    ('\u{3260}', '\u{327e}', Script::Hangul),
+    ('\u{327f}', '\u{32cf}', Script::Common),
+    ('\u{32d0}', '\u{32fe}', Script::Katakana),
+    ('\u{32ff}', '\u{32ff}', Script::Common),
+    ('\u{3300}', '\u{3357}', Script::Katakana),
+    ('\u{3358}', '\u{33ff}', Script::Common),
+    ('\u{3400}', '\u{4dbf}', Script::Han),
+    ('\u{4dc0}', '\u{4dff}', Script::Common),
+    ('\u{4e00}', '\u{9fff}', Script::Han),
+    ('\u{a000}', '\u{a48c}', Script::Yi),
+    ('\u{a490}', '\u{a4c6}', Script::Yi),
+    ('\u{a4d0}', '\u{a4ff}', Script::Lisu),
+    ('\u{a500}', '\u{a62b}', Script::Vai),
+    ('\u{a640}', '\u{a69f}', Script::Cyrillic),
+    ('\u{a6a0}', '\u{a6f7}', Script::Bamum),
+    ('\u{a700}', '\u{a721}', Script::Common),
+    ('\u{a722}', '\u{a787}', Script::Latin),
+    ('\u{a788}', '\u{a78a}', Script::Common),
+    ('\u{a78b}', '\u{a7cd}', Script::Latin),
+    ('\u{a7d0}', '\u{a7d1}', Script::Latin),
+    ('\u{a7d3}', '\u{a7d3}', Script::Latin),
+    ('\u{a7d5}', '\u{a7dc}', Script::Latin),
+    ('\u{a7f2}', '\u{a7ff}', Script::Latin),
+    ('\u{a800}', '\u{a82c}', Script::SylotiNagri),
+    ('\u{a830}', '\u{a839}', Script::Common),
+    ('\u{a840}', '\u{a877}', Script::PhagsPa),
+    ('\u{a880}', '\u{a8c5}', Script::Saurashtra),
+    ('\u{a8ce}', '\u{a8d9}', Script::Saurashtra),
+    ('\u{a8e0}', '\u{a8ff}', Script::Devanagari),
+    ('\u{a900}', '\u{a92d}', Script::KayahLi),
+    ('\u{a92e}', '\u{a92e}', Script::Common),
+    ('\u{a92f}', '\u{a92f}', Script::KayahLi),
+    ('\u{a930}', '\u{a953}', Script::Rejang),
+    ('\u{a95f}', '\u{a95f}', Script::Rejang),
+    ('\u{a960}', '\u{a97c}', Script::Hangul),
+    ('\u{a980}', '\u{a9cd}', Script::Javanese),
+    ('\u{a9cf}', '\u{a9cf}', Script::Common),
+    ('\u{a9d0}', '\u{a9d9}', Script::Javanese),
+    ('\u{a9de}', '\u{a9df}', Script::Javanese),
+    ('\u{a9e0}', '\u{a9fe}', Script::Myanmar),
+    ('\u{aa00}', '\u{aa36}', Script::Cham),
+    ('\u{aa40}', '\u{aa4d}', Script::Cham),
+    ('\u{aa50}', '\u{aa59}', Script::Cham),
+    ('\u{aa5c}', '\u{aa5f}', Script::Cham),
+    ('\u{aa60}', '\u{aa7f}', Script::Myanmar),
+    ('\u{aa80}', '\u{aac2}', Script::TaiViet),
+    ('\u{aadb}', '\u{aadf}', Script::TaiViet),
+    ('\u{aae0}', '\u{aaf6}', Script::MeeteiMayek),
+    ('\u{ab01}', '\u{ab06}', Script::Ethiopic),
+    ('\u{ab09}', '\u{ab0e}', Script::Ethiopic),
+    ('\u{ab11}', '\u{ab16}', Script::Ethiopic),
+    ('\u{ab20}', '\u{ab26}', Script::Ethiopic),
+    ('\u{ab28}', '\u{ab2e}', Script::Ethiopic),
+    ('\u{ab30}', '\u{ab5a}', Script::Latin),
+    ('\u{ab5b}', '\u{ab5b}', Script::Common),
+    ('\u{ab5c}', '\u{ab64}', Script::Latin),
+    ('\u{ab65}', '\u{ab65}', Script::Greek),
+    ('\u{ab66}', '\u{ab69}', Script::Latin),
+    ('\u{ab6a}', '\u{ab6b}', Script::Common),
+    ('\u{ab70}', '\u{abbf}', Script::Cherokee),
+    ('\u{abc0}', '\u{abed}', Script::MeeteiMayek),
+    ('\u{abf0}', '\u{abf9}', Script::MeeteiMayek),
+    ('\u{ac00}', '\u{d7a3}', Script::Hangul),
+    ('\u{d7b0}', '\u{d7c6}', Script::Hangul),
+    ('\u{d7cb}', '\u{d7fb}', Script::Hangul),
+    ('\u{f900}', '\u{fa6d}', Script::Han),
+    ('\u{fa70}', '\u{fad9}', Script::Han),
+    ('\u{fb00}', '\u{fb06}', Script::Latin),
+    ('\u{fb13}', '\u{fb17}', Script::Armenian),
+    ('\u{fb1d}', '\u{fb36}', Script::Hebrew),
+    ('\u{fb38}', '\u{fb3c}', Script::Hebrew),
+    ('\u{fb3e}', '\u{fb3e}', Script::Hebrew),
+    ('\u{fb40}', '\u{fb41}', Script::Hebrew),
+    ('\u{fb43}', '\u{fb44}', Script::Hebrew),
+    ('\u{fb46}', '\u{fb4f}', Script::Hebrew),
+    ('\u{fb50}', '\u{fbc2}', Script::Arabic),
+    ('\u{fbd3}', '\u{fd3d}', Script::Arabic),
+    ('\u{fd3e}', '\u{fd3f}', Script::Common),
+    ('\u{fd40}', '\u{fd8f}', Script::Arabic),
+    ('\u{fd92}', '\u{fdc7}', Script::Arabic),
+    ('\u{fdcf}', '\u{fdcf}', Script::Arabic),
+    ('\u{fdf0}', '\u{fdff}', Script::Arabic),
+    ('\u{fe00}', '\u{fe0f}', Script::Inherited),
+    ('\u{fe10}', '\u{fe19}', Script::Common),
+    ('\u{fe20}', '\u{fe2d}', Script::Inherited),
+    ('\u{fe2e}', '\u{fe2f}', Script::Cyrillic),
+    ('\u{fe30}', '\u{fe52}', Script::Common),
+    ('\u{fe54}', '\u{fe66}', Script::Common),
+    ('\u{fe68}', '\u{fe6b}', Script::Common),
+    ('\u{fe70}', '\u{fe74}', Script::Arabic),
+    ('\u{fe76}', '\u{fefc}', Script::Arabic),
+    ('\u{feff}', '\u{feff}', Script::Common),
+    ('\u{ff01}', '\u{ff20}', Script::Common),
+    ('\u{ff21}', '\u{ff3a}', Script::Latin),
+    ('\u{ff3b}', '\u{ff40}', Script::Common),
+    ('\u{ff41}', '\u{ff5a}', Script::Latin),
+    ('\u{ff5b}', '\u{ff65}', Script::Common),
+    ('\u{ff66}', '\u{ff6f}', Script::Katakana),
+    ('\u{ff70}', '\u{ff70}', Script::Common),
+    ('\u{ff71}', '\u{ff9d}', Script::Katakana),
+    ('\u{ff9e}', '\u{ff9f}', Script::Common),
+    ('\u{ffa0}', '\u{ffbe}', Script::Hangul),
+    ('\u{ffc2}', '\u{ffc7}', Script::Hangul),
+    ('\u{ffca}', '\u{ffcf}', Script::Hangul),
+    ('\u{ffd2}', '\u{ffd7}', Script::Hangul),
+    ('\u{ffda}', '\u{ffdc}', Script::Hangul),
+    ('\u{ffe0}', '\u{ffe6}', Script::Common),
+    ('\u{ffe8}', '\u{ffee}', Script::Common),
+    ('\u{fff9}', '\u{fffd}', Script::Common),
+    ('\u{10000}', '\u{1000b}', Script::LinearB),
+    ('\u{1000d}', '\u{10026}', Script::LinearB),
+    ('\u{10028}', '\u{1003a}', Script::LinearB),
+    ('\u{1003c}', '\u{1003d}', Script::LinearB),
+    ('\u{1003f}', '\u{1004d}', Script::LinearB),
+    ('\u{10050}', '\u{1005d}', Script::LinearB),
+    ('\u{10080}', '\u{100fa}', Script::LinearB),
+    ('\u{10100}', '\u{10102}', Script::Common),
+    ('\u{10107}', '\u{10133}', Script::Common),
+    ('\u{10137}', '\u{1013f}', Script::Common),
+    ('\u{10140}', '\u{1018e}', Script::Greek),
+    ('\u{10190}', '\u{1019c}', Script::Common),
+    ('\u{101a0}', '\u{101a0}', Script::Greek),
+    ('\u{101d0}', '\u{101fc}', Script::Common),
+    ('\u{101fd}', '\u{101fd}', Script::Inherited),
+    ('\u{10280}', '\u{1029c}', Script::Lycian),
+    ('\u{102a0}', '\u{102d0}', Script::Carian),
+    ('\u{102e0}', '\u{102e0}', Script::Inherited),
+    ('\u{102e1}', '\u{102fb}', Script::Common),
+    ('\u{10300}', '\u{10323}', Script::OldItalic),
+    ('\u{1032d}', '\u{1032f}', Script::OldItalic),
+    ('\u{10330}', '\u{1034a}', Script::Gothic),
+    ('\u{10350}', '\u{1037a}', Script::OldPermic),
+    ('\u{10380}', '\u{1039d}', Script::Ugaritic),
+    ('\u{1039f}', '\u{1039f}', Script::Ugaritic),
+    ('\u{103a0}', '\u{103c3}', Script::OldPersian),
+    ('\u{103c8}', '\u{103d5}', Script::OldPersian),
+    ('\u{10400}', '\u{1044f}', Script::Deseret),
+    ('\u{10450}', '\u{1047f}', Script::Shavian),
+    ('\u{10480}', '\u{1049d}', Script::Osmanya),
+    ('\u{104a0}', '\u{104a9}', Script::Osmanya),
+    ('\u{104b0}', '\u{104d3}', Script::Osage),
+    ('\u{104d8}', '\u{104fb}', Script::Osage),
+    ('\u{10500}', '\u{10527}', Script::Elbasan),
+    ('\u{10530}', '\u{10563}', Script::CaucasianAlbanian),
+    ('\u{1056f}', '\u{1056f}', Script::CaucasianAlbanian),
+    ('\u{10570}', '\u{1057a}', Script::Vithkuqi),
+    ('\u{1057c}', '\u{1058a}', Script::Vithkuqi),
+    ('\u{1058c}', '\u{10592}', Script::Vithkuqi),
+    ('\u{10594}', '\u{10595}', Script::Vithkuqi),
+    ('\u{10597}', '\u{105a1}', Script::Vithkuqi),
+    ('\u{105a3}', '\u{105b1}', Script::Vithkuqi),
+    ('\u{105b3}', '\u{105b9}', Script::Vithkuqi),
+    ('\u{105bb}', '\u{105bc}', Script::Vithkuqi),
+    ('\u{105c0}', '\u{105f3}', Script::Todhri),
+    ('\u{10600}', '\u{10736}', Script::LinearA),
+    ('\u{10740}', '\u{10755}', Script::LinearA),
+    ('\u{10760}', '\u{10767}', Script::LinearA),
+    ('\u{10780}', '\u{10785}', Script::Latin),
+    ('\u{10787}', '\u{107b0}', Script::Latin),
+    ('\u{107b2}', '\u{107ba}', Script::Latin),
+    ('\u{10800}', '\u{10805}', Script::Cypriot),
+    ('\u{10808}', '\u{10808}', Script::Cypriot),
+    ('\u{1080a}', '\u{10835}', Script::Cypriot),
+    ('\u{10837}', '\u{10838}', Script::Cypriot),
+    ('\u{1083c}', '\u{1083c}', Script::Cypriot),
+    ('\u{1083f}', '\u{1083f}', Script::Cypriot),
+    ('\u{10840}', '\u{10855}', Script::ImperialAramaic),
+    ('\u{10857}', '\u{1085f}', Script::ImperialAramaic),
+    ('\u{10860}', '\u{1087f}', Script::Palmyrene),
+    ('\u{10880}', '\u{1089e}', Script::Nabataean),
+    ('\u{108a7}', '\u{108af}', Script::Nabataean),
+    ('\u{108e0}', '\u{108f2}', Script::Hatran),
+    ('\u{108f4}', '\u{108f5}', Script::Hatran),
+    ('\u{108fb}', '\u{108ff}', Script::Hatran),
+    ('\u{10900}', '\u{1091b}', Script::Phoenician),
+    ('\u{1091f}', '\u{1091f}', Script::Phoenician),
+    ('\u{10920}', '\u{10939}', Script::Lydian),
+    ('\u{1093f}', '\u{1093f}', Script::Lydian),
+    ('\u{10980}', '\u{1099f}', Script::MeroiticHieroglyphs),
+    ('\u{109a0}', '\u{109b7}', Script::MeroiticCursive),
+    ('\u{109bc}', '\u{109cf}', Script::MeroiticCursive),
+    ('\u{109d2}', '\u{109ff}', Script::MeroiticCursive),
+    ('\u{10a00}', '\u{10a03}', Script::Kharoshthi),
+    ('\u{10a05}', '\u{10a06}', Script::Kharoshthi),
+    ('\u{10a0c}', '\u{10a13}', Script::Kharoshthi),
+    ('\u{10a15}', '\u{10a17}', Script::Kharoshthi),
+    ('\u{10a19}', '\u{10a35}', Script::Kharoshthi),
+    ('\u{10a38}', '\u{10a3a}', Script::Kharoshthi),
+    ('\u{10a3f}', '\u{10a48}', Script::Kharoshthi),
+    ('\u{10a50}', '\u{10a58}', Script::Kharoshthi),
+    ('\u{10a60}', '\u{10a7f}', Script::OldSouthArabian),
+    ('\u{10a80}', '\u{10a9f}', Script::OldNorthArabian),
+    ('\u{10ac0}', '\u{10ae6}', Script::Manichaean),
+    ('\u{10aeb}', '\u{10af6}', Script::Manichaean),
+    ('\u{10b00}', '\u{10b35}', Script::Avestan),
+    ('\u{10b39}', '\u{10b3f}', Script::Avestan),
+    ('\u{10b40}', '\u{10b55}', Script::InscriptionalParthian),
+    ('\u{10b58}', '\u{10b5f}', Script::InscriptionalParthian),
+    ('\u{10b60}', '\u{10b72}', Script::InscriptionalPahlavi),
+    ('\u{10b78}', '\u{10b7f}', Script::InscriptionalPahlavi),
+    ('\u{10b80}', '\u{10b91}', Script::PsalterPahlavi),
+    ('\u{10b99}', '\u{10b9c}', Script::PsalterPahlavi),
+    ('\u{10ba9}', '\u{10baf}', Script::PsalterPahlavi),
+    ('\u{10c00}', '\u{10c48}', Script::OldTurkic),
+    ('\u{10c80}', '\u{10cb2}', Script::OldHungarian),
+    ('\u{10cc0}', '\u{10cf2}', Script::OldHungarian),
+    ('\u{10cfa}', '\u{10cff}', Script::OldHungarian),
+    ('\u{10d00}', '\u{10d27}', Script::HanifiRohingya),
+    ('\u{10d30}', '\u{10d39}', Script::HanifiRohingya),
+    ('\u{10d40}', '\u{10d65}', Script::Garay),
+    ('\u{10d69}', '\u{10d85}', Script::Garay),
+    ('\u{10d8e}', '\u{10d8f}', Script::Garay),
+    ('\u{10e60}', '\u{10e7e}', Script::Arabic),
+    ('\u{10e80}', '\u{10ea9}', Script::Yezidi),
+    ('\u{10eab}', '\u{10ead}', Script::Yezidi),
+    ('\u{10eb0}', '\u{10eb1}', Script::Yezidi),
+    ('\u{10ec2}', '\u{10ec4}', Script::Arabic),
+    ('\u{10efc}', '\u{10eff}', Script::Arabic),
+    ('\u{10f00}', '\u{10f27}', Script::OldSogdian),
+    ('\u{10f30}', '\u{10f59}', Script::Sogdian),
+    ('\u{10f70}', '\u{10f89}', Script::OldUyghur),
+    ('\u{10fb0}', '\u{10fcb}', Script::Chorasmian),
+    ('\u{10fe0}', '\u{10ff6}', Script::Elymaic),
+    ('\u{11000}', '\u{1104d}', Script::Brahmi),
+    ('\u{11052}', '\u{11075}', Script::Brahmi),
+    ('\u{1107f}', '\u{1107f}', Script::Brahmi),
+    ('\u{11080}', '\u{110c2}', Script::Kaithi),
+    ('\u{110cd}', '\u{110cd}', Script::Kaithi),
+    ('\u{110d0}', '\u{110e8}', Script::SoraSompeng),
+    ('\u{110f0}', '\u{110f9}', Script::SoraSompeng),
+    ('\u{11100}', '\u{11134}', Script::Chakma),
+    ('\u{11136}', '\u{11147}', Script::Chakma),
+    ('\u{11150}', '\u{11176}', Script::Mahajani),
+    ('\u{11180}', '\u{111df}', Script::Sharada),
+    ('\u{111e1}', '\u{111f4}', Script::Sinhala),
+    ('\u{11200}', '\u{11211}', Script::Khojki),
+    ('\u{11213}', '\u{11241}', Script::Khojki),
+    ('\u{11280}', '\u{11286}', Script::Multani),
+    ('\u{11288}', '\u{11288}', Script::Multani),
+    ('\u{1128a}', '\u{1128d}', Script::Multani),
+    ('\u{1128f}', '\u{1129d}', Script::Multani),
+    ('\u{1129f}', '\u{112a9}', Script::Multani),
+    ('\u{112b0}', '\u{112ea}', Script::Khudawadi),
+    ('\u{112f0}', '\u{112f9}', Script::Khudawadi),
+    ('\u{11300}', '\u{11303}', Script::Grantha),
+    ('\u{11305}', '\u{1130c}', Script::Grantha),
+    ('\u{1130f}', '\u{11310}', Script::Grantha),
+    ('\u{11313}', '\u{11328}', Script::Grantha),
+    ('\u{1132a}', '\u{11330}', Script::Grantha),
+    ('\u{11332}', '\u{11333}', Script::Grantha),
+    ('\u{11335}', '\u{11339}', Script::Grantha),
+    ('\u{1133b}', '\u{1133b}', Script::Inherited),
+    ('\u{1133c}', '\u{11344}', Script::Grantha),
+    ('\u{11347}', '\u{11348}', Script::Grantha),
+    ('\u{1134b}', '\u{1134d}', Script::Grantha),
+    ('\u{11350}', '\u{11350}', Script::Grantha),
+    ('\u{11357}', '\u{11357}', Script::Grantha),
+    ('\u{1135d}', '\u{11363}', Script::Grantha),
+    ('\u{11366}', '\u{1136c}', Script::Grantha),
+    ('\u{11370}', '\u{11374}', Script::Grantha),
+    ('\u{11380}', '\u{11389}', Script::TuluTigalari),
+    ('\u{1138b}', '\u{1138b}', Script::TuluTigalari),
+    ('\u{1138e}', '\u{1138e}', Script::TuluTigalari),
+    ('\u{11390}', '\u{113b5}', Script::TuluTigalari),
+    ('\u{113b7}', '\u{113c0}', Script::TuluTigalari),
+    ('\u{113c2}', '\u{113c2}', Script::TuluTigalari),
+    ('\u{113c5}', '\u{113c5}', Script::TuluTigalari),
+    ('\u{113c7}', '\u{113ca}', Script::TuluTigalari),
+    ('\u{113cc}', '\u{113d5}', Script::TuluTigalari),
+    ('\u{113d7}', '\u{113d8}', Script::TuluTigalari),
+    ('\u{113e1}', '\u{113e2}', Script::TuluTigalari),
+    ('\u{11400}', '\u{1145b}', Script::Newa),
+    ('\u{1145d}', '\u{11461}', Script::Newa),
+    ('\u{11480}', '\u{114c7}', Script::Tirhuta),
+    ('\u{114d0}', '\u{114d9}', Script::Tirhuta),
+    ('\u{11580}', '\u{115b5}', Script::Siddham),
+    ('\u{115b8}', '\u{115dd}', Script::Siddham),
+    ('\u{11600}', '\u{11644}', Script::Modi),
+    ('\u{11650}', '\u{11659}', Script::Modi),
+    ('\u{11660}', '\u{1166c}', Script::Mongolian),
+    ('\u{11680}', '\u{116b9}', Script::Takri),
+    ('\u{116c0}', '\u{116c9}', Script::Takri),
+    ('\u{116d0}', '\u{116e3}', Script::Myanmar),
+    ('\u{11700}', '\u{1171a}', Script::Ahom),
+    ('\u{1171d}', '\u{1172b}', Script::Ahom),
+    ('\u{11730}', '\u{11746}', Script::Ahom),
+    ('\u{11800}', '\u{1183b}', Script::Dogra),
+    ('\u{118a0}', '\u{118f2}', Script::WarangCiti),
+    ('\u{118ff}', '\u{118ff}', Script::WarangCiti),
+    ('\u{11900}', '\u{11906}', Script::DivesAkuru),
+    ('\u{11909}', '\u{11909}', Script::DivesAkuru),
+    ('\u{1190c}', '\u{11913}', Script::DivesAkuru),
+    ('\u{11915}', '\u{11916}', Script::DivesAkuru),
+    ('\u{11918}', '\u{11935}', Script::DivesAkuru),
+    ('\u{11937}', '\u{11938}', Script::DivesAkuru),
+    ('\u{1193b}', '\u{11946}', Script::DivesAkuru),
+    ('\u{11950}', '\u{11959}', Script::DivesAkuru),
+    ('\u{119a0}', '\u{119a7}', Script::Nandinagari),
+    ('\u{119aa}', '\u{119d7}', Script::Nandinagari),
+    ('\u{119da}', '\u{119e4}', Script::Nandinagari),
+    ('\u{11a00}', '\u{11a47}', Script::ZanabazarSquare),
+    ('\u{11a50}', '\u{11aa2}', Script::Soyombo),
+    ('\u{11ab0}', '\u{11abf}', Script::CanadianAboriginal),
+    ('\u{11ac0}', '\u{11af8}', Script::PauCinHau),
+    ('\u{11b00}', '\u{11b09}', Script::Devanagari),
+    ('\u{11bc0}', '\u{11be1}', Script::Sunuwar),
+    ('\u{11bf0}', '\u{11bf9}', Script::Sunuwar),
+    ('\u{11c00}', '\u{11c08}', Script::Bhaiksuki),
+    ('\u{11c0a}', '\u{11c36}', Script::Bhaiksuki),
+    ('\u{11c38}', '\u{11c45}', Script::Bhaiksuki),
+    ('\u{11c50}', '\u{11c6c}', Script::Bhaiksuki),
+    ('\u{11c70}', '\u{11c8f}', Script::Marchen),
+    ('\u{11c92}', '\u{11ca7}', Script::Marchen),
+    ('\u{11ca9}', '\u{11cb6}', Script::Marchen),
+    ('\u{11d00}', '\u{11d06}', Script::MasaramGondi),
+    ('\u{11d08}', '\u{11d09}', Script::MasaramGondi),
+    ('\u{11d0b}', '\u{11d36}', Script::MasaramGondi),
+    ('\u{11d3a}', '\u{11d3a}', Script::MasaramGondi),
+    ('\u{11d3c}', '\u{11d3d}', Script::MasaramGondi),
+    ('\u{11d3f}', '\u{11d47}', Script::MasaramGondi),
+    ('\u{11d50}', '\u{11d59}', Script::MasaramGondi),
+    ('\u{11d60}', '\u{11d65}', Script::GunjalaGondi),
+    ('\u{11d67}', '\u{11d68}', Script::GunjalaGondi),
+    ('\u{11d6a}', '\u{11d8e}', Script::GunjalaGondi),
+    ('\u{11d90}', '\u{11d91}', Script::GunjalaGondi),
+    ('\u{11d93}', '\u{11d98}', Script::GunjalaGondi),
+    ('\u{11da0}', '\u{11da9}', Script::GunjalaGondi),
+    ('\u{11ee0}', '\u{11ef8}', Script::Makasar),
+    ('\u{11f00}', '\u{11f10}', Script::Kawi),
+    ('\u{11f12}', '\u{11f3a}', Script::Kawi),
+    ('\u{11f3e}', '\u{11f5a}', Script::Kawi),
+    ('\u{11fb0}', '\u{11fb0}', Script::Lisu),
+    ('\u{11fc0}', '\u{11ff1}', Script::Tamil),
+    ('\u{11fff}', '\u{11fff}', Script::Tamil),
+    ('\u{12000}', '\u{12399}', Script::Cuneiform),
+    ('\u{12400}', '\u{1246e}', Script::Cuneiform),
+    ('\u{12470}', '\u{12474}', Script::Cuneiform),
+    ('\u{12480}', '\u{12543}', Script::Cuneiform),
+    ('\u{12f90}', '\u{12ff2}', Script::CyproMinoan),
+    ('\u{13000}', '\u{13455}', Script::EgyptianHieroglyphs),
+    ('\u{13460}', '\u{143fa}', Script::EgyptianHieroglyphs),
+    ('\u{14400}', '\u{14646}', Script::AnatolianHieroglyphs),
+    ('\u{16100}', '\u{16139}', Script::GurungKhema),
+    ('\u{16800}', '\u{16a38}', Script::Bamum),
+    ('\u{16a40}', '\u{16a5e}', Script::Mro),
+    ('\u{16a60}', '\u{16a69}', Script::Mro),
+    ('\u{16a6e}', '\u{16a6f}', Script::Mro),
+    ('\u{16a70}', '\u{16abe}', Script::Tangsa),
+    ('\u{16ac0}', '\u{16ac9}', Script::Tangsa),
+    ('\u{16ad0}', '\u{16aed}', Script::BassaVah),
+    ('\u{16af0}', '\u{16af5}', Script::BassaVah),
+    ('\u{16b00}', '\u{16b45}', Script::PahawhHmong),
+    ('\u{16b50}', '\u{16b59}', Script::PahawhHmong),
+    ('\u{16b5b}', '\u{16b61}', Script::PahawhHmong),
+    ('\u{16b63}', '\u{16b77}', Script::PahawhHmong),
+    ('\u{16b7d}', '\u{16b8f}', Script::PahawhHmong),
+    ('\u{16d40}', '\u{16d79}', Script::KiratRai),
+    ('\u{16e40}', '\u{16e9a}', Script::Medefaidrin),
+    ('\u{16f00}', '\u{16f4a}', Script::Miao),
+    ('\u{16f4f}', '\u{16f87}', Script::Miao),
+    ('\u{16f8f}', '\u{16f9f}', Script::Miao),
+    ('\u{16fe0}', '\u{16fe0}', Script::Tangut),
+    ('\u{16fe1}', '\u{16fe1}', Script::Nushu),
+    ('\u{16fe2}', '\u{16fe3}', Script::Han),
+    ('\u{16fe4}', '\u{16fe4}', Script::KhitanSmallScript),
+    ('\u{16ff0}', '\u{16ff1}', Script::Han),
+    ('\u{17000}', '\u{187f7}', Script::Tangut),
+    ('\u{18800}', '\u{18aff}', Script::Tangut),
+    ('\u{18b00}', '\u{18cd5}', Script::KhitanSmallScript),
+    ('\u{18cff}', '\u{18cff}', Script::KhitanSmallScript),
+    ('\u{18d00}', '\u{18d08}', Script::Tangut),
+    ('\u{1aff0}', '\u{1aff3}', Script::Katakana),
+    ('\u{1aff5}', '\u{1affb}', Script::Katakana),
+    ('\u{1affd}', '\u{1affe}', Script::Katakana),
+    ('\u{1b000}', '\u{1b000}', Script::Katakana),
+    ('\u{1b001}', '\u{1b11f}', Script::Hiragana),
+    ('\u{1b120}', '\u{1b122}', Script::Katakana),
+    ('\u{1b132}', '\u{1b132}', Script::Hiragana),
+    ('\u{1b150}', '\u{1b152}', Script::Hiragana),
+    ('\u{1b155}', '\u{1b155}', Script::Katakana),
+    ('\u{1b164}', '\u{1b167}', Script::Katakana),
+    ('\u{1b170}', '\u{1b2fb}', Script::Nushu),
+    ('\u{1bc00}', '\u{1bc6a}', Script::Duployan),
+    ('\u{1bc70}', '\u{1bc7c}', Script::Duployan),
+    ('\u{1bc80}', '\u{1bc88}', Script::Duployan),
+    ('\u{1bc90}', '\u{1bc99}', Script::Duployan),
+    ('\u{1bc9c}', '\u{1bc9f}', Script::Duployan),
+    ('\u{1bca0}', '\u{1bca3}', Script::Common),
+    ('\u{1cc00}', '\u{1ccf9}', Script::Common),
+    ('\u{1cd00}', '\u{1ceb3}', Script::Common),
+    ('\u{1cf00}', '\u{1cf2d}', Script::Inherited),
+    ('\u{1cf30}', '\u{1cf46}', Script::Inherited),
+    ('\u{1cf50}', '\u{1cfc3}', Script::Common),
+    ('\u{1d000}', '\u{1d0f5}', Script::Common),
+    ('\u{1d100}', '\u{1d126}', Script::Common),
+    ('\u{1d129}', '\u{1d166}', Script::Common),
+    ('\u{1d167}', '\u{1d169}', Script::Inherited),
+    ('\u{1d16a}', '\u{1d17a}', Script::Common),
+    ('\u{1d17b}', '\u{1d182}', Script::Inherited),
+    ('\u{1d183}', '\u{1d184}', Script::Common),
+    ('\u{1d185}', '\u{1d18b}', Script::Inherited),
+    ('\u{1d18c}', '\u{1d1a9}', Script::Common),
+    ('\u{1d1aa}', '\u{1d1ad}', Script::Inherited),
+    ('\u{1d1ae}', '\u{1d1ea}', Script::Common),
+    ('\u{1d200}', '\u{1d245}', Script::Greek),
+    ('\u{1d2c0}', '\u{1d2d3}', Script::Common),
+    ('\u{1d2e0}', '\u{1d2f3}', Script::Common),
+    ('\u{1d300}', '\u{1d356}', Script::Common),
+    ('\u{1d360}', '\u{1d378}', Script::Common),
+    ('\u{1d400}', '\u{1d454}', Script::Common),
+    ('\u{1d456}', '\u{1d49c}', Script::Common),
+    ('\u{1d49e}', '\u{1d49f}', Script::Common),
+    ('\u{1d4a2}', '\u{1d4a2}', Script::Common),
+    ('\u{1d4a5}', '\u{1d4a6}', Script::Common),
+    ('\u{1d4a9}', '\u{1d4ac}', Script::Common),
+    ('\u{1d4ae}', '\u{1d4b9}', Script::Common),
+    ('\u{1d4bb}', '\u{1d4bb}', Script::Common),
+    ('\u{1d4bd}', '\u{1d4c3}', Script::Common),
+    ('\u{1d4c5}', '\u{1d505}', Script::Common),
+    ('\u{1d507}', '\u{1d50a}', Script::Common),
+    ('\u{1d50d}', '\u{1d514}', Script::Common),
+    ('\u{1d516}', '\u{1d51c}', Script::Common),
+    ('\u{1d51e}', '\u{1d539}', Script::Common),
+    ('\u{1d53b}', '\u{1d53e}', Script::Common),
+    ('\u{1d540}', '\u{1d544}', Script::Common),
+    ('\u{1d546}', '\u{1d546}', Script::Common),
+    ('\u{1d54a}', '\u{1d550}', Script::Common),
+    ('\u{1d552}', '\u{1d6a5}', Script::Common),
+    ('\u{1d6a8}', '\u{1d7cb}', Script::Common),
+    ('\u{1d7ce}', '\u{1d7ff}', Script::Common),
+    ('\u{1d800}', '\u{1da8b}', Script::SignWriting),
+    ('\u{1da9b}', '\u{1da9f}', Script::SignWriting),
+    ('\u{1daa1}', '\u{1daaf}', Script::SignWriting),
+    ('\u{1df00}', '\u{1df1e}', Script::Latin),
+    ('\u{1df25}', '\u{1df2a}', Script::Latin),
+    ('\u{1e000}', '\u{1e006}', Script::Glagolitic),
+    ('\u{1e008}', '\u{1e018}', Script::Glagolitic),
+    ('\u{1e01b}', '\u{1e021}', Script::Glagolitic),
+    ('\u{1e023}', '\u{1e024}', Script::Glagolitic),
+    ('\u{1e026}', '\u{1e02a}', Script::Glagolitic),
+    ('\u{1e030}', '\u{1e06d}', Script::Cyrillic),
+    ('\u{1e08f}', '\u{1e08f}', Script::Cyrillic),
+    ('\u{1e100}', '\u{1e12c}', Script::NyiakengPuachueHmong),
+    ('\u{1e130}', '\u{1e13d}', Script::NyiakengPuachueHmong),
+    ('\u{1e140}', '\u{1e149}', Script::NyiakengPuachueHmong),
+    ('\u{1e14e}', '\u{1e14f}', Script::NyiakengPuachueHmong),
+    ('\u{1e290}', '\u{1e2ae}', Script::Toto),
+    ('\u{1e2c0}', '\u{1e2f9}', Script::Wancho),
+    ('\u{1e2ff}', '\u{1e2ff}', Script::Wancho),
+    ('\u{1e4d0}', '\u{1e4f9}', Script::NagMundari),
+    ('\u{1e5d0}', '\u{1e5fa}', Script::OlOnal),
+    ('\u{1e5ff}', '\u{1e5ff}', Script::OlOnal),
+    ('\u{1e7e0}', '\u{1e7e6}', Script::Ethiopic),
+    ('\u{1e7e8}', '\u{1e7eb}', Script::Ethiopic),
+    ('\u{1e7ed}', '\u{1e7ee}', Script::Ethiopic),
+    ('\u{1e7f0}', '\u{1e7fe}', Script::Ethiopic),
+    ('\u{1e800}', '\u{1e8c4}', Script::MendeKikakui),
+    ('\u{1e8c7}', '\u{1e8d6}', Script::MendeKikakui),
+    ('\u{1e900}', '\u{1e94b}', Script::Adlam),
+    ('\u{1e950}', '\u{1e959}', Script::Adlam),
+    ('\u{1e95e}', '\u{1e95f}', Script::Adlam),
+    ('\u{1ec71}', '\u{1ecb4}', Script::Common),
+    ('\u{1ed01}', '\u{1ed3d}', Script::Common),
+    ('\u{1ee00}', '\u{1ee03}', Script::Arabic),
+    ('\u{1ee05}', '\u{1ee1f}', Script::Arabic),
+    ('\u{1ee21}', '\u{1ee22}', Script::Arabic),
+    ('\u{1ee24}', '\u{1ee24}', Script::Arabic),
+    ('\u{1ee27}', '\u{1ee27}', Script::Arabic),
+    ('\u{1ee29}', '\u{1ee32}', Script::Arabic),
+    ('\u{1ee34}', '\u{1ee37}', Script::Arabic),
+    ('\u{1ee39}', '\u{1ee39}', Script::Arabic),
+    ('\u{1ee3b}', '\u{1ee3b}', Script::Arabic),
+    ('\u{1ee42}', '\u{1ee42}', Script::Arabic),
+    ('\u{1ee47}', '\u{1ee47}', Script::Arabic),
+    ('\u{1ee49}', '\u{1ee49}', Script::Arabic),
+    ('\u{1ee4b}', '\u{1ee4b}', Script::Arabic),
+    ('\u{1ee4d}', '\u{1ee4f}', Script::Arabic),
+    ('\u{1ee51}', '\u{1ee52}', Script::Arabic),
+    ('\u{1ee54}', '\u{1ee54}', Script::Arabic),
+    ('\u{1ee57}', '\u{1ee57}', Script::Arabic),
+    ('\u{1ee59}', '\u{1ee59}', Script::Arabic),
+    ('\u{1ee5b}', '\u{1ee5b}', Script::Arabic),
+    ('\u{1ee5d}', '\u{1ee5d}', Script::Arabic),
+    ('\u{1ee5f}', '\u{1ee5f}', Script::Arabic),
+    ('\u{1ee61}', '\u{1ee62}', Script::Arabic),
+    ('\u{1ee64}', '\u{1ee64}', Script::Arabic),
+    ('\u{1ee67}', '\u{1ee6a}', Script::Arabic),
+    ('\u{1ee6c}', '\u{1ee72}', Script::Arabic),
+    ('\u{1ee74}', '\u{1ee77}', Script::Arabic),
+    ('\u{1ee79}', '\u{1ee7c}', Script::Arabic),
+    ('\u{1ee7e}', '\u{1ee7e}', Script::Arabic),
+    ('\u{1ee80}', '\u{1ee89}', Script::Arabic),
+    ('\u{1ee8b}', '\u{1ee9b}', Script::Arabic),
+    ('\u{1eea1}', '\u{1eea3}', Script::Arabic),
+    ('\u{1eea5}', '\u{1eea9}', Script::Arabic),
+    ('\u{1eeab}', '\u{1eebb}', Script::Arabic),
+    ('\u{1eef0}', '\u{1eef1}', Script::Arabic),
+    ('\u{1f000}', '\u{1f02b}', Script::Common),
+    ('\u{1f030}', '\u{1f093}', Script::Common),
+    ('\u{1f0a0}', '\u{1f0ae}', Script::Common),
+    ('\u{1f0b1}', '\u{1f0bf}', Script::Common),
+    ('\u{1f0c1}', '\u{1f0cf}', Script::Common),
+    ('\u{1f0d1}', '\u{1f0f5}', Script::Common),
+    ('\u{1f100}', '\u{1f1ad}', Script::Common),
+    ('\u{1f1e6}', '\u{1f1ff}', Script::Common),
+    ('\u{1f200}', '\u{1f200}', Script::Hiragana),
+    ('\u{1f201}', '\u{1f202}', Script::Common),
+    ('\u{1f210}', '\u{1f23b}', Script::Common),
+    ('\u{1f240}', '\u{1f248}', Script::Common),
+    ('\u{1f250}', '\u{1f251}', Script::Common),
+    ('\u{1f260}', '\u{1f265}', Script::Common),
+    ('\u{1f300}', '\u{1f6d7}', Script::Common),
+    ('\u{1f6dc}', '\u{1f6ec}', Script::Common),
+    ('\u{1f6f0}', '\u{1f6fc}', Script::Common),
+    ('\u{1f700}', '\u{1f776}', Script::Common),
+    ('\u{1f77b}', '\u{1f7d9}', Script::Common),
+    ('\u{1f7e0}', '\u{1f7eb}', Script::Common),
+    ('\u{1f7f0}', '\u{1f7f0}', Script::Common),
+    ('\u{1f800}', '\u{1f80b}', Script::Common),
+    ('\u{1f810}', '\u{1f847}', Script::Common),
+    ('\u{1f850}', '\u{1f859}', Script::Common),
+    ('\u{1f860}', '\u{1f887}', Script::Common),
+    ('\u{1f890}', '\u{1f8ad}', Script::Common),
+    ('\u{1f8b0}', '\u{1f8bb}', Script::Common),
+    ('\u{1f8c0}', '\u{1f8c1}', Script::Common),
+    ('\u{1f900}', '\u{1fa53}', Script::Common),
+    ('\u{1fa60}', '\u{1fa6d}', Script::Common),
+    ('\u{1fa70}', '\u{1fa7c}', Script::Common),
+    ('\u{1fa80}', '\u{1fa89}', Script::Common),
+    ('\u{1fa8f}', '\u{1fac6}', Script::Common),
+    ('\u{1face}', '\u{1fadc}', Script::Common),
+    ('\u{1fadf}', '\u{1fae9}', Script::Common),
+    ('\u{1faf0}', '\u{1faf8}', Script::Common),
+    ('\u{1fb00}', '\u{1fb92}', Script::Common),
+    ('\u{1fb94}', '\u{1fbf9}', Script::Common),
+    ('\u{20000}', '\u{2a6df}', Script::Han),
+    ('\u{2a700}', '\u{2b739}', Script::Han),
+    ('\u{2b740}', '\u{2b81d}', Script::Han),
+    ('\u{2b820}', '\u{2cea1}', Script::Han),
+    ('\u{2ceb0}', '\u{2ebe0}', Script::Han),
+    ('\u{2ebf0}', '\u{2ee5d}', Script::Han),
+    ('\u{2f800}', '\u{2fa1d}', Script::Han),
+    ('\u{30000}', '\u{3134a}', Script::Han),
+    ('\u{31350}', '\u{323af}', Script::Han),
+    ('\u{e0001}', '\u{e0001}', Script::Common),
+    ('\u{e0020}', '\u{e007f}', Script::Common),
+    ('\u{e0100}', '\u{e01ef}', Script::Inherited),
+];
@@ -0,0 +1,417 @@
+use std::ops::BitOr;
+
+use super::charset::CharSet;
+use super::properties::{WHITE_SPACE, XID_CONTINUE, XID_START};
+use super::satisfy::{next_if, Predicate};
+use super::tables::general_category::GENERAL_CATEGORY;
+use super::tables::script::{SCRIPT, SCRIPT_NAMES};
+use crate::parser::prelude::*;
+use crate::stream::traits::Stream;
+
+pub use super::tables::script::Script;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GeneralCategory {
+    Lu,
+    Ll,
+    Lt,
+    Lm,
+    Lo,
+    Mn,
+    Mc,
+    Me,
+    Nd,
+    Nl,
+    No,
+    Pc,
+    Pd,
+    Ps,
+    Pe,
+    Pi,
+    Pf,
+    Po,
+    Sm,
+    Sc,
+    Sk,
+    So,
+    Zs,
+    Zl,
+    Zp,
+    Cc,
+    Cf,
+    Cs,
+    Co,
+    Cn,
+}
+
+const GENERAL_CATEGORIES: [GeneralCategory; 30] = {
+    use GeneralCategory::*;
+    [
+        Lu, Ll, Lt, Lm, Lo, Mn, Mc, Me, Nd, Nl, No, Pc, Pd, Ps, Pe, Pi, Pf, Po, Sm, Sc, Sk, So, Zs,
+        Zl, Zp, Cc, Cf, Cs, Co, Cn,
+    ]
+};
+
+impl GeneralCategory {
+    pub fn of(ch: char) -> Self {
+        lookup(GENERAL_CATEGORY, ch).unwrap_or(GeneralCategory::Cn)
+    }
+
+    pub fn abbr(self) -> &'static str {
+        use GeneralCategory::*;
+        match self {
+            Lu => "Lu",
+            Ll => "Ll",
+            Lt => "Lt",
+            Lm => "Lm",
+            Lo => "Lo",
+            Mn => "Mn",
+            Mc => "Mc",
+            Me => "Me",
+            Nd => "Nd",
+            Nl => "Nl",
+            No => "No",
+            Pc => "Pc",
+            Pd => "Pd",
+            Ps => "Ps",
+            Pe => "Pe",
+            Pi => "Pi",
+            Pf => "Pf",
+            Po => "Po",
+            Sm => "Sm",
+            Sc => "Sc",
+            Sk => "Sk",
+            So => "So",
+            Zs => "Zs",
+            Zl => "Zl",
+            Zp => "Zp",
+            Cc => "Cc",
+            Cf => "Cf",
+            Cs => "Cs",
+            Co => "Co",
+            Cn => "Cn",
+        }
+    }
+
+    pub fn from_abbr(abbr: &str) -> Option<Self> {
+        GENERAL_CATEGORIES
+            .into_iter()
+            .find(|category| category.abbr() == abbr)
+    }
+
+    pub fn char_set(self) -> CharSet {
+        Categories::EMPTY.with(self).char_set()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Categories(u32);
+
+impl Categories {
+    pub const EMPTY: Self = Self(0);
+    pub const LETTER: Self = Self::of(&[
+        GeneralCategory::Lu,
+        GeneralCategory::Ll,
+        GeneralCategory::Lt,
+        GeneralCategory::Lm,
+        GeneralCategory::Lo,
+    ]);
+    pub const CASED_LETTER: Self = Self::of(&[
+        GeneralCategory::Lu,
+        GeneralCategory::Ll,
+        GeneralCategory::Lt,
+    ]);
+    pub const MARK: Self = Self::of(&[
+        GeneralCategory::Mn,
+        GeneralCategory::Mc,
+        GeneralCategory::Me,
+    ]);
+    pub const NUMBER: Self = Self::of(&[
+        GeneralCategory::Nd,
+        GeneralCategory::Nl,
+        GeneralCategory::No,
+    ]);
+    pub const PUNCTUATION: Self = Self::of(&[
+        GeneralCategory::Pc,
+        GeneralCategory::Pd,
+        GeneralCategory::Ps,
+        GeneralCategory::Pe,
+        GeneralCategory::Pi,
+        GeneralCategory::Pf,
+        GeneralCategory::Po,
+    ]);
+    pub const SYMBOL: Self = Self::of(&[
+        GeneralCategory::Sm,
+        GeneralCategory::Sc,
+        GeneralCategory::Sk,
+        GeneralCategory::So,
+    ]);
+    pub const SEPARATOR: Self = Self::of(&[
+        GeneralCategory::Zs,
+        GeneralCategory::Zl,
+        GeneralCategory::Zp,
+    ]);
+    pub const OTHER: Self = Self::of(&[
+        GeneralCategory::Cc,
+        GeneralCategory::Cf,
+        GeneralCategory::Cs,
+        GeneralCategory::Co,
+        GeneralCategory::Cn,
+    ]);
+
+    pub const fn of(categories: &[GeneralCategory]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut index = 0;
+        while index < categories.len() {
+            set = set.with(categories[index]);
+            index += 1;
+        }
+        set
+    }
+
+    pub const fn with(self, category: GeneralCategory) -> Self {
+        Self(self.0 | 1 << category as u32)
+    }
+
+    pub const fn has(self, category: GeneralCategory) -> bool {
+        self.0 & 1 << category as u32 != 0
+    }
+
+    pub fn contains(self, ch: char) -> bool {
+        self.has(GeneralCategory::of(ch))
+    }
+
+    // Unassigned code points (Cn) are not part of the resulting set.
+    pub fn char_set(self) -> CharSet {
+        CharSet::from_ranges(
+            GENERAL_CATEGORY
+                .iter()
+                .filter(|(_, _, category)| self.has(*category))
+                .map(|&(lo, hi, _)| (lo, hi)),
+        )
+    }
+}
+
+impl From<GeneralCategory> for Categories {
+    fn from(category: GeneralCategory) -> Self {
+        Categories::EMPTY.with(category)
+    }
+}
+
+impl<Cat> BitOr<Cat> for GeneralCategory
+where
+    Cat: Into<Categories>,
+{
+    type Output = Categories;
+
+    fn bitor(self, rhs: Cat) -> Self::Output {
+        Categories::from(self) | rhs
+    }
+}
+
+impl<Cat> BitOr<Cat> for Categories
+where
+    Cat: Into<Categories>,
+{
+    type Output = Categories;
+
+    fn bitor(self, rhs: Cat) -> Self::Output {
+        Self(self.0 | rhs.into().0)
+    }
+}
+
+impl Script {
+    pub fn of(ch: char) -> Self {
+        lookup(SCRIPT, ch).unwrap_or(Script::Unknown)
+    }
+
+    pub fn name(self) -> &'static str {
+        SCRIPT_NAMES
+            .iter()
+            .find(|(_, script)| *script == self)
+            .map(|(name, _)| *name)
+            .unwrap_or("Unknown")
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        SCRIPT_NAMES
+            .binary_search_by(|(other, _)| (*other).cmp(name))
+            .ok()
+            .map(|index| SCRIPT_NAMES[index].1)
+    }
+
+    pub fn char_set(self) -> CharSet {
+        Scripts::EMPTY.with(self).char_set()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Scripts([u64; 3]);
+
+impl Scripts {
+    pub const EMPTY: Self = Self([0; 3]);
+
+    pub const fn of(scripts: &[Script]) -> Self {
+        let mut set = Self::EMPTY;
+        let mut index = 0;
+        while index < scripts.len() {
+            set = set.with(scripts[index]);
+            index += 1;
+        }
+        set
+    }
+
+    pub const fn with(mut self, script: Script) -> Self {
+        let script = script as usize;
+        self.0[script / 64] |= 1 << (script % 64);
+        self
+    }
+
+    pub const fn has(self, script: Script) -> bool {
+        let script = script as usize;
+        self.0[script / 64] & 1 << (script % 64) != 0
+    }
+
+    pub fn contains(self, ch: char) -> bool {
+        self.has(Script::of(ch))
+    }
+
+    // Code points without an assigned script (Unknown) are not part of the
+    // resulting set.
+    pub fn char_set(self) -> CharSet {
+        CharSet::from_ranges(
+            SCRIPT
+                .iter()
+                .filter(|(_, _, script)| self.has(*script))
+                .map(|&(lo, hi, _)| (lo, hi)),
+        )
+    }
+}
+
+impl From<Script> for Scripts {
+    fn from(script: Script) -> Self {
+        Scripts::EMPTY.with(script)
+    }
+}
+
+impl<Scr> BitOr<Scr> for Script
+where
+    Scr: Into<Scripts>,
+{
+    type Output = Scripts;
+
+    fn bitor(self, rhs: Scr) -> Self::Output {
+        Scripts::from(self) | rhs
+    }
+}
+
+impl<Scr> BitOr<Scr> for Scripts
+where
+    Scr: Into<Scripts>,
+{
+    type Output = Scripts;
+
+    fn bitor(self, rhs: Scr) -> Self::Output {
+        let rhs = rhs.into();
+        Self([
+            self.0[0] | rhs.0[0],
+            self.0[1] | rhs.0[1],
+            self.0[2] | rhs.0[2],
+        ])
+    }
+}
+
+pub fn is_white_space(ch: char) -> bool {
+    contains(WHITE_SPACE, ch)
+}
+
+pub fn is_xid_start(ch: char) -> bool {
+    contains(XID_START, ch)
+}
+
+pub fn is_xid_continue(ch: char) -> bool {
+    contains(XID_CONTINUE, ch)
+}
+
+fn contains(table: &[(char, char)], ch: char) -> bool {
+    table
+        .binary_search_by(|&(lo, hi)| cmp_range(lo, hi, ch))
+        .is_ok()
+}
+
+fn lookup<T>(table: &[(char, char, T)], ch: char) -> Option<T>
+where
+    T: Copy,
+{
+    table
+        .binary_search_by(|&(lo, hi, _)| cmp_range(lo, hi, ch))
+        .ok()
+        .map(|index| table[index].2)
+}
+
+fn cmp_range(lo: char, hi: char, ch: char) -> std::cmp::Ordering {
+    match (lo > ch, hi < ch) {
+        (true, _) => std::cmp::Ordering::Greater,
+        (_, true) => std::cmp::Ordering::Less,
+        _ => std::cmp::Ordering::Equal,
+    }
+}
+
+impl Predicate<char> for GeneralCategory {
+    fn test(&self, item: &char) -> bool {
+        GeneralCategory::of(*item) == *self
+    }
+}
+
+impl Predicate<char> for Categories {
+    fn test(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+impl Predicate<char> for Script {
+    fn test(&self, item: &char) -> bool {
+        Script::of(*item) == *self
+    }
+}
+
+impl Predicate<char> for Scripts {
+    fn test(&self, item: &char) -> bool {
+        self.contains(*item)
+    }
+}
+
+macro_rules! char_class {
+    ($($ty:ty),*) => {$(
+        impl<Str> Parser<Str> for $ty
+        where
+            Str: Stream<Item = char>,
+        {
+            fn parse_stream(&self, input: &mut Str) -> Self::Output {
+                next_if(input, |ch| self.test(ch))
+            }
+        }
+
+        impl<Str> ParserMut<Str> for $ty
+        where
+            Str: Stream<Item = char>,
+        {
+            fn parse_stream_mut(&mut self, input: &mut Str) -> Self::Output {
+                self.parse_stream(input)
+            }
+        }
+
+        impl<Str> ParserOnce<Str> for $ty
+        where
+            Str: Stream<Item = char>,
+        {
+            type Output = Option<char>;
+
+            fn parse_stream_once(self, input: &mut Str) -> Self::Output {
+                self.parse_stream(input)
+            }
+        }
+    )*};
+}
+
+char_class!(GeneralCategory, Categories, Script, Scripts);
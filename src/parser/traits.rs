@@ -1,18 +1,23 @@
 use super::adapters::{
     and::And,
     attach::Attach,
+    fold::FoldLeft,
     ignore::Ignore,
+    lookaround::Peek,
     map::Map,
     map_err::MapErr,
     non_terminal::NonTerminal,
     opt::Opt,
     or::Or,
     repeat::{mode::*, *},
+    sequence::{Delimited, IgnoreThen, Padded, Separable, ThenIgnore},
+    then_with::ThenWith,
     try_map::TryMap,
 };
 use super::util::assoc::{err, val};
 use crate::data::prelude::*;
 use crate::stream::traits::Stream;
+use std::ops::RangeBounds;
 
 pub trait ParserOnce<Str>
 where
@@ -83,6 +88,13 @@ where
         Opt::new(self)
     }
 
+    fn peek(self) -> Peek<Self>
+    where
+        Self: Sized,
+    {
+        Peek::new(self)
+    }
+
     // Binary Adapters
 
     fn and<Par>(self, parser: Par) -> And<Self, Par>
@@ -94,6 +106,53 @@ where
         And::new(self, parser)
     }
 
+    fn then_ignore<Par>(self, parser: Par) -> ThenIgnore<Self, Par>
+    where
+        Self: Sized,
+        Self::Output: Separable,
+        Par: ParserOnce<Str>,
+        Par::Output: Ignorable,
+    {
+        ThenIgnore::new(self, parser)
+    }
+
+    fn ignore_then<Par>(self, parser: Par) -> IgnoreThen<Self, Par>
+    where
+        Self: Sized,
+        Self::Output: Ignorable,
+        Par: ParserOnce<Str>,
+        Par::Output: Separable,
+    {
+        IgnoreThen::new(self, parser)
+    }
+
+    fn delimited_by<Open, Close>(self, open: Open, close: Close) -> Delimited<Self, Open, Close>
+    where
+        Self: Sized,
+        Open: ParserOnce<Str>,
+        Close: ParserOnce<Str>,
+    {
+        Delimited::new(self, open, close)
+    }
+
+    fn padded_by<Pad>(self, padding: Pad) -> Padded<Self, Pad>
+    where
+        Self: Sized,
+        Pad: ParserMut<Str>,
+    {
+        Padded::new(self, padding)
+    }
+
+    fn then_with<Fun, Next>(self, f: Fun) -> ThenWith<Self, Fun>
+    where
+        Self: Sized,
+        Self::Output: ResultConvertable + Combinable<Next::Output>,
+        Fun: FnOnce(&<Self::Output as ResultConvertable>::Value) -> Next,
+        Next: ParserOnce<Str>,
+    {
+        ThenWith::new(self, f)
+    }
+
     fn or<Par>(self, parser: Par) -> Or<Self, Par>
     where
         Self: Sized,
@@ -103,6 +162,21 @@ where
         Or::new(self, parser)
     }
 
+    fn fold_left<Par, Mod, Fun>(
+        self,
+        rest: Repeater<Par, Mod>,
+        f: Fun,
+    ) -> FoldLeft<Self, Par, Mod, Fun>
+    where
+        Self: Sized,
+        Self::Output: Data,
+        Par: ParserMut<Str>,
+        Par::Output: ResultConvertable,
+        Fun: FnMut(val![Self], <Par::Output as ResultConvertable>::Value) -> val![Self],
+    {
+        FoldLeft::new(self, rest, f)
+    }
+
     // repeat
 
     fn repeat(self) -> Repeat<Self>
@@ -158,6 +232,27 @@ where
     {
         RepeatExact::new(self, Exact(count))
     }
+
+    fn repeat_range<Rng>(self, range: Rng) -> RepeatRange<Self>
+    where
+        Self: Sized,
+        Self: ParserMut<Str>,
+        Self::Output: UnerringConvertable,
+        Rng: RangeBounds<usize>,
+    {
+        RepeatRange::new(self, Range::new(range))
+    }
+
+    fn repeat_until<Term>(self, terminator: Term) -> RepeatUntil<Self, Term>
+    where
+        Self: Sized,
+        Self: ParserMut<Str>,
+        Self::Output: ResultConvertable,
+        Term: ParserMut<Str>,
+        Term::Output: ResultConvertable,
+    {
+        RepeatUntil::new(self, Until(terminator))
+    }
 }
 
 pub trait ParserMut<Str>: ParserOnce<Str>
@@ -1,4 +1,4 @@
-use super::traits::Stream;
+use super::traits::{Borrowed, Stream};
 
 impl<T> Stream for (Vec<T>, usize)
 where
@@ -53,9 +53,73 @@ where
     fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
         (start, end)
     }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        Some(self.0.len())
+    }
 }
 
-impl<'b> Stream for (&'b str, usize) {
+impl<T> Stream for (&[T], usize)
+where
+    T: Clone,
+{
+    type Item = T;
+    type Offset = usize;
+    type Span = (usize, usize);
+    type Peek<'a> = &'a T
+    where
+        Self: 'a;
+    type Slice<'a> = &'a [T]
+    where
+        Self: 'a;
+
+    fn offset(&self) -> Self::Offset {
+        self.1
+    }
+
+    fn offset_mut(&mut self) -> &mut Self::Offset {
+        &mut self.1
+    }
+
+    fn skip(&mut self) {
+        self.advance(1);
+    }
+
+    fn advance(&mut self, offset: Self::Offset) {
+        *self.offset_mut() += offset;
+    }
+
+    fn retract(&mut self) {
+        self.go_back(1);
+    }
+
+    fn go_back(&mut self, offset: Self::Offset) {
+        *self.offset_mut() -= offset;
+    }
+
+    fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
+        self.0.get(offset).cloned()
+    }
+
+    fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
+        self.0.get(offset)
+    }
+
+    fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
+        &self.0[start..end]
+    }
+
+    fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
+        (start, end)
+    }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        Some(self.0.len())
+    }
+}
+
+// Offsets are byte positions in the string, the same positions `slice` takes.
+impl Stream for (&str, usize) {
     type Item = char;
     type Offset = usize;
     type Span = (usize, usize);
@@ -75,7 +139,9 @@ impl<'b> Stream for (&'b str, usize) {
     }
 
     fn skip(&mut self) {
-        self.advance(1);
+        if let Some(ch) = self.peek() {
+            self.advance(ch.len_utf8());
+        }
     }
 
     fn advance(&mut self, offset: Self::Offset) {
@@ -83,7 +149,9 @@ impl<'b> Stream for (&'b str, usize) {
     }
 
     fn retract(&mut self) {
-        self.go_back(1);
+        if let Some(ch) = self.0[..self.1].chars().next_back() {
+            self.go_back(ch.len_utf8());
+        }
     }
 
     fn go_back(&mut self, offset: Self::Offset) {
@@ -91,11 +159,11 @@ impl<'b> Stream for (&'b str, usize) {
     }
 
     fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
-        self.0.chars().nth(offset)
+        self.peek_nth(offset)
     }
 
     fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
-        self.0.chars().nth(offset)
+        self.0.get(offset..)?.chars().next()
     }
 
     fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
@@ -105,4 +173,35 @@ impl<'b> Stream for (&'b str, usize) {
     fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
         (start, end)
     }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        Some(self.0.len())
+    }
+}
+
+impl<'src> Borrowed for (&'src str, usize) {
+    type Source = &'src str;
+
+    fn source(&self) -> Self::Source {
+        self.0
+    }
+
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source {
+        &self.0[start..end]
+    }
+}
+
+impl<'src, T> Borrowed for (&'src [T], usize)
+where
+    T: Clone,
+{
+    type Source = &'src [T];
+
+    fn source(&self) -> Self::Source {
+        self.0
+    }
+
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source {
+        &self.0[start..end]
+    }
 }
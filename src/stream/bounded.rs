@@ -0,0 +1,123 @@
+use super::traits::{Borrowed, Checkpoint, Stream};
+
+// A view of a stream that ends at `end`, for parsing a frame in place. The
+// offsets stay those of the underlying stream, so spans and slices taken
+// inside the frame are valid outside of it too.
+pub struct Bounded<'a, Str> {
+    stream: &'a mut Str,
+    end: usize,
+}
+
+impl<'a, Str> Bounded<'a, Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    pub fn new(stream: &'a mut Str, end: usize) -> Self {
+        Self { stream, end }
+    }
+
+    pub fn end(&self) -> usize {
+        self.end
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.end.saturating_sub(self.stream.offset())
+    }
+
+    pub fn get_ref(&self) -> &Str {
+        self.stream
+    }
+
+    pub fn get_mut(&mut self) -> &mut Str {
+        self.stream
+    }
+}
+
+impl<Str> Stream for Bounded<'_, Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    type Item = Str::Item;
+    type Offset = usize;
+    type Span = Str::Span;
+    type Peek<'a> = Str::Peek<'a>
+    where
+        Self: 'a;
+    type Slice<'a> = Str::Slice<'a>
+    where
+        Self: 'a;
+
+    fn offset(&self) -> Self::Offset {
+        self.stream.offset()
+    }
+
+    fn offset_mut(&mut self) -> &mut Self::Offset {
+        self.stream.offset_mut()
+    }
+
+    fn skip(&mut self) {
+        self.stream.skip();
+    }
+
+    fn advance(&mut self, offset: Self::Offset) {
+        self.stream.advance(offset);
+    }
+
+    fn retract(&mut self) {
+        self.stream.retract();
+    }
+
+    fn go_back(&mut self, offset: Self::Offset) {
+        self.stream.go_back(offset);
+    }
+
+    fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
+        match offset < self.end {
+            true => self.stream.nth(offset),
+            false => None,
+        }
+    }
+
+    fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
+        match offset < self.end {
+            true => self.stream.peek_nth(offset),
+            false => None,
+        }
+    }
+
+    fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
+        self.stream.slice(start.min(self.end), end.min(self.end))
+    }
+
+    fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
+        self.stream.span(start, end)
+    }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        Some(self.end)
+    }
+
+    fn checkpoint(&self) -> Checkpoint<Self::Offset> {
+        self.stream.checkpoint()
+    }
+
+    fn rewind(&mut self, checkpoint: Checkpoint<Self::Offset>) {
+        self.stream.rewind(checkpoint);
+    }
+}
+
+impl<Str> Borrowed for Bounded<'_, Str>
+where
+    Str: Borrowed,
+{
+    type Source = Str::Source;
+
+    fn source(&self) -> Self::Source {
+        self.stream.borrow_slice(0, self.end)
+    }
+
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source {
+        self.stream
+            .borrow_slice(start.min(self.end), end.min(self.end))
+    }
+}
@@ -0,0 +1,173 @@
+use super::bounded::Bounded;
+use super::traits::{Borrowed, Checkpoint, Stream};
+
+// Streams that remember named ranges of their input for `backref`.
+pub trait CaptureState: Stream<Offset = usize> {
+    fn capture(&mut self, name: &'static str, start: usize, end: usize);
+    fn captured(&self, name: &str) -> Option<(usize, usize)>;
+}
+
+// Captures are kept in a log where each one links back to the capture made
+// before it on the same path. A checkpoint is the latest capture, and since
+// nothing is dropped on rewinding, a checkpoint taken later on another path
+// brings its captures back. Capturing a name again shadows the previous range.
+pub struct Capturing<Str> {
+    stream: Str,
+    captures: Vec<Capture>,
+    latest: usize,
+}
+
+struct Capture {
+    name: &'static str,
+    start: usize,
+    end: usize,
+    // One past the index of the previous capture, zero for none.
+    previous: usize,
+}
+
+impl<Str> Capturing<Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    pub fn new(stream: Str) -> Self {
+        Self {
+            stream,
+            captures: vec![],
+            latest: 0,
+        }
+    }
+
+    pub fn get_ref(&self) -> &Str {
+        &self.stream
+    }
+
+    pub fn into_inner(self) -> Str {
+        self.stream
+    }
+}
+
+impl<Str> CaptureState for Capturing<Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    fn capture(&mut self, name: &'static str, start: usize, end: usize) {
+        self.captures.push(Capture {
+            name,
+            start,
+            end,
+            previous: self.latest,
+        });
+        self.latest = self.captures.len();
+    }
+
+    fn captured(&self, name: &str) -> Option<(usize, usize)> {
+        let mut latest = self.latest;
+        while latest > 0 {
+            let capture = &self.captures[latest - 1];
+            if capture.name == name {
+                return Some((capture.start, capture.end));
+            }
+            latest = capture.previous;
+        }
+        None
+    }
+}
+
+impl<Str> CaptureState for Bounded<'_, Str>
+where
+    Str: CaptureState,
+{
+    fn capture(&mut self, name: &'static str, start: usize, end: usize) {
+        self.get_mut().capture(name, start, end);
+    }
+
+    fn captured(&self, name: &str) -> Option<(usize, usize)> {
+        self.get_ref().captured(name)
+    }
+}
+
+impl<Str> Stream for Capturing<Str>
+where
+    Str: Stream<Offset = usize>,
+{
+    type Item = Str::Item;
+    type Offset = usize;
+    type Span = Str::Span;
+    type Peek<'a> = Str::Peek<'a>
+    where
+        Self: 'a;
+    type Slice<'a> = Str::Slice<'a>
+    where
+        Self: 'a;
+
+    fn offset(&self) -> Self::Offset {
+        self.stream.offset()
+    }
+
+    fn offset_mut(&mut self) -> &mut Self::Offset {
+        self.stream.offset_mut()
+    }
+
+    fn skip(&mut self) {
+        self.stream.skip();
+    }
+
+    fn advance(&mut self, offset: Self::Offset) {
+        self.stream.advance(offset);
+    }
+
+    fn retract(&mut self) {
+        self.stream.retract();
+    }
+
+    fn go_back(&mut self, offset: Self::Offset) {
+        self.stream.go_back(offset);
+    }
+
+    fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
+        self.stream.nth(offset)
+    }
+
+    fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
+        self.stream.peek_nth(offset)
+    }
+
+    fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
+        self.stream.slice(start, end)
+    }
+
+    fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
+        self.stream.span(start, end)
+    }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        self.stream.total_len()
+    }
+
+    fn checkpoint(&self) -> Checkpoint<Self::Offset> {
+        Checkpoint {
+            offset: self.stream.offset(),
+            state: self.latest,
+        }
+    }
+
+    fn rewind(&mut self, checkpoint: Checkpoint<Self::Offset>) {
+        self.latest = checkpoint.state;
+        *self.stream.offset_mut() = checkpoint.offset;
+    }
+}
+
+impl<Str> Borrowed for Capturing<Str>
+where
+    Str: Borrowed,
+{
+    type Source = Str::Source;
+
+    fn source(&self) -> Self::Source {
+        self.stream.source()
+    }
+
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source {
+        self.stream.borrow_slice(start, end)
+    }
+}
@@ -0,0 +1,185 @@
+use std::cell::{Cell, RefCell};
+
+use super::traits::{Borrowed, Checkpoint, Stream};
+
+const DEFAULT_STEP: usize = 64 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub offset: usize,
+    pub total: Option<usize>,
+}
+
+impl Progress {
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some(self.offset.min(total) as f64 / total as f64),
+            None => None,
+        }
+    }
+}
+
+// The callback only fires when the furthest offset reached crosses the next
+// multiple of `step`, so rewinds from `or` or `non_terminal` never repeat it.
+// Moves written through `offset_mut` can only be seen on the next access, so
+// adapters move with `advance`, `go_back` and `rewind` instead.
+pub struct Observed<Str, Fun> {
+    stream: Str,
+    callback: RefCell<Fun>,
+    step: usize,
+    next: Cell<usize>,
+}
+
+impl<Str, Fun> Observed<Str, Fun>
+where
+    Str: Stream<Offset = usize>,
+    Fun: FnMut(Progress),
+{
+    pub fn new(stream: Str, callback: Fun) -> Self {
+        let step = match stream.total_len() {
+            Some(total) => (total / 100).max(1),
+            None => DEFAULT_STEP,
+        };
+        Self::with_step(stream, step, callback)
+    }
+
+    pub fn with_step(stream: Str, step: usize, callback: Fun) -> Self {
+        let step = step.max(1);
+        let next = (stream.offset() / step + 1) * step;
+
+        Self {
+            stream,
+            callback: RefCell::new(callback),
+            step,
+            next: Cell::new(next),
+        }
+    }
+
+    pub fn every(self, step: usize) -> Self {
+        let stream = self.stream;
+        let callback = self.callback.into_inner();
+        Self::with_step(stream, step, callback)
+    }
+
+    pub fn total_len(&self) -> Option<usize> {
+        self.stream.total_len()
+    }
+
+    pub fn get_ref(&self) -> &Str {
+        &self.stream
+    }
+
+    pub fn into_inner(self) -> Str {
+        self.stream
+    }
+
+    #[inline(always)]
+    fn observe(&self) {
+        let offset = self.stream.offset();
+        if offset >= self.next.get() {
+            self.notify(offset);
+        }
+    }
+
+    #[cold]
+    fn notify(&self, offset: usize) {
+        self.next.set((offset / self.step + 1) * self.step);
+        if let Ok(mut callback) = self.callback.try_borrow_mut() {
+            callback(Progress {
+                offset,
+                total: self.stream.total_len(),
+            });
+        }
+    }
+}
+
+impl<Str, Fun> Stream for Observed<Str, Fun>
+where
+    Str: Stream<Offset = usize>,
+    Fun: FnMut(Progress),
+{
+    type Item = Str::Item;
+    type Offset = usize;
+    type Span = Str::Span;
+    type Peek<'a> = Str::Peek<'a>
+    where
+        Self: 'a;
+    type Slice<'a> = Str::Slice<'a>
+    where
+        Self: 'a;
+
+    fn offset(&self) -> Self::Offset {
+        self.stream.offset()
+    }
+
+    fn offset_mut(&mut self) -> &mut Self::Offset {
+        self.observe();
+        self.stream.offset_mut()
+    }
+
+    fn skip(&mut self) {
+        self.stream.skip();
+        self.observe();
+    }
+
+    fn advance(&mut self, offset: Self::Offset) {
+        self.stream.advance(offset);
+        self.observe();
+    }
+
+    fn retract(&mut self) {
+        self.stream.retract();
+    }
+
+    fn go_back(&mut self, offset: Self::Offset) {
+        self.stream.go_back(offset);
+    }
+
+    fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
+        self.observe();
+        self.stream.nth(offset)
+    }
+
+    fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
+        self.observe();
+        self.stream.peek_nth(offset)
+    }
+
+    fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
+        self.stream.slice(start, end)
+    }
+
+    fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
+        self.stream.span(start, end)
+    }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        self.stream.total_len()
+    }
+
+    fn checkpoint(&self) -> Checkpoint<Self::Offset> {
+        self.stream.checkpoint()
+    }
+
+    fn rewind(&mut self, checkpoint: Checkpoint<Self::Offset>) {
+        self.stream.rewind(checkpoint);
+        self.observe();
+    }
+}
+
+impl<Str, Fun> Borrowed for Observed<Str, Fun>
+where
+    Str: Borrowed,
+    Fun: FnMut(Progress),
+{
+    type Source = Str::Source;
+
+    fn source(&self) -> Self::Source {
+        self.stream.source()
+    }
+
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source {
+        self.stream.borrow_slice(start, end)
+    }
+}
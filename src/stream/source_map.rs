@@ -0,0 +1,236 @@
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+use std::ops::Range;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FileId(pub(crate) usize);
+
+impl FileId {
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub file: FileId,
+    pub range: Range<usize>,
+}
+
+impl Span {
+    pub fn new(file: FileId, range: Range<usize>) -> Self {
+        Self { file, range }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Location<'a> {
+    pub name: &'a str,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Display for Location<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}:{}", self.name, self.line, self.column)
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct SourceFile {
+    name: String,
+    text: String,
+    line_starts: Vec<usize>,
+}
+
+impl SourceFile {
+    fn new(name: String, text: String) -> Self {
+        let line_starts = std::iter::once(0)
+            .chain(text.match_indices('\n').map(|(index, _)| index + 1))
+            .collect();
+
+        Self {
+            name,
+            text,
+            line_starts,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.line_starts.len()
+    }
+
+    pub fn line_range(&self, line: usize) -> Option<Range<usize>> {
+        let start = *self.line_starts.get(line)?;
+        let end = self
+            .line_starts
+            .get(line + 1)
+            .map_or(self.text.len(), |next| next - 1);
+        Some(start..end)
+    }
+
+    // Zero-based line and column (in chars) of a byte offset.
+    pub fn line_column(&self, offset: usize) -> (usize, usize) {
+        let offset = offset.min(self.text.len());
+        let line = match self.line_starts.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        let column = self.text[self.line_starts[line]..offset].chars().count();
+        (line, column)
+    }
+}
+
+pub trait FileLoader {
+    type Error;
+
+    fn load(&mut self, path: &str) -> Result<String, Self::Error>;
+}
+
+impl<Ldr> FileLoader for &mut Ldr
+where
+    Ldr: FileLoader,
+{
+    type Error = Ldr::Error;
+
+    fn load(&mut self, path: &str) -> Result<String, Self::Error> {
+        (**self).load(path)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FileSystem;
+
+impl FileLoader for FileSystem {
+    type Error = std::io::Error;
+
+    fn load(&mut self, path: &str) -> Result<String, Self::Error> {
+        std::fs::read_to_string(path)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NotFound(pub String);
+
+#[derive(Clone, Debug, Default)]
+pub struct MemoryFiles {
+    files: HashMap<String, String>,
+}
+
+impl MemoryFiles {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&mut self, path: impl Into<String>, text: impl Into<String>) -> &mut Self {
+        self.files.insert(path.into(), text.into());
+        self
+    }
+}
+
+impl<Pat, Txt> FromIterator<(Pat, Txt)> for MemoryFiles
+where
+    Pat: Into<String>,
+    Txt: Into<String>,
+{
+    fn from_iter<Itr>(iter: Itr) -> Self
+    where
+        Itr: IntoIterator<Item = (Pat, Txt)>,
+    {
+        Self {
+            files: iter
+                .into_iter()
+                .map(|(path, text)| (path.into(), text.into()))
+                .collect(),
+        }
+    }
+}
+
+impl FileLoader for MemoryFiles {
+    type Error = NotFound;
+
+    fn load(&mut self, path: &str) -> Result<String, Self::Error> {
+        self.files
+            .get(path)
+            .cloned()
+            .ok_or_else(|| NotFound(path.to_owned()))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct SourceMap {
+    files: Vec<SourceFile>,
+    by_name: HashMap<String, FileId>,
+}
+
+impl SourceMap {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn add(&mut self, name: impl Into<String>, text: impl Into<String>) -> FileId {
+        let name = name.into();
+        let id = FileId(self.files.len());
+        self.by_name.insert(name.clone(), id);
+        self.files.push(SourceFile::new(name, text.into()));
+        id
+    }
+
+    // Files already in the map are not loaded a second time.
+    pub fn load<Ldr>(&mut self, loader: &mut Ldr, path: &str) -> Result<FileId, Ldr::Error>
+    where
+        Ldr: FileLoader,
+    {
+        match self.by_name.get(path) {
+            Some(id) => Ok(*id),
+            None => Ok(self.add(path, loader.load(path)?)),
+        }
+    }
+
+    pub fn lookup(&self, name: &str) -> Option<FileId> {
+        self.by_name.get(name).copied()
+    }
+
+    pub fn file(&self, id: FileId) -> &SourceFile {
+        &self.files[id.0]
+    }
+
+    pub fn name(&self, id: FileId) -> &str {
+        self.file(id).name()
+    }
+
+    pub fn text(&self, id: FileId) -> &str {
+        self.file(id).text()
+    }
+
+    pub fn snippet(&self, span: &Span) -> &str {
+        &self.text(span.file)[span.range.clone()]
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+
+    // One-based line and column of the start of `span`.
+    pub fn location(&self, span: &Span) -> Location<'_> {
+        let file = self.file(span.file);
+        let (line, column) = file.line_column(span.range.start);
+        Location {
+            name: file.name(),
+            line: line + 1,
+            column: column + 1,
+        }
+    }
+}
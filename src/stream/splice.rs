@@ -0,0 +1,241 @@
+use std::borrow::Cow;
+use std::ops::Range;
+
+use super::source_map::{FileId, FileLoader, Location, SourceMap, Span};
+use super::traits::Stream;
+
+#[derive(Clone, Debug)]
+struct Segment {
+    file: FileId,
+    range: Range<usize>,
+    start: usize,
+}
+
+impl Segment {
+    fn len(&self) -> usize {
+        self.range.len()
+    }
+
+    fn end(&self) -> usize {
+        self.start + self.len()
+    }
+
+    fn local(&self, offset: usize) -> usize {
+        self.range.start + offset - self.start
+    }
+}
+
+// Offsets are byte positions in the expanded text, i.e. the root file with
+// every spliced file pasted in at the position it was spliced at.
+#[derive(Clone, Debug)]
+pub struct Spliced {
+    map: SourceMap,
+    root: FileId,
+    segments: Vec<Segment>,
+    offset: usize,
+}
+
+impl Spliced {
+    pub fn new(map: SourceMap, root: FileId) -> Self {
+        let len = map.text(root).len();
+        let segments = match len {
+            0 => vec![],
+            len => vec![Segment {
+                file: root,
+                range: 0..len,
+                start: 0,
+            }],
+        };
+
+        Self {
+            map,
+            root,
+            segments,
+            offset: 0,
+        }
+    }
+
+    pub fn source_map(&self) -> &SourceMap {
+        &self.map
+    }
+
+    pub fn into_source_map(self) -> SourceMap {
+        self.map
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.last().map_or(0, Segment::end)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    pub fn splice(&mut self, file: FileId) {
+        let len = self.map.text(file).len();
+        if len == 0 {
+            return;
+        }
+
+        let offset = self.offset;
+        let index = match self.segment(offset) {
+            Some(index) if self.segments[index].start == offset => index,
+            Some(index) => {
+                let segment = &mut self.segments[index];
+                let split = segment.local(offset);
+                let tail = Segment {
+                    file: segment.file,
+                    range: split..segment.range.end,
+                    start: offset,
+                };
+                segment.range.end = split;
+                self.segments.insert(index + 1, tail);
+                index + 1
+            }
+            None => self.segments.len(),
+        };
+
+        for segment in &mut self.segments[index..] {
+            segment.start += len;
+        }
+        self.segments.insert(
+            index,
+            Segment {
+                file,
+                range: 0..len,
+                start: offset,
+            },
+        );
+    }
+
+    pub fn include<Ldr>(&mut self, loader: &mut Ldr, path: &str) -> Result<FileId, Ldr::Error>
+    where
+        Ldr: FileLoader,
+    {
+        let file = self.map.load(loader, path)?;
+        self.splice(file);
+        Ok(file)
+    }
+
+    pub fn location(&self, offset: usize) -> Location<'_> {
+        self.map.location(&self.span(offset, offset))
+    }
+
+    fn segment(&self, offset: usize) -> Option<usize> {
+        let index = self
+            .segments
+            .partition_point(|segment| segment.start <= offset)
+            .checked_sub(1)?;
+        match offset < self.segments[index].end() {
+            true => Some(index),
+            false => None,
+        }
+    }
+
+    fn text(&self, segment: &Segment) -> &str {
+        &self.map.text(segment.file)[segment.range.clone()]
+    }
+
+    fn char_at(&self, offset: usize) -> Option<char> {
+        let segment = &self.segments[self.segment(offset)?];
+        self.text(segment)[offset - segment.start..].chars().next()
+    }
+
+    fn char_before(&self, offset: usize) -> Option<char> {
+        let segment = &self.segments[self.segment(offset.checked_sub(1)?)?];
+        self.text(segment)[..offset - segment.start]
+            .chars()
+            .next_back()
+    }
+}
+
+impl Stream for Spliced {
+    type Item = char;
+    type Offset = usize;
+    type Span = Span;
+    type Peek<'a> = char
+    where
+        Self: 'a;
+    type Slice<'a> = Cow<'a, str>
+    where
+        Self: 'a;
+
+    fn offset(&self) -> Self::Offset {
+        self.offset
+    }
+
+    fn offset_mut(&mut self) -> &mut Self::Offset {
+        &mut self.offset
+    }
+
+    fn skip(&mut self) {
+        if let Some(ch) = self.char_at(self.offset) {
+            self.advance(ch.len_utf8());
+        }
+    }
+
+    fn advance(&mut self, offset: Self::Offset) {
+        self.offset += offset;
+    }
+
+    fn retract(&mut self) {
+        if let Some(ch) = self.char_before(self.offset) {
+            self.go_back(ch.len_utf8());
+        }
+    }
+
+    fn go_back(&mut self, offset: Self::Offset) {
+        self.offset -= offset;
+    }
+
+    fn nth(&mut self, offset: Self::Offset) -> Option<Self::Item> {
+        self.char_at(offset)
+    }
+
+    fn peek_nth(&self, offset: Self::Offset) -> Option<Self::Peek<'_>> {
+        self.char_at(offset)
+    }
+
+    fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_> {
+        let first = match self.segment(start) {
+            Some(first) => first,
+            None => return Cow::Borrowed(""),
+        };
+
+        let segment = &self.segments[first];
+        if end <= segment.end() {
+            return Cow::Borrowed(&self.text(segment)[start - segment.start..end - segment.start]);
+        }
+
+        let mut slice = String::with_capacity(end - start);
+        for segment in self.segments[first..]
+            .iter()
+            .take_while(|segment| segment.start < end)
+        {
+            let from = start.max(segment.start) - segment.start;
+            let to = end.min(segment.end()) - segment.start;
+            slice.push_str(&self.text(segment)[from..to]);
+        }
+        Cow::Owned(slice)
+    }
+
+    // Spans never cross file boundaries, so a span starting in one file is
+    // cut off where that file's segment ends.
+    fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span {
+        match self.segment(start) {
+            Some(index) => {
+                let segment = &self.segments[index];
+                let end = end.clamp(start, segment.end());
+                Span::new(segment.file, segment.local(start)..segment.local(end))
+            }
+            None => match self.segments.last() {
+                Some(segment) => Span::new(segment.file, segment.range.end..segment.range.end),
+                None => Span::new(self.root, 0..0),
+            },
+        }
+    }
+
+    fn total_len(&self) -> Option<Self::Offset> {
+        Some(self.len())
+    }
+}
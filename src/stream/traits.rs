@@ -1,5 +1,13 @@
 use crate::parser::traits::{Parse, Parser};
 
+// An offset together with the parse-local state reached along with it, so
+// that backtracking undoes both.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Checkpoint<Off> {
+    pub offset: Off,
+    pub state: usize,
+}
+
 pub trait Stream {
     type Item;
     type Offset;
@@ -26,6 +34,21 @@ pub trait Stream {
     fn slice(&self, start: Self::Offset, end: Self::Offset) -> Self::Slice<'_>;
     fn span(&self, start: Self::Offset, end: Self::Offset) -> Self::Span;
 
+    fn total_len(&self) -> Option<Self::Offset> {
+        None
+    }
+
+    fn checkpoint(&self) -> Checkpoint<Self::Offset> {
+        Checkpoint {
+            offset: self.offset(),
+            state: 0,
+        }
+    }
+
+    fn rewind(&mut self, checkpoint: Checkpoint<Self::Offset>) {
+        *self.offset_mut() = checkpoint.offset;
+    }
+
     fn next(&mut self) -> Option<Self::Item> {
         self.nth(self.offset())
     }
@@ -50,9 +73,14 @@ pub trait Stream {
     }
 
     fn has_next(&self) -> bool {
-        match self.peek() {
-            Some(_) => false,
-            None => true,
-        }
+        self.peek().is_some()
     }
 }
+
+// Streams over borrowed input, whose slices may outlive the stream itself.
+pub trait Borrowed: Stream<Offset = usize> {
+    type Source: Copy;
+
+    fn source(&self) -> Self::Source;
+    fn borrow_slice(&self, start: usize, end: usize) -> Self::Source;
+}
@@ -0,0 +1,115 @@
+use lavan::parser::adapters::capture::{backref, capture, BackrefError, BackrefErrorKind};
+use lavan::parser::adapters::lookaround::not;
+use lavan::parser::traits::{Parser, ParserOnce};
+use lavan::stream::capture::{CaptureState, Capturing};
+use lavan::stream::traits::Stream;
+
+type S = Capturing<(&'static str, usize)>;
+type Item = fn(&mut S) -> Option<char>;
+
+const ANY: Item = |input| {
+    let ch = input.peek()?;
+    input.skip();
+    Some(ch)
+};
+
+fn input(source: &'static str) -> S {
+    Capturing::new((source, 0))
+}
+
+fn error(kind: BackrefErrorKind, offset: usize) -> BackrefError<(usize, usize)> {
+    BackrefError {
+        kind,
+        name: "d",
+        span: (offset, offset),
+    }
+}
+
+#[test]
+fn backref_matches_ascii_captures() {
+    let mut input = input("abab");
+    assert_eq!(capture("d", "ab").parse_stream(&mut input), Some("ab"));
+    assert_eq!(backref("d").parse_stream(&mut input), Ok((2, 4)));
+    assert_eq!(input.offset(), 4);
+}
+
+#[test]
+fn backref_matches_non_ascii_captures() {
+    let mut input = input("éaéa");
+    let captured = capture("d", ANY.repeat_exact(2).to_vec()).parse_stream_once(&mut input);
+    assert_eq!(captured, Some(vec!['é', 'a']));
+    assert_eq!(input.captured("d"), Some((0, 3)));
+    assert_eq!(backref("d").parse_stream(&mut input), Ok((3, 6)));
+    assert_eq!(input.offset(), 6);
+}
+
+#[test]
+fn backref_mismatch_consumes_nothing() {
+    let mut input = input("éaéb");
+    capture("d", "éa").parse_stream(&mut input);
+    assert_eq!(
+        backref("d").parse_stream(&mut input),
+        Err(error(BackrefErrorKind::Mismatch, 3))
+    );
+    assert_eq!(input.offset(), 3);
+
+    // The capture runs past the end of what is left.
+    let mut input = self::input("éaé");
+    capture("d", "éa").parse_stream(&mut input);
+    assert_eq!(
+        backref("d").parse_stream(&mut input),
+        Err(error(BackrefErrorKind::Mismatch, 3))
+    );
+    assert_eq!(input.offset(), 3);
+}
+
+#[test]
+fn backref_without_capture_is_unbound() {
+    let mut input = input("a");
+    assert_eq!(
+        backref("d").parse_stream(&mut input),
+        Err(error(BackrefErrorKind::Unbound, 0))
+    );
+}
+
+#[test]
+fn failed_alternatives_drop_their_captures() {
+    let mut input = input("ac");
+    let first = ParserOnce::<S>::and(capture("d", "a"), "b");
+    let second: fn(&mut S) -> Option<(&'static str, &'static str)> = |input| {
+        let a = "a".parse_stream(input)?;
+        Some((a, "c".parse_stream(input)?))
+    };
+    let parser = ParserOnce::<S>::or(first, second);
+    assert_eq!(parser.parse_stream_once(&mut input), Some(("a", "c")));
+    assert_eq!(input.offset(), 2);
+    assert_eq!(input.captured("d"), None);
+    assert_eq!(
+        backref("d").parse_stream(&mut input),
+        Err(error(BackrefErrorKind::Unbound, 2))
+    );
+}
+
+#[test]
+fn non_terminal_drops_captures_on_failure() {
+    let mut input = input("ab");
+    capture("d", "a").parse_stream(&mut input);
+    let item = ParserOnce::<S>::and(capture("d", "b"), "c");
+    let parser = ParserOnce::<S>::non_terminal(item);
+    assert_eq!(parser.parse_stream_once(&mut input), None);
+    assert_eq!(input.offset(), 1);
+    assert_eq!(input.captured("d"), Some((0, 1)));
+}
+
+#[test]
+fn lookarounds_drop_their_captures() {
+    let mut input = input("ab");
+    let peeked = ParserOnce::<S>::peek(capture("d", "a")).parse_stream_once(&mut input);
+    assert_eq!(peeked, Some("a"));
+    assert_eq!(input.offset(), 0);
+    assert_eq!(input.captured("d"), None);
+
+    assert_eq!(not(capture("d", "a")).parse_stream(&mut input), None);
+    assert_eq!(input.offset(), 0);
+    assert_eq!(input.captured("d"), None);
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use common::run;
+use lavan::chars;
+use lavan::parser::sources::charset::CharSet;
+use lavan::parser::sources::satisfy::{satisfy, take_while};
+
+const HEX: CharSet = chars!['0'..='9', 'a'..='f', 'A'..='F'];
+
+#[test]
+fn the_macro_normalizes_its_ranges() {
+    let set = chars!['z', 'b'..='e', 'a'..='c', 'f', 'q'..='p'];
+    assert_eq!(set.ranges(), [('a', 'f'), ('z', 'z')]);
+    assert_eq!(HEX.ranges(), CharSet::HEX_DIGIT.ranges());
+    assert!(chars![].is_empty());
+}
+
+#[test]
+fn sets_combine() {
+    let sign = CharSet::from("+-");
+    let number = CharSet::DIGIT | sign.clone() | '.';
+    assert_eq!(number.ranges(), [('+', '+'), ('-', '.'), ('0', '9')]);
+
+    assert_eq!((CharSet::ALNUM & HEX).ranges(), HEX.ranges());
+    assert_eq!((HEX - CharSet::DIGIT).ranges(), [('A', 'F'), ('a', 'f')]);
+    assert_eq!(
+        (CharSet::ALPHA - ('b'..='y')).ranges(),
+        [('A', 'Z'), ('a', 'a'), ('z', 'z')]
+    );
+    assert!((sign & CharSet::DIGIT).is_empty());
+}
+
+#[test]
+fn negation_skips_the_surrogate_gap() {
+    assert_eq!((!CharSet::EMPTY).ranges(), CharSet::ANY.ranges());
+    assert!((!CharSet::ANY).is_empty());
+
+    let below = CharSet::from('\0'..='\u{D7FF}');
+    assert_eq!((!below).ranges(), [('\u{E000}', char::MAX)]);
+
+    // The code points on either side of the gap are adjacent.
+    let edges = CharSet::from_ranges([('\u{E000}', '\u{E000}'), ('\u{D7FF}', '\u{D7FF}')]);
+    assert_eq!(edges.ranges(), [('\u{D7FF}', '\u{E000}')]);
+    assert_eq!(
+        (CharSet::ANY - edges).ranges(),
+        [('\0', '\u{D7FE}'), ('\u{E001}', char::MAX)]
+    );
+}
+
+#[test]
+fn ascii_and_other_characters_agree() {
+    // ASCII lookups use the bitmap, the others search the ranges.
+    let set = CharSet::from('a'..='é');
+    assert!(set.contains('a') && set.contains('z') && set.contains('é'));
+    assert!(!set.contains('`') && !set.contains('ê'));
+
+    let not_alpha = !CharSet::ALPHA;
+    assert!(not_alpha.contains('1') && not_alpha.contains('é'));
+    assert!(!not_alpha.contains('a') && !not_alpha.contains('Z'));
+
+    let owned = CharSet::from_ranges([('\u{7F}', '\u{80}')]);
+    assert!(owned.contains('\u{7F}') && owned.contains('\u{80}'));
+    assert!(!owned.contains('\u{7E}') && !owned.contains('\u{81}'));
+}
+
+#[test]
+fn builtin_sets() {
+    assert!(CharSet::WHITESPACE.contains('\u{3000}'));
+    assert!(!CharSet::WHITESPACE.contains('\u{200B}'));
+    assert!(CharSet::XID_START.contains('é') && !CharSet::XID_START.contains('1'));
+    assert!(CharSet::XID_CONTINUE.contains('1') && CharSet::XID_CONTINUE.contains('\u{301}'));
+}
+
+#[test]
+fn sets_and_predicates_parse_characters() {
+    assert_eq!(run(CharSet::DIGIT, "7x"), (Some('7'), 1));
+    assert_eq!(run(CharSet::DIGIT, "x7"), (None, 0));
+    assert_eq!(run(!CharSet::ALPHA, "éa"), (Some('é'), 2));
+
+    assert_eq!(run(satisfy(char::is_ascii_uppercase), "Ab"), (Some('A'), 1));
+    assert_eq!(run(satisfy(char::is_ascii_uppercase), ""), (None, 0));
+
+    let (digits, offset) = run(take_while(CharSet::DIGIT), "123a");
+    assert_eq!((digits.value(), offset), ("123", 3));
+    let (letters, offset) = run(take_while(CharSet::XID_START), "été!");
+    assert_eq!((letters.value(), offset), ("été", 5));
+}
@@ -0,0 +1,103 @@
+mod common;
+
+use common::{run, S};
+use lavan::parser::adapters::choice::choice;
+use lavan::stream::traits::Stream;
+
+fn expect(expected: char) -> impl Fn(&mut S) -> Result<char, char> {
+    move |input| match input.peek() {
+        Some(ch) if ch == expected => {
+            input.skip();
+            Ok(ch)
+        }
+        found => Err(found.unwrap_or('$')),
+    }
+}
+
+const A: fn(&mut S) -> Result<char, char> = |input| expect('a')(input);
+const B: fn(&mut S) -> Option<char> = |input| expect('b')(input).ok();
+const DIGIT: fn(&mut S) -> Result<char, usize> = |input| match input.peek() {
+    Some(ch) if ch.is_ascii_digit() => {
+        input.skip();
+        Ok(ch)
+    }
+    _ => Err(input.offset()),
+};
+
+// An `a` followed by a `b`, which doesn't rewind when the `b` is missing.
+const AB: fn(&mut S) -> Option<char> = |input| {
+    expect('a')(input).ok()?;
+    expect('b')(input).ok()
+};
+
+#[test]
+fn tuples_return_every_error_side_by_side() {
+    let parser = || choice((A, B, DIGIT));
+    assert_eq!(run(parser(), "a"), (Ok('a'), 1));
+    assert_eq!(run(parser(), "b"), (Ok('b'), 1));
+    assert_eq!(run(parser(), "7"), (Ok('7'), 1));
+    assert_eq!(run(parser(), "x"), (Err(('x', (), 0)), 0));
+
+    // Nesting choices nests their errors, where chaining `or` would merge
+    // them.
+    let nested = choice((choice((A, DIGIT)), B));
+    assert_eq!(run(nested, "x"), (Err((('x', 0), ())), 0));
+}
+
+#[test]
+fn arrays_and_vecs_take_the_first_success() {
+    let keywords = || choice(["if", "in", "i"]);
+    assert_eq!(run(keywords(), "in"), (Ok("in"), 2));
+    assert_eq!(run(keywords(), "it"), (Ok("i"), 1));
+    assert_eq!(run(keywords(), "x"), (Err([(); 3]), 0));
+
+    let letters = || choice(vec![A, |input: &mut S| expect('y')(input)]);
+    assert_eq!(run(letters(), "y"), (Ok('y'), 1));
+    assert_eq!(run(letters(), "z"), (Err(vec!['z', 'z']), 0));
+    assert_eq!(
+        run(choice(Vec::<fn(&mut S) -> Option<char>>::new()), "a").0,
+        Err(vec![])
+    );
+}
+
+#[test]
+fn alternatives_after_the_first_start_from_the_same_offset() {
+    assert_eq!(
+        run(choice([AB, |input: &mut S| expect('a')(input).ok()]), "ac"),
+        (Ok('a'), 1)
+    );
+    // The last failure is left where it stopped.
+    assert_eq!(run(choice([AB, AB]), "ac"), (Err([(), ()]), 1));
+}
+
+#[cfg(feature = "either")]
+mod one_of {
+    use super::*;
+    use lavan::parser::adapters::choice::{OneOf2, OneOf3};
+
+    const WORD: fn(&mut S) -> Option<&'static str> = |input| {
+        let start = input.offset();
+        while input.peek().is_some_and(char::is_alphabetic) {
+            input.skip();
+        }
+        (input.offset() > start).then(|| &input.0[start..input.offset()])
+    };
+
+    const NUMBER: fn(&mut S) -> Result<u32, usize> = |input| {
+        let digit = DIGIT(input)?;
+        Ok(digit.to_digit(10).unwrap())
+    };
+
+    #[test]
+    fn alternatives_with_different_values() {
+        let parser = || choice((NUMBER, WORD)).one_of::<S>();
+        assert_eq!(run(parser(), "7"), (Ok(OneOf2::A(7)), 1));
+        assert_eq!(run(parser(), "ab1"), (Ok(OneOf2::B("ab")), 2));
+        assert_eq!(run(parser(), ";"), (Err((0, ())), 0));
+
+        let parser = choice((NUMBER, WORD, A)).one_of::<S>();
+        assert_eq!(run(parser, "%"), (Err((0, (), '%')), 0));
+        let parser = choice((NUMBER, B, WORD)).one_of::<S>();
+        assert_eq!(run(parser, "bc"), (Ok(OneOf3::B('b')), 1));
+    }
+}
@@ -0,0 +1,65 @@
+mod common;
+
+use common::run;
+use lavan::parser::sources::comment::{
+    block_comment, line_comment, CommentError, CommentErrorKind,
+};
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+fn error(kind: CommentErrorKind, span: (usize, usize)) -> CommentError<(usize, usize)> {
+    CommentError { kind, span }
+}
+
+#[test]
+fn line_comments_stop_before_the_line_ending() {
+    assert_eq!(run(line_comment("//"), "// hi\nx"), (Some(" hi"), 5));
+    assert_eq!(run(line_comment("//"), "// hi\r\nx"), (Some(" hi"), 5));
+    assert_eq!(run(line_comment("#"), "# a\rb"), (Some(" a\rb"), 5));
+    assert_eq!(run(line_comment("--"), "--"), (Some(""), 2));
+    assert_eq!(run(line_comment("//"), "/ x"), (None, 0));
+}
+
+#[test]
+fn flat_block_comments_end_at_the_first_close() {
+    let comment = || block_comment("/*", "*/", false);
+    assert_eq!(run(comment(), "/* a /* b */ c */"), (Ok(" a /* b "), 12));
+    assert_eq!(run(comment(), "/* é */x"), (Ok(" é "), 8));
+    assert_eq!(run(comment(), "/**/"), (Ok(""), 4));
+    assert_eq!(
+        run(comment(), "x /* */"),
+        (Err(error(CommentErrorKind::Missing, (0, 0))), 0)
+    );
+}
+
+#[test]
+fn nested_block_comments_balance_their_delimiters() {
+    let comment = || block_comment("/*", "*/", true);
+    assert_eq!(
+        run(comment(), "/* a /* b */ c */;"),
+        (Ok(" a /* b */ c "), 17)
+    );
+    assert_eq!(
+        run(block_comment("{-", "-}", true), "{- {- -} -}"),
+        (Ok(" {- -} "), 11)
+    );
+}
+
+#[test]
+fn unterminated_comments_point_at_the_opening_delimiter() {
+    use CommentErrorKind::Unterminated;
+    let nested = || block_comment("/*", "*/", true);
+    assert_eq!(
+        run(nested(), "/* a /* b */"),
+        (Err(error(Unterminated, (0, 2))), 0)
+    );
+    assert_eq!(
+        run(block_comment("(*", "*)", false), "(* a"),
+        (Err(error(Unterminated, (0, 2))), 0)
+    );
+
+    let mut input = ("x <!-- a", 2);
+    let output = block_comment("<!--", "-->", false).parse_stream_once(&mut input);
+    assert_eq!(output, Err(error(Unterminated, (2, 6))));
+    assert_eq!(input.offset(), 2);
+}
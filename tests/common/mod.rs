@@ -0,0 +1,19 @@
+// Helpers shared by the integration tests. Each test crate uses a different
+// subset of them.
+#![allow(dead_code)]
+
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+pub type S = (&'static str, usize);
+
+// Runs `parser` over the whole of `source`, returning its output and the
+// offset it stopped at.
+pub fn run<Par>(parser: Par, source: &'static str) -> (Par::Output, usize)
+where
+    Par: ParserOnce<S>,
+{
+    let mut input = (source, 0);
+    let output = parser.parse_stream_once(&mut input);
+    (output, input.offset())
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::{run, S};
+use lavan::dispatch;
+use lavan::parser::adapters::dispatch::{
+    dispatch, dispatch_branches, DispatchError, DispatchKeyError,
+};
+use lavan::parser::traits::Parser;
+use lavan::stream::traits::Stream;
+
+const WORD: fn(&mut S) -> Option<&'static str> = |input| {
+    let start = input.offset();
+    while input.peek().is_some_and(|ch| ch.is_ascii_lowercase()) {
+        input.skip();
+    }
+    Some(&input.0[start..input.offset()])
+};
+
+const REST: fn(&mut S) -> Option<&'static str> = |input| {
+    let start = input.offset();
+    input.1 = input.0.len();
+    Some(&input.0[start..])
+};
+
+#[test]
+fn branches_can_have_different_types() {
+    let parser = dispatch!['a'..='z' => WORD, '0' => "0", '1'..='9' => "1"];
+    assert_eq!(run(&parser, "abc1"), (Ok("abc"), 3));
+    assert_eq!(run(&parser, "0"), (Ok("0"), 1));
+    assert_eq!(run(&parser, "12"), (Ok("1"), 1));
+    assert_eq!(run(&parser, "2"), (Err(DispatchError::Branch(())), 0));
+}
+
+#[test]
+fn unmatched_items_go_to_the_default() {
+    let parser = dispatch!['a'..='z' => WORD, '0' => "0", _ => REST];
+    assert_eq!(run(&parser, "?!"), (Ok("?!"), 2));
+    assert_eq!(run(&parser, ""), (Ok(""), 0));
+}
+
+#[test]
+fn unmatched_items_without_a_default_are_unexpected() {
+    let parser = dispatch!['0'..='9' => "0", 'a' => "a"];
+    let mut input = ("?", 0);
+    let error = parser.parse_stream(&mut input).unwrap_err();
+    assert_eq!(
+        error,
+        DispatchError::Unexpected {
+            expected: vec!['0'..='9', 'a'..='a'],
+            found: Some('?'),
+            span: (0, 0),
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "expected one of '0'..='9', 'a', found '?'"
+    );
+}
+
+#[test]
+fn invalid_keys_are_errors() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let empty = dispatch([('b'..='a', "a")]);
+    assert_eq!(empty.err(), Some(DispatchKeyError::Empty('b'..='a')));
+
+    let overlapping = dispatch([('a'..='m', "a"), ('k'..='z', "k")]);
+    assert_eq!(
+        overlapping.err(),
+        Some(DispatchKeyError::Overlapping('a'..='m', 'k'..='z'))
+    );
+
+    let missing = dispatch_branches([('a'..='z', 1)], None, ("a",));
+    assert_eq!(missing.err(), Some(DispatchKeyError::Missing(1)));
+
+    let missing = dispatch_branches([('a'..='z', 0)], Some(3), ("a",));
+    assert_eq!(missing.err(), Some(DispatchKeyError::Missing(3)));
+}
+
+#[test]
+fn dispatch_tables_take_a_default_parser() {
+    let parser = dispatch([('a'..='z', "a"), ('0'..='9', "0")])
+        .unwrap()
+        .default("?");
+    assert_eq!(run(&parser, "?"), (Ok("?"), 1));
+    assert_eq!(run(&parser, "0"), (Ok("0"), 1));
+}
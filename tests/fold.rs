@@ -0,0 +1,97 @@
+mod common;
+
+use common::{run, S};
+use lavan::parser::adapters::repeat::RepeatError;
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+const DIGIT: fn(&mut S) -> Option<u32> = |input| {
+    let digit = input.peek()?.to_digit(10)?;
+    input.skip();
+    Some(digit)
+};
+
+const STRICT_DIGIT: fn(&mut S) -> Result<u32, char> = |input| match input.peek() {
+    Some(ch) if ch.is_ascii_digit() => {
+        input.skip();
+        Ok(ch.to_digit(10).unwrap())
+    }
+    found => Err(found.unwrap_or('$')),
+};
+
+// A digit after a `-`.
+const MINUS: fn(&mut S) -> Option<u32> = |input| {
+    let start = input.checkpoint();
+    if input.peek() == Some('-') {
+        input.skip();
+        if let Some(digit) = DIGIT(input) {
+            return Some(digit);
+        }
+    }
+    input.rewind(start);
+    None
+};
+
+fn decimal(number: u32, digit: u32) -> u32 {
+    number * 10 + digit
+}
+
+#[test]
+fn fold_threads_the_accumulator() {
+    let (number, offset) = run(DIGIT.repeat().fold(0, decimal), "123x");
+    assert_eq!(number.value(), 123);
+    assert_eq!(offset, 3);
+
+    let push = |mut digits: Vec<u32>, digit| {
+        digits.push(digit);
+        digits
+    };
+    let (digits, _) = run(DIGIT.repeat().fold(vec![], push), "321");
+    assert_eq!(digits.value(), [3, 2, 1]);
+
+    let (number, offset) = run(DIGIT.repeat().fold(7, decimal), "x");
+    assert_eq!(number.value(), 7);
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn fold_keeps_the_response_of_its_mode() {
+    assert_eq!(
+        run(DIGIT.repeat_max(2).fold(0, decimal), "123").0.value(),
+        12
+    );
+    assert_eq!(
+        run(DIGIT.repeat_exact(2).fold(0, decimal), "123"),
+        (Some(12), 2)
+    );
+    assert_eq!(run(DIGIT.repeat_exact(4).fold(0, decimal), "123").0, None);
+
+    assert_eq!(
+        run(STRICT_DIGIT.repeat_min(2).fold(0, decimal), "123x"),
+        (Ok(123), 3)
+    );
+    assert_eq!(
+        run(STRICT_DIGIT.repeat_min(4).fold(0, decimal), "123x").0,
+        Err('x')
+    );
+
+    let (number, offset) = run(STRICT_DIGIT.repeat_eoi().fold(0, decimal), "123");
+    assert_eq!((number, offset), (Ok(123), 3));
+    let (number, _) = run(STRICT_DIGIT.repeat_eoi().fold(0, decimal), "12x");
+    assert_eq!(number, Err(RepeatError::Item('x')));
+}
+
+#[test]
+fn fold_left_associates_to_the_left() {
+    let subtract = |left: u32, right| left.wrapping_sub(right);
+    let parser = || ParserOnce::<S>::fold_left(DIGIT, MINUS.repeat(), subtract);
+    // (9 - 2) - 3, where 9 - (2 - 3) would be 10.
+    assert_eq!(run(parser(), "9-2-3"), (Some(4), 5));
+    assert_eq!(run(parser(), "9-2-x"), (Some(7), 3));
+    assert_eq!(run(parser(), "9"), (Some(9), 1));
+    assert_eq!(run(parser(), "-2"), (None, 0));
+
+    let digits = || ParserOnce::<S>::fold_left(DIGIT, DIGIT.repeat_exact(2), decimal);
+    assert_eq!(run(digits(), "123"), (Some(123), 3));
+    assert_eq!(run(digits(), "12").0, None);
+}
@@ -0,0 +1,124 @@
+use lavan::parser::adapters::framed::{count_prefixed, length_prefixed, tlv, FrameError};
+use lavan::parser::sources::satisfy::{satisfy, take_while, Satisfy, TakeWhile};
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+type B = (&'static [u8], usize);
+type Byte = Satisfy<fn(&u8) -> bool>;
+
+fn byte() -> Byte {
+    satisfy(|_| true)
+}
+
+fn lowercase() -> Byte {
+    satisfy(u8::is_ascii_lowercase)
+}
+
+fn run<Par>(parser: Par, source: &'static [u8]) -> (Par::Output, usize)
+where
+    Par: ParserOnce<B>,
+{
+    let mut input = (source, 0);
+    let output = parser.parse_stream_once(&mut input);
+    (output, input.offset())
+}
+
+#[test]
+fn length_prefixed_bodies_stop_at_the_frame() {
+    let parser = length_prefixed(byte(), take_while(u8::is_ascii_lowercase));
+    assert_eq!(run(parser, b"\x03abcd"), (Ok(&b"abc"[..]), 4));
+
+    let body = ParserOnce::<B>::repeat(lowercase()).collector(vec![]);
+    let parser = length_prefixed(byte(), body);
+    assert_eq!(run(parser, b"\x02abc"), (Ok(b"ab".to_vec()), 3));
+
+    let parser = length_prefixed(byte(), take_while(u8::is_ascii_lowercase));
+    assert_eq!(run(parser, b"\x00abc"), (Ok(&b""[..]), 1));
+}
+
+#[test]
+fn length_prefixed_failures_rewind() {
+    let parser = || length_prefixed(byte(), take_while(u8::is_ascii_lowercase));
+    assert_eq!(run(parser(), b""), (Err(FrameError::Header(())), 0));
+    assert_eq!(
+        run(parser(), b"\x04abc"),
+        (Err(FrameError::Truncated((0, 1))), 0)
+    );
+    assert_eq!(
+        run(parser(), b"\x03a1b"),
+        (Err(FrameError::Unconsumed((2, 4))), 0)
+    );
+
+    let parser = length_prefixed(byte(), lowercase());
+    assert_eq!(run(parser, b"\x011"), (Err(FrameError::Body(())), 0));
+}
+
+const LOWERCASE: fn(&mut B) -> Option<u8> = |input| {
+    let byte = *input.peek().filter(|byte| byte.is_ascii_lowercase())?;
+    input.skip();
+    Some(byte)
+};
+
+#[test]
+fn count_prefixed_takes_that_many_items() {
+    let parser = count_prefixed(byte(), LOWERCASE).collector(vec![]);
+    assert_eq!(run(parser, b"\x02abc"), (Ok(b"ab".to_vec()), 3));
+
+    let parser = count_prefixed(byte(), LOWERCASE).collector(vec![]);
+    assert_eq!(run(parser, b"\x03ab1"), (Err(FrameError::Body(())), 0));
+
+    let parser = count_prefixed(byte(), LOWERCASE).collector(vec![]);
+    assert_eq!(run(parser, b""), (Err(FrameError::Header(())), 0));
+
+    // A count that doesn't fit in a `usize` is truncated.
+    let negative: fn(&mut B) -> Option<i8> = |input| {
+        input.skip();
+        Some(-1)
+    };
+    let parser = count_prefixed(negative, LOWERCASE).collector(vec![]);
+    assert_eq!(
+        run(parser, b"\xffab"),
+        (Err(FrameError::Truncated((0, 1))), 0)
+    );
+}
+
+type Body = TakeWhile<fn(&u8) -> bool>;
+
+fn table() -> [(u8, Body); 2] {
+    [
+        (b'l', take_while(u8::is_ascii_lowercase)),
+        (b'd', take_while(u8::is_ascii_digit)),
+    ]
+}
+
+#[test]
+fn tlv_picks_the_body_by_tag() {
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"l\x02abc"),
+        (Ok((b'l', &b"ab"[..])), 4)
+    );
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"d\x0112"),
+        (Ok((b'd', &b"1"[..])), 3)
+    );
+}
+
+#[test]
+fn tlv_failures_rewind() {
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"x\x01a"),
+        (Err(FrameError::UnknownTag((0, 1))), 0)
+    );
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"l\x03ab"),
+        (Err(FrameError::Truncated((0, 2))), 0)
+    );
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"d\x021a"),
+        (Err(FrameError::Unconsumed((3, 4))), 0)
+    );
+    assert_eq!(
+        run(tlv(byte(), byte(), table()), b"l"),
+        (Err(FrameError::Header(())), 0)
+    );
+}
@@ -0,0 +1,69 @@
+mod common;
+
+use common::{run, S};
+use lavan::chars;
+use lavan::parser::sources::ident::identifier;
+use lavan::parser::traits::ParserOnce;
+
+#[test]
+fn identifiers_follow_uax_31() {
+    assert_eq!(run(identifier(), "café_1 x"), (Some("café_1"), 7));
+    assert_eq!(run(identifier(), "_x"), (Some("_x"), 2));
+    assert_eq!(run(identifier(), "π2;"), (Some("π2"), 3));
+    assert_eq!(run(identifier(), "1x"), (None, 0));
+    assert_eq!(run(identifier(), "·x"), (None, 0));
+}
+
+#[test]
+fn ascii_identifiers_stop_at_other_characters() {
+    assert_eq!(run(identifier().ascii(), "café"), (Some("caf"), 3));
+    assert_eq!(run(identifier().ascii(), "π2"), (None, 0));
+    assert_eq!(run(identifier().ascii(), "_a1"), (Some("_a1"), 3));
+}
+
+#[test]
+fn identifiers_take_custom_character_sets() {
+    let kebab = identifier().chars(chars!['a'..='z'], chars!['a'..='z', '-']);
+    assert_eq!(run(kebab, "kebab-case_x"), (Some("kebab-case"), 10));
+}
+
+#[test]
+fn reserved_words_are_not_identifiers() {
+    let keywords = &["let", "fn"];
+    assert_eq!(run(identifier().reserved(keywords), "let x"), (None, 0));
+    assert_eq!(
+        run(identifier().reserved(keywords), "letter"),
+        (Some("letter"), 6)
+    );
+    assert_eq!(
+        run(identifier().reserved(keywords), "fn_"),
+        (Some("fn_"), 3)
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Let,
+    Name(&'static str),
+}
+
+fn keyword(_: &str) -> Token {
+    Token::Let
+}
+
+// The annotations pin the stream, which `or` can't infer from two sources
+// that are generic over it. `keyword` is a function because the second
+// parser of `or` has to be a `Parser`, and a closure passed to `map` is only
+// inferred as `FnOnce`.
+fn token() -> impl ParserOnce<S, Output = Option<Token>> {
+    let name = ParserOnce::<S>::map(identifier().reserved(&["let"]), Token::Name);
+    let keyword = ParserOnce::<S>::map("let", keyword);
+    ParserOnce::<S>::or(name, keyword)
+}
+
+#[test]
+fn reserved_words_fall_through_to_keywords() {
+    assert_eq!(run(token(), "let"), (Some(Token::Let), 3));
+    assert_eq!(run(token(), "letter"), (Some(Token::Name("letter")), 6));
+    assert_eq!(run(token(), "1"), (None, 0));
+}
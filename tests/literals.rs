@@ -0,0 +1,74 @@
+mod common;
+
+use common::run;
+use lavan::parser::sources::literals::{literal_map, one_of_literals};
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Op {
+    Less,
+    LessEqual,
+    Shift,
+    ShiftAssign,
+}
+
+#[test]
+fn the_longest_literal_wins() {
+    let ops = || one_of_literals(["<", "<=", "<<=", "<<"]);
+    assert_eq!(run(ops(), "<<x"), (Some(3), 2));
+    assert_eq!(run(ops(), "<<=x"), (Some(2), 3));
+    assert_eq!(run(ops(), "<=<"), (Some(1), 2));
+    assert_eq!(run(ops(), "<x"), (Some(0), 1));
+}
+
+#[test]
+fn order_does_not_matter() {
+    let ops = || {
+        literal_map([
+            ("<<=", Op::ShiftAssign),
+            ("<", Op::Less),
+            ("<<", Op::Shift),
+            ("<=", Op::LessEqual),
+        ])
+    };
+    assert_eq!(run(ops(), "<<x"), (Some(Op::Shift), 2));
+    assert_eq!(run(ops(), "<<="), (Some(Op::ShiftAssign), 3));
+    assert_eq!(run(ops(), "<="), (Some(Op::LessEqual), 2));
+    assert_eq!(run(ops(), "<"), (Some(Op::Less), 1));
+}
+
+#[test]
+fn later_duplicates_replace_earlier_values() {
+    let ops = literal_map([("<", Op::Less), ("<", Op::Shift)]);
+    assert_eq!(run(ops, "<"), (Some(Op::Shift), 1));
+}
+
+#[test]
+fn failures_leave_the_offset() {
+    let ops = || one_of_literals(["<=", "<<="]);
+    assert_eq!(run(ops(), "<"), (None, 0));
+    assert_eq!(run(ops(), "<<"), (None, 0));
+    assert_eq!(run(ops(), ""), (None, 0));
+
+    // Starting past the beginning of the input.
+    let mut input = ("a<<b", 1);
+    assert_eq!(ops().parse_stream_once(&mut input), None);
+    assert_eq!(input.offset(), 1);
+}
+
+#[test]
+fn literals_match_bytes() {
+    let methods = || literal_map([(&b"GET"[..], 0), (b"GETS", 1), (b"POST", 2)]);
+    let parse = |source: &'static [u8]| {
+        let mut input = (source, 0);
+        let output = methods().parse_stream_once(&mut input);
+        (output, input.offset())
+    };
+    assert_eq!(parse(b"GET /"), (Some(0), 3));
+    assert_eq!(parse(b"GETSx"), (Some(1), 4));
+    assert_eq!(parse(b"POS"), (None, 0));
+
+    let pairs = one_of_literals([&[1u8, 2], &[1, 3]]);
+    assert_eq!(pairs.longest_match(&[1, 3, 4]), Some((2, &1)));
+}
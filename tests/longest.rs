@@ -0,0 +1,58 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use lavan::parser::adapters::capture::capture;
+use lavan::parser::adapters::longest::longest;
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::capture::{CaptureState, Capturing};
+use lavan::stream::traits::Stream;
+
+type S = Capturing<(&'static str, usize)>;
+
+static RUNS: AtomicUsize = AtomicUsize::new(0);
+
+const WORD: fn(&mut S) -> Option<usize> = |input| {
+    RUNS.fetch_add(1, Ordering::Relaxed);
+    let start = input.offset();
+    while input.peek().is_some_and(char::is_alphabetic) {
+        input.skip();
+    }
+    (input.offset() > start).then_some(input.offset() - start)
+};
+
+const LETTER: fn(&mut S) -> Option<usize> = |input| {
+    input.peek().filter(|ch| ch.is_alphabetic())?;
+    input.skip();
+    Some(1)
+};
+
+#[test]
+fn the_winner_is_not_run_again() {
+    let mut input = Capturing::new(("abc;", 0));
+    let output = longest((WORD, LETTER)).parse_stream_once(&mut input);
+    assert_eq!(output, Ok(3));
+    assert_eq!(input.offset(), 3);
+    assert_eq!(RUNS.load(Ordering::Relaxed), 1);
+}
+
+#[test]
+fn ties_go_to_the_earliest_and_failures_are_merged() {
+    let mut input = Capturing::new(("a;", 0));
+    let other: fn(&mut S) -> Option<usize> = |input| LETTER(input).map(|_| 2);
+    let output = longest((LETTER, other)).parse_stream_once(&mut input);
+    assert_eq!(output, Ok(1));
+
+    let mut input = Capturing::new((";", 0));
+    let output = longest((LETTER, LETTER)).parse_stream_once(&mut input);
+    assert_eq!(output, Err(((), ())));
+    assert_eq!(input.offset(), 0);
+}
+
+#[test]
+fn the_winner_keeps_its_captures() {
+    let mut input = Capturing::new(("ab;", 0));
+    let output =
+        longest((capture("d", "ab"), capture("d", "a"), "a")).parse_stream_once(&mut input);
+    assert_eq!(output, Ok("ab"));
+    assert_eq!(input.offset(), 2);
+    assert_eq!(input.captured("d"), Some((0, 2)));
+}
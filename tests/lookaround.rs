@@ -0,0 +1,84 @@
+use lavan::parser::adapters::lookaround::{not, preceded_by};
+use lavan::parser::traits::{Parser, ParserMut, ParserOnce};
+use lavan::stream::traits::Stream;
+
+type S = (&'static str, usize);
+
+const LETTER: fn(&mut S) -> Option<char> = |input| {
+    let ch = input.peek().filter(|ch| ch.is_alphabetic())?;
+    input.skip();
+    Some(ch)
+};
+
+fn at(source: &'static str, offset: usize) -> S {
+    (source, offset)
+}
+
+#[test]
+fn peek_rewinds_on_success_and_failure() {
+    let mut input = at("ab", 0);
+    assert_eq!(
+        ParserOnce::<S>::peek("ab").parse_stream_once(&mut input),
+        Some("ab")
+    );
+    assert_eq!(input.offset(), 0);
+
+    let mut input = at("ab", 0);
+    let a_then_c = ParserOnce::<S>::and("a", "c");
+    assert_eq!(
+        ParserOnce::<S>::peek(a_then_c).parse_stream_once(&mut input),
+        None
+    );
+    assert_eq!(input.offset(), 0);
+}
+
+#[test]
+fn not_consumes_nothing() {
+    let mut input = at("ab", 0);
+    assert_eq!(not("ab").parse_stream(&mut input), None);
+    assert_eq!(input.offset(), 0);
+    assert_eq!(not("ac").parse_stream(&mut input), Some(()));
+    assert_eq!(input.offset(), 0);
+}
+
+#[test]
+fn keywords_end_at_a_boundary() {
+    let mut keyword = "if".then_ignore(not(LETTER));
+    assert_eq!(keyword.parse_stream_mut(&mut at("if x", 0)), Some("if"));
+
+    assert_eq!(keyword.parse_stream_mut(&mut at("iffy", 0)), None);
+}
+
+#[test]
+fn preceded_by_looks_back_and_rewinds() {
+    let mut input = at("ab", 2);
+    assert_eq!(preceded_by("b", 1).parse_stream(&mut input), Some(()));
+    assert_eq!(preceded_by("ab", 1).parse_stream(&mut input), None);
+    assert_eq!(preceded_by("ab", 2).parse_stream(&mut input), Some(()));
+    assert_eq!(preceded_by("a", 2).parse_stream(&mut input), None);
+    assert_eq!(input.offset(), 2);
+}
+
+#[test]
+fn preceded_by_steps_over_whole_characters() {
+    let mut input = at("éa", 3);
+    assert_eq!(preceded_by("éa", 2).parse_stream(&mut input), Some(()));
+    assert_eq!(preceded_by("éa", 1).parse_stream(&mut input), None);
+    assert_eq!(input.offset(), 3);
+}
+
+#[test]
+fn preceded_by_stops_at_the_start() {
+    let mut input = at("ab", 0);
+    assert_eq!(preceded_by("a", 3).parse_stream(&mut input), None);
+    assert_eq!(input.offset(), 0);
+
+    let mut items: (&'static [u8], usize) = (b"ab", 1);
+    let a: fn(&mut (&'static [u8], usize)) -> Option<u8> = |input| {
+        let item = *input.peek().filter(|item| **item == b'a')?;
+        input.skip();
+        Some(item)
+    };
+    assert_eq!(preceded_by(a, 5).parse_stream(&mut items), Some(()));
+    assert_eq!(items.offset(), 1);
+}
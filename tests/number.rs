@@ -0,0 +1,134 @@
+mod common;
+
+use common::run;
+use lavan::parser::sources::number::{float, integer, NumberError, NumberErrorKind, NumberSyntax};
+
+fn error(kind: NumberErrorKind, span: (usize, usize)) -> NumberError<(usize, usize)> {
+    NumberError { kind, span }
+}
+
+#[test]
+fn integers_fill_their_type() {
+    assert_eq!(run(integer::<i8>(), "-128;"), (Ok(-128), 4));
+    assert_eq!(run(integer::<i8>(), "127"), (Ok(127), 3));
+    assert_eq!(run(integer::<u64>(), "1_000_000"), (Ok(1_000_000), 9));
+
+    use NumberErrorKind::Overflow;
+    assert_eq!(
+        run(integer::<i8>(), "128"),
+        (Err(error(Overflow, (0, 3))), 0)
+    );
+    assert_eq!(
+        run(integer::<i8>(), "-129"),
+        (Err(error(Overflow, (1, 4))), 0)
+    );
+    assert_eq!(
+        run(integer::<u8>(), "-1"),
+        (Err(error(Overflow, (1, 2))), 0)
+    );
+}
+
+#[test]
+fn integers_read_radix_prefixes() {
+    assert_eq!(run(integer::<i32>(), "0x1F"), (Ok(31), 4));
+    assert_eq!(run(integer::<i32>(), "0o_17"), (Ok(15), 5));
+    assert_eq!(run(integer::<i32>(), "-0b101"), (Ok(-5), 6));
+
+    use NumberErrorKind::{InvalidDigit, Missing};
+    assert_eq!(
+        run(integer::<i32>(), "0b102"),
+        (Err(error(InvalidDigit, (4, 5))), 0)
+    );
+    assert_eq!(
+        run(integer::<i32>(), "0x"),
+        (Err(error(Missing, (0, 2))), 0)
+    );
+}
+
+#[test]
+fn missing_numbers_rewind() {
+    use NumberErrorKind::Missing;
+    assert_eq!(run(integer::<i32>(), "x"), (Err(error(Missing, (0, 0))), 0));
+    assert_eq!(
+        run(integer::<i32>(), "-x"),
+        (Err(error(Missing, (0, 1))), 0)
+    );
+    assert_eq!(
+        run(integer::<i32>(), "_1"),
+        (Err(error(Missing, (0, 0))), 0)
+    );
+    assert_eq!(run(float::<f64>(), "-."), (Err(error(Missing, (0, 1))), 0));
+}
+
+#[test]
+fn json_numbers() {
+    let int = || integer::<i32>().syntax(NumberSyntax::JSON);
+    let float = || float::<f64>().syntax(NumberSyntax::JSON);
+
+    use NumberErrorKind::{LeadingZero, Missing};
+    assert_eq!(run(int(), "0"), (Ok(0), 1));
+    assert_eq!(run(int(), "0123"), (Err(error(LeadingZero, (0, 4))), 0));
+    assert_eq!(run(int(), "+1"), (Err(error(Missing, (0, 0))), 0));
+    assert_eq!(run(int(), "0x1"), (Ok(0), 1));
+    assert_eq!(run(int(), "1_0"), (Ok(1), 1));
+
+    assert_eq!(run(float(), "-0.5e-2"), (Ok(-0.005), 7));
+    assert_eq!(run(float(), "01.5"), (Err(error(LeadingZero, (0, 2))), 0));
+    assert_eq!(run(float(), "1."), (Ok(1.0), 1));
+    assert_eq!(run(float(), ".5"), (Err(error(Missing, (0, 0))), 0));
+}
+
+#[test]
+fn c_numbers() {
+    let int = || integer::<i32>().syntax(NumberSyntax::C);
+    let float = || float::<f64>().syntax(NumberSyntax::C);
+
+    use NumberErrorKind::InvalidDigit;
+    assert_eq!(run(int(), "017"), (Ok(15), 3));
+    assert_eq!(run(int(), "+5"), (Ok(5), 2));
+    assert_eq!(run(int(), "0"), (Ok(0), 1));
+    assert_eq!(run(int(), "08"), (Err(error(InvalidDigit, (1, 2))), 0));
+
+    assert_eq!(run(float(), ".5"), (Ok(0.5), 2));
+    assert_eq!(run(float(), "1."), (Ok(1.0), 2));
+}
+
+#[test]
+fn floats_leave_ranges_and_methods_alone() {
+    assert_eq!(run(float::<f64>(), "1..2"), (Ok(1.0), 1));
+    assert_eq!(run(float::<f64>(), "1.e5"), (Ok(1.0), 1));
+    assert_eq!(run(float::<f64>(), "1.max(2)"), (Ok(1.0), 1));
+    assert_eq!(run(float::<f64>(), "1. "), (Ok(1.0), 2));
+    assert_eq!(run(float::<f64>(), "1_000.5"), (Ok(1000.5), 7));
+    assert_eq!(run(float::<f32>(), "2.5E3"), (Ok(2500.0), 5));
+}
+
+#[test]
+fn float_exponents_need_digits() {
+    use NumberErrorKind::{EmptyExponent, Overflow};
+    assert_eq!(
+        run(float::<f64>(), "1e"),
+        (Err(error(EmptyExponent, (1, 2))), 0)
+    );
+    assert_eq!(
+        run(float::<f64>(), "1.5e+;"),
+        (Err(error(EmptyExponent, (3, 5))), 0)
+    );
+    assert_eq!(
+        run(float::<f64>(), "1e400"),
+        (Err(error(Overflow, (0, 5))), 0)
+    );
+    assert_eq!(
+        run(float::<f32>(), "-1e39"),
+        (Err(error(Overflow, (1, 5))), 0)
+    );
+}
+
+#[test]
+fn errors_describe_their_kind() {
+    let (output, _) = run(integer::<u8>(), "256");
+    assert_eq!(
+        output.unwrap_err().to_string(),
+        "number does not fit the target type"
+    );
+}
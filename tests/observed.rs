@@ -0,0 +1,66 @@
+use lavan::parser::sources::number::integer;
+use lavan::parser::traits::Parser;
+use lavan::stream::observed::{Observed, Progress};
+use lavan::stream::traits::{Checkpoint, Stream};
+
+fn record(
+    source: &str,
+    step: usize,
+    run: impl FnOnce(&mut Observed<(&str, usize), &mut dyn FnMut(Progress)>),
+) -> Vec<Progress> {
+    let mut seen = vec![];
+    let mut callback = |progress| seen.push(progress);
+    let mut stream =
+        Observed::with_step((source, 0), step, &mut callback as &mut dyn FnMut(Progress));
+    run(&mut stream);
+    seen
+}
+
+fn offsets(seen: &[Progress]) -> Vec<usize> {
+    seen.iter().map(|progress| progress.offset).collect()
+}
+
+#[test]
+fn reports_each_threshold_once() {
+    let seen = record("abcdefgh", 2, |stream| {
+        while stream.next().is_some() {
+            stream.skip();
+        }
+    });
+    assert_eq!(offsets(&seen), [2, 4, 6, 8]);
+    assert!(seen.iter().all(|progress| progress.total == Some(8)));
+}
+
+#[test]
+fn backtracking_does_not_repeat_reports() {
+    let seen = record("abcdefgh", 4, |stream| {
+        let checkpoint = stream.checkpoint();
+        stream.advance(5);
+        stream.rewind(checkpoint);
+        stream.advance(6);
+    });
+    assert_eq!(offsets(&seen), [5]);
+}
+
+#[test]
+fn forward_rewind_is_reported() {
+    let seen = record("abcdefgh", 4, |stream| {
+        stream.advance(1);
+        stream.rewind(Checkpoint {
+            offset: 6,
+            state: 0,
+        });
+    });
+    assert_eq!(offsets(&seen), [6]);
+}
+
+#[test]
+fn failed_number_rewinds_without_new_reports() {
+    let seen = record("999", 1, |stream| {
+        let number = integer::<u8>().parse_stream(stream);
+        assert!(number.is_err());
+        assert_eq!(stream.offset(), 0);
+        stream.advance(2);
+    });
+    assert_eq!(offsets(&seen), [1, 2, 3]);
+}
@@ -0,0 +1,77 @@
+mod common;
+
+use common::{run, S};
+use lavan::data::adapters::sure::Sure;
+use lavan::parser::adapters::permutation::{permutation, PermutationError, PermutationErrorKind};
+use lavan::stream::traits::Stream;
+
+fn expect(expected: char) -> impl Fn(&mut S) -> Option<char> {
+    move |input| {
+        let ch = input.peek().filter(|ch| *ch == expected)?;
+        input.skip();
+        Some(ch)
+    }
+}
+
+const X: fn(&mut S) -> Option<char> = |input| expect('x')(input);
+const Z: fn(&mut S) -> Option<char> = |input| expect('z')(input);
+// An optional `y`, which succeeds without consuming anything when it's absent.
+const Y: fn(&mut S) -> Sure<Option<char>> = |input| Sure(expect('y')(input));
+
+fn error(
+    kind: PermutationErrorKind,
+    field: usize,
+    span: (usize, usize),
+) -> PermutationError<(usize, usize)> {
+    PermutationError { kind, field, span }
+}
+
+#[test]
+fn fields_match_in_any_order() {
+    assert_eq!(
+        run(permutation((X, Y, Z)), "zxq"),
+        (Ok(('x', None, 'z')), 2)
+    );
+    assert_eq!(
+        run(permutation((X, Y, Z)), "yzx"),
+        (Ok(('x', Some('y'), 'z')), 3)
+    );
+    assert_eq!(run(permutation((X, Z)), "xz"), (Ok(('x', 'z')), 2));
+}
+
+#[test]
+fn non_consuming_successes_are_fallbacks() {
+    // `Y` first succeeds at `z` without consuming, then matches the `y`.
+    assert_eq!(
+        run(permutation((X, Y, Z)), "zyx"),
+        (Ok(('x', Some('y'), 'z')), 3)
+    );
+    assert_eq!(run(permutation((Y, X)), "x"), (Ok((None, 'x')), 1));
+}
+
+#[test]
+fn repeated_fields_are_duplicates() {
+    let (output, offset) = run(permutation((X, Y, Z)), "xzx");
+    assert_eq!(
+        output,
+        Err(error(PermutationErrorKind::Duplicate, 0, (2, 3)))
+    );
+    assert_eq!(offset, 2);
+
+    let (output, _) = run(permutation((X, Y, Z)), "yxzy");
+    assert_eq!(
+        output,
+        Err(error(PermutationErrorKind::Duplicate, 1, (3, 4)))
+    );
+}
+
+#[test]
+fn fields_that_never_match_are_missing() {
+    let (output, offset) = run(permutation((X, Y, Z)), "z;");
+    assert_eq!(output, Err(error(PermutationErrorKind::Missing, 0, (1, 1))));
+    assert_eq!(offset, 1);
+
+    let (output, _) = run(permutation((X, Z)), "x");
+    assert_eq!(output, Err(error(PermutationErrorKind::Missing, 1, (1, 1))));
+    assert_eq!(output.unwrap_err().to_string(), "missing field 1");
+}
@@ -0,0 +1,161 @@
+use lavan::parser::sources::regex::{regex, RegexError, RegexErrorKind};
+use lavan::parser::traits::Parser;
+
+// Matches `pattern` at `offset` into `source`, returning the matched text and
+// the offset after it.
+fn matched(pattern: &str, source: &'static str, offset: usize) -> Option<(&'static str, usize)> {
+    let mut input = (source, offset);
+    let captures = regex(pattern).unwrap().parse_stream(&mut input);
+    captures.map(|captures| (captures.as_str(), input.1))
+}
+
+fn error(pattern: &str) -> RegexError {
+    regex(pattern).unwrap_err()
+}
+
+#[test]
+fn hex_escapes_reject_signs() {
+    for pattern in [r"\x+1", r"\x{+41}"] {
+        let error = regex(pattern).unwrap_err();
+        assert_eq!(error.kind, RegexErrorKind::InvalidEscape, "{pattern}");
+    }
+    assert!(regex(r"\x41").is_ok());
+}
+
+#[test]
+fn reused_regex_starts_each_search_afresh() {
+    let pattern = regex(r"(a)?(b)").unwrap();
+
+    let mut input = ("abb", 0);
+    let first = pattern.parse_stream(&mut input).unwrap();
+    assert_eq!((first.get(1), first.get(2)), (Some("a"), Some("b")));
+
+    // Group 1 did not take part in this match and must not keep its slots
+    // from the previous search.
+    let second = pattern.parse_stream(&mut input).unwrap();
+    assert_eq!((second.get(1), second.get(2)), (None, Some("b")));
+    assert_eq!(input.1, 3);
+    assert!(pattern.parse_stream(&mut input).is_none());
+
+    let copy = pattern.clone();
+    let third = copy.parse_stream(&mut ("ab", 0)).unwrap();
+    assert_eq!(third.as_str(), "ab");
+}
+
+#[test]
+fn alternation_prefers_earlier_branches() {
+    assert_eq!(matched("a|ab", "abc", 0), Some(("a", 1)));
+    assert_eq!(matched("ab|a", "abc", 0), Some(("ab", 2)));
+    assert_eq!(matched("(?:a|ab)c", "abc", 0), Some(("abc", 3)));
+}
+
+#[test]
+fn lazy_quantifiers_match_as_little_as_they_can() {
+    assert_eq!(matched("a+", "aaa", 0), Some(("aaa", 3)));
+    assert_eq!(matched("a+?", "aaa", 0), Some(("a", 1)));
+    assert_eq!(matched("a{2,}?", "aaa", 0), Some(("aa", 2)));
+    assert_eq!(matched("a??", "aaa", 0), Some(("", 0)));
+    assert_eq!(matched("a*?b", "aab", 0), Some(("aab", 3)));
+    assert_eq!(matched("<.*>", "<a><b>", 0), Some(("<a><b>", 6)));
+    assert_eq!(matched("<.*?>", "<a><b>", 0), Some(("<a>", 3)));
+}
+
+#[test]
+fn word_boundaries_see_text_before_the_offset() {
+    assert_eq!(matched(r"\bb", "a b", 2), Some(("b", 3)));
+    assert_eq!(matched(r"\bb", "ab", 1), None);
+    assert_eq!(matched(r"\Bb", "ab", 1), Some(("b", 2)));
+    assert_eq!(matched(r"\w+\b", "foo bar", 0), Some(("foo", 3)));
+    assert_eq!(matched(r"fo\b", "foo", 0), None);
+}
+
+#[test]
+fn classes_and_negation() {
+    assert_eq!(matched("[a-c_]+", "ab_cd", 0), Some(("ab_c", 4)));
+    assert_eq!(matched(r"[^a-c\d]", "d", 0), Some(("d", 1)));
+    assert_eq!(matched(r"[^a-c\d]", "b", 0), None);
+    assert_eq!(matched(r"[^a-c\d]", "5", 0), None);
+    assert_eq!(matched("[]a]+", "a]b", 0), Some(("a]", 2)));
+    assert_eq!(matched("[a-]+", "-a-b", 0), Some(("-a-", 3)));
+    assert_eq!(matched(r"\D\W\S", "x. ", 0), None);
+    assert_eq!(matched(r"\D\W\S", "x.y", 0), Some(("x.y", 3)));
+    assert_eq!(matched(".", "\n", 0), None);
+}
+
+#[test]
+fn named_groups() {
+    let pattern = regex(r"(?<key>\w+)=(?P<value>\d+)?").unwrap();
+    assert_eq!(pattern.captures_len(), 3);
+    assert_eq!(pattern.group_index("value"), Some(2));
+    assert_eq!(pattern.group_index("other"), None);
+
+    let captures = pattern.parse_stream(&mut ("size=12;", 0)).unwrap();
+    assert_eq!(captures.name("key"), Some("size"));
+    assert_eq!(captures.name("value"), Some("12"));
+    assert_eq!(captures.name("other"), None);
+
+    let captures = pattern.parse_stream(&mut ("size=;", 0)).unwrap();
+    assert_eq!(
+        captures.iter().collect::<Vec<_>>(),
+        [Some("size="), Some("size"), None]
+    );
+}
+
+#[test]
+fn matches_are_anchored_at_the_current_offset() {
+    let mut input = ("abab", 0);
+    assert!(regex("b").unwrap().parse_stream(&mut input).is_none());
+    assert_eq!(input.1, 0);
+
+    assert_eq!(matched("b", "abab", 1), Some(("b", 2)));
+    // `^` and `$` refer to the whole source, not the offset.
+    assert_eq!(matched("^b", "abab", 1), None);
+    assert_eq!(matched(r"\Aa", "abab", 0), Some(("a", 1)));
+    assert_eq!(matched("ab$", "abab", 2), Some(("ab", 4)));
+    assert_eq!(matched(r"ab\z", "abab", 0), None);
+}
+
+#[test]
+fn syntax_errors() {
+    let cases = [
+        (r"a\", RegexErrorKind::UnexpectedEnd, 1..2),
+        ("(a", RegexErrorKind::UnclosedGroup, 0..1),
+        ("a)", RegexErrorKind::UnopenedGroup, 1..2),
+        ("x[ab", RegexErrorKind::UnclosedClass, 1..2),
+        ("[z-a]", RegexErrorKind::InvalidClassRange, 1..4),
+        (r"[\d-z]", RegexErrorKind::InvalidClassRange, 1..5),
+        (r"a\q", RegexErrorKind::InvalidEscape, 1..3),
+        ("(?x)", RegexErrorKind::InvalidGroup, 0..2),
+        ("(?<1a>x)", RegexErrorKind::InvalidGroup, 0..5),
+        ("(?<a>x)(?<a>y)", RegexErrorKind::DuplicateGroupName, 10..11),
+        ("*a", RegexErrorKind::NothingToRepeat, 0..1),
+        ("a**", RegexErrorKind::NothingToRepeat, 2..3),
+        ("a{3,2}", RegexErrorKind::InvalidRepetition, 1..6),
+        ("a{,2}", RegexErrorKind::InvalidRepetition, 1..5),
+        ("a{2", RegexErrorKind::InvalidRepetition, 1..3),
+    ];
+    for (pattern, kind, span) in cases {
+        assert_eq!(error(pattern), RegexError { kind, span }, "{pattern}");
+    }
+    assert_eq!(error("(a").to_string(), "unclosed group");
+}
+
+#[test]
+fn oversized_patterns_are_rejected() {
+    assert!(regex("a{1000}").is_ok());
+    let too_many = error("a{1001}");
+    assert_eq!(
+        (too_many.kind, too_many.span),
+        (RegexErrorKind::TooLarge, 1..7)
+    );
+    assert_eq!(error("a{2,99999999999}").kind, RegexErrorKind::TooLarge);
+
+    // Each repetition is within the limit, but the compiled program isn't.
+    let pattern = "(?:a{1000}){100}";
+    let too_long = error(pattern);
+    assert_eq!(too_long.to_string(), "compiled pattern is too large");
+    assert_eq!(
+        (too_long.kind, too_long.span),
+        (RegexErrorKind::TooLarge, 0..pattern.len())
+    );
+}
@@ -0,0 +1,374 @@
+mod common;
+
+use common::{run, S};
+use lavan::data::adapters::sure::Sure;
+use lavan::parser::adapters::repeat::mode::Trailing;
+use lavan::parser::adapters::repeat::{
+    ArrayBuf, CapacityError, Count, Last, OneOrMul, RepeatError,
+};
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+use std::collections::{BTreeMap, HashMap};
+
+type Item = fn(&mut S) -> Option<char>;
+
+const A: Item = |input| {
+    let ch = input.peek().filter(|ch| *ch == 'a')?;
+    input.skip();
+    Some(ch)
+};
+
+#[test]
+fn ranges_take_the_items_they_allow() {
+    let (items, offset) = run(A.repeat_range(1..3).to_vec(), "aaaa");
+    assert_eq!(items.map(|items| items.len()), Some(2));
+    assert_eq!(offset, 2);
+
+    let (items, _) = run(A.repeat_range(2..).to_vec(), "a");
+    assert_eq!(items, None);
+}
+
+#[test]
+#[allow(clippy::reversed_empty_ranges)]
+fn empty_ranges_are_clamped_to_their_start() {
+    let (items, offset) = run(A.repeat_range(0..0).to_vec(), "aaa");
+    assert_eq!(items, Some(vec![]));
+    assert_eq!(offset, 0);
+
+    let (items, offset) = run(A.repeat_range(5..2).to_vec(), "aaaaaa");
+    assert_eq!(items.map(|items| items.len()), Some(5));
+    assert_eq!(offset, 5);
+
+    let (items, _) = run(A.repeat_range(5..2).to_vec(), "aaa");
+    assert_eq!(items, None);
+}
+
+// Matches without consuming anything, so unbounded repetitions of it stall.
+const EMPTY: Item = |_| Some('-');
+
+const COMMA: Item = |input| {
+    let ch = input.peek().filter(|ch| *ch == ',')?;
+    input.skip();
+    Some(ch)
+};
+
+#[test]
+fn bounded_repetitions_allow_empty_items() {
+    let (items, offset) = run(EMPTY.repeat_exact(3).to_vec(), "abc");
+    assert_eq!(items, Some(vec!['-'; 3]));
+    assert_eq!(offset, 0);
+
+    let (items, _) = run(EMPTY.repeat_max(2).to_vec(), "abc");
+    assert_eq!(items.value(), vec!['-'; 2]);
+
+    let (items, _) = run(EMPTY.repeat_range(1..=2).to_vec(), "abc");
+    assert_eq!(items, Some(vec!['-'; 2]));
+}
+
+#[test]
+fn repeat_stops_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat().to_vec(), "abc");
+    assert_eq!(items.value(), vec!['-']);
+    assert_eq!(offset, 0);
+
+    // An optional item, which matches empty once the `a`s run out.
+    let a_or_empty: Item = |input| A(input).or(Some('-'));
+    let (items, offset) = run(a_or_empty.repeat().to_vec(), "aab");
+    assert_eq!(items.value(), vec!['a', 'a', '-']);
+    assert_eq!(offset, 2);
+}
+
+#[test]
+fn repeat_min_stops_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat_min(2).to_vec(), "abc");
+    assert_eq!(items, Some(vec!['-'; 3]));
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn open_range_stops_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat_range(1..).to_vec(), "abc");
+    assert_eq!(items, Some(vec!['-'; 2]));
+    assert_eq!(offset, 0);
+}
+
+fn stalled_at<T, SepErr>(
+    output: Result<T, RepeatError<(), (usize, usize), SepErr>>,
+) -> Option<(usize, usize)> {
+    match output {
+        Err(RepeatError::Stalled { span, .. }) => Some(span),
+        _ => None,
+    }
+}
+
+#[test]
+fn repeat_eoi_fails_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat_eoi().to_vec(), "abc");
+    assert_eq!(stalled_at(items), Some((0, 0)));
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn repeat_min_eoi_fails_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat_min_eoi(1).to_vec(), "abc");
+    assert_eq!(stalled_at(items), Some((0, 0)));
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn repeat_until_fails_on_a_stall() {
+    let (items, offset) = run(EMPTY.repeat_until(COMMA).to_vec(), "abc,");
+    assert_eq!(stalled_at(items), Some((0, 0)));
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn terminated_repeat_fails_on_a_stall() {
+    let parser = EMPTY.repeat_until(COMMA).consume_terminator().to_vec();
+    let (items, offset) = run(parser, "abc,");
+    assert_eq!(stalled_at(items), Some((0, 0)));
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn stalls_name_the_item_parser() {
+    let (items, _) = run(EMPTY.repeat_eoi().to_vec(), "abc");
+    let message = items.unwrap_err().to_string();
+    assert!(message.contains("Option<char>"));
+    assert!(message.ends_with("` was repeated without consuming any input"));
+}
+
+#[test]
+fn until_and_eoi_repetitions_succeed_without_stalls() {
+    let (items, offset) = run(A.repeat_until(COMMA).to_vec(), "aa,");
+    assert_eq!(items, Ok(vec!['a'; 2]));
+    assert_eq!(offset, 2);
+
+    let parser = A.repeat_until(COMMA).consume_terminator().to_vec();
+    assert_eq!(run(parser, "aa,"), (Ok((vec!['a'; 2], ',')), 3));
+
+    let (items, _) = run(A.repeat_eoi().to_vec(), "ab");
+    assert_eq!(items, Err(RepeatError::Item(())));
+}
+
+#[test]
+fn interspersed_repeat_stops_on_a_stall() {
+    let parser = EMPTY.repeat().interspersed(EMPTY).to_vec();
+    let (items, offset) = run(parser, "abc");
+    assert_eq!(items.value(), vec!['-']);
+    assert_eq!(offset, 0);
+}
+
+#[test]
+fn interspersed_repeat_eoi_fails_on_a_stall() {
+    let parser = EMPTY.repeat_eoi().interspersed(EMPTY).to_vec();
+    let (items, offset) = run(parser, "abc");
+    assert_eq!(stalled_at(items), Some((0, 0)));
+    assert_eq!(offset, 0);
+}
+
+const ANY: Item = |input| {
+    let ch = input.peek()?;
+    input.skip();
+    Some(ch)
+};
+
+type Buf = Result<ArrayBuf<char, 2>, CapacityError>;
+
+#[test]
+fn full_buffers_stop_optional_items() {
+    let (items, offset) = run(ANY.repeat().collector(Buf::Ok(ArrayBuf::new())), "abcd");
+    let items = items.value().unwrap();
+    assert_eq!(items.iter().collect::<Vec<_>>(), [&'a', &'b']);
+    assert_eq!(items.into_array().ok(), Some(['a', 'b']));
+    assert_eq!(offset, 2);
+
+    let parser = ANY.repeat_max(3).collector(Buf::Ok(ArrayBuf::new()));
+    assert_eq!(run(parser, "abcd").1, 2);
+
+    let parser = ANY.repeat_min(1).collector(Buf::Ok(ArrayBuf::new()));
+    assert_eq!(run(parser, "abcd").1, 2);
+
+    let parser = A
+        .repeat()
+        .interspersed(COMMA)
+        .collector(Buf::Ok(ArrayBuf::new()));
+    let (items, offset) = run(parser, "a,a,a");
+    assert_eq!(items.value().map(|items| items.len()), Ok(2));
+    assert_eq!(offset, 3);
+}
+
+#[test]
+fn full_buffers_overflow_on_required_items() {
+    let parser = ANY.repeat_exact(3).collector(Buf::Ok(ArrayBuf::new()));
+    let (items, offset) = run(parser, "abcd");
+    assert_eq!(
+        items.map(|items| items.err()),
+        Some(Some(CapacityError { capacity: 2 }))
+    );
+    assert_eq!(offset, 3);
+
+    let parser = ANY.repeat_eoi().collector(Buf::Ok(ArrayBuf::new()));
+    let (items, offset) = run(parser, "abc");
+    assert_eq!(
+        items.map(|items| items.err()),
+        Ok(Some(CapacityError { capacity: 2 }))
+    );
+    assert_eq!(offset, 3);
+}
+
+#[test]
+fn counts_and_last_values() {
+    let (count, offset) = run(A.repeat().collect::<Count>(), "aab");
+    assert_eq!(count.value(), Count(2));
+    assert_eq!(offset, 2);
+
+    let (last, _) = run(ANY.repeat().collect::<Last<char>>(), "abc");
+    assert_eq!(last.value(), Last(Some('c')));
+
+    let (last, _) = run(A.repeat().collect::<Last<char>>(), "b");
+    assert_eq!(last.value(), Last(None));
+}
+
+const ENTRY: fn(&mut S) -> Option<(char, char)> = |input| {
+    let key = input.peek().filter(char::is_ascii_lowercase)?;
+    input.skip();
+    let value = input.peek().filter(char::is_ascii_digit)?;
+    input.skip();
+    Some((key, value))
+};
+
+#[test]
+fn maps_keep_the_last_value_for_a_key() {
+    let (map, offset) = run(ENTRY.repeat().collect::<HashMap<_, _>>(), "a1b2a3;");
+    assert_eq!(map.value(), HashMap::from([('a', '3'), ('b', '2')]));
+    assert_eq!(offset, 6);
+
+    let (map, _) = run(ENTRY.repeat().collect::<BTreeMap<_, _>>(), "b2a1b4");
+    assert_eq!(
+        map.value().into_iter().collect::<Vec<_>>(),
+        [('a', '1'), ('b', '4')]
+    );
+}
+
+#[test]
+fn strings_collect_chars_and_slices() {
+    let (string, _) = run(ANY.repeat_exact(3).collect::<String>(), "héllo");
+    assert_eq!(string.as_deref(), Some("hél"));
+
+    let (string, offset) = run(ParserOnce::<S>::repeat("ab").collect::<String>(), "ababa");
+    assert_eq!(string.value(), "abab");
+    assert_eq!(offset, 4);
+}
+
+#[test]
+fn one_or_mul_separates_single_values() {
+    let (values, _) = run(A.repeat().collect::<OneOrMul<char>>(), "b");
+    assert!(matches!(values.value(), OneOrMul::Empty));
+
+    let (values, _) = run(A.repeat().collect::<OneOrMul<char>>(), "ab");
+    assert!(matches!(values.value(), OneOrMul::One('a')));
+
+    let (values, _) = run(A.repeat().collect::<OneOrMul<char>>(), "aaab");
+    assert!(matches!(values.value(), OneOrMul::Multiple(values) if values == ['a'; 3]));
+}
+
+// A separator whose error, the offset it failed at, differs from an item's.
+const SEP: fn(&mut S) -> Result<char, usize> = |input| match input.peek() {
+    Some(',') => {
+        input.skip();
+        Ok(',')
+    }
+    _ => Err(input.offset()),
+};
+
+type Outcome = (
+    Result<Vec<char>, RepeatError<(), (usize, usize), usize>>,
+    usize,
+);
+
+fn interspersed(mode: &str, trailing: Trailing, source: &'static str) -> Outcome {
+    fn sure((items, offset): (Sure<Vec<char>>, usize)) -> Outcome {
+        (Ok(items.value()), offset)
+    }
+
+    match mode {
+        "until_err" => sure(run(
+            A.repeat().interspersed(SEP).trailing(trailing).to_vec(),
+            source,
+        )),
+        "until_eoi" => run(
+            A.repeat_eoi().interspersed(SEP).trailing(trailing).to_vec(),
+            source,
+        ),
+        "minimum" => run(
+            A.repeat_min(2)
+                .interspersed(SEP)
+                .trailing(trailing)
+                .to_vec(),
+            source,
+        ),
+        "minimum_eoi" => run(
+            A.repeat_min_eoi(2)
+                .interspersed(SEP)
+                .trailing(trailing)
+                .to_vec(),
+            source,
+        ),
+        "maximum" => sure(run(
+            A.repeat_max(2)
+                .interspersed(SEP)
+                .trailing(trailing)
+                .to_vec(),
+            source,
+        )),
+        "exact" => run(
+            A.repeat_exact(2)
+                .interspersed(SEP)
+                .trailing(trailing)
+                .to_vec(),
+            source,
+        ),
+        _ => panic!("unknown mode {mode}"),
+    }
+}
+
+#[test]
+fn trailing_separators_in_every_mode() {
+    use RepeatError::{Item, Separator};
+    use Trailing::{Allowed, Forbidden, Required};
+
+    let table: &[(&str, Trailing, &str, Outcome)] = &[
+        ("until_err", Forbidden, "a,a,;", (Ok(vec!['a'; 2]), 3)),
+        ("until_err", Allowed, "a,a,;", (Ok(vec!['a'; 2]), 4)),
+        ("until_err", Required, "a,a,;", (Ok(vec!['a'; 2]), 4)),
+        ("until_err", Required, "a,a;", (Ok(vec!['a']), 2)),
+        ("until_eoi", Forbidden, "a,a", (Ok(vec!['a'; 2]), 3)),
+        ("until_eoi", Forbidden, "a,a,", (Err(Item(())), 4)),
+        ("until_eoi", Allowed, "a,a,", (Ok(vec!['a'; 2]), 4)),
+        ("until_eoi", Allowed, "a,a", (Ok(vec!['a'; 2]), 3)),
+        ("until_eoi", Required, "a,a", (Err(Separator(3)), 3)),
+        ("until_eoi", Required, "a;a", (Err(Separator(1)), 1)),
+        ("minimum", Forbidden, "a,a,a,;", (Ok(vec!['a'; 3]), 5)),
+        ("minimum", Allowed, "a,a,a,;", (Ok(vec!['a'; 3]), 6)),
+        ("minimum", Required, "a,a,a;", (Ok(vec!['a'; 2]), 4)),
+        ("minimum", Forbidden, "a;a", (Err(Separator(1)), 1)),
+        ("minimum_eoi", Forbidden, "a,a,a", (Ok(vec!['a'; 3]), 5)),
+        ("minimum_eoi", Allowed, "a,a,", (Ok(vec!['a'; 2]), 4)),
+        ("minimum_eoi", Required, "a,a,", (Ok(vec!['a'; 2]), 4)),
+        ("minimum_eoi", Forbidden, "a,", (Err(Item(())), 2)),
+        ("maximum", Forbidden, "a,a,a", (Ok(vec!['a'; 2]), 3)),
+        ("maximum", Allowed, "a,a,a", (Ok(vec!['a'; 2]), 4)),
+        ("maximum", Required, "a,a,a", (Ok(vec!['a'; 2]), 4)),
+        ("maximum", Required, "a,a", (Ok(vec!['a']), 2)),
+        ("exact", Forbidden, "a,a,", (Ok(vec!['a'; 2]), 3)),
+        ("exact", Allowed, "a,a,", (Ok(vec!['a'; 2]), 4)),
+        ("exact", Required, "a,a,", (Ok(vec!['a'; 2]), 4)),
+        ("exact", Required, "a,a", (Err(Separator(3)), 3)),
+        ("exact", Forbidden, "a,;", (Err(Item(())), 2)),
+    ];
+    for (mode, trailing, source, expected) in table {
+        let outcome = interspersed(mode, *trailing, source);
+        assert_eq!(&outcome, expected, "{mode} {trailing:?} {source:?}");
+    }
+}
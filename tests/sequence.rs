@@ -0,0 +1,122 @@
+mod common;
+
+use common::{run, S};
+use lavan::data::adapters::sure::Sure;
+use lavan::parser::adapters::sequence::DelimitedError;
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+const SPACES: fn(&mut S) = |input| {
+    while input.peek() == Some(' ') {
+        input.skip();
+    }
+};
+
+const ANY: fn(&mut S) -> Sure<char> = |input| {
+    let ch = input.peek().unwrap_or('?');
+    input.skip();
+    Sure(ch)
+};
+
+const DIGIT: fn(&mut S) -> Option<char> = |input| {
+    let ch = input.peek().filter(char::is_ascii_digit)?;
+    input.skip();
+    Some(ch)
+};
+
+fn expect(expected: char) -> impl Fn(&mut S) -> Result<char, char> {
+    move |input| match input.peek() {
+        Some(ch) if ch == expected => {
+            input.skip();
+            Ok(ch)
+        }
+        found => Err(found.unwrap_or('$')),
+    }
+}
+
+const OPEN: fn(&mut S) -> Result<char, char> = |input| expect('(')(input);
+const CLOSE: fn(&mut S) -> Result<char, char> = |input| expect(')')(input);
+const WORD: fn(&mut S) -> Result<char, char> = |input| expect('w')(input);
+
+#[test]
+fn unit_responses_can_be_ignored() {
+    let (output, offset) = run(DIGIT.then_ignore(SPACES), "1  2");
+    assert_eq!(output, Some('1'));
+    assert_eq!(offset, 3);
+
+    let (output, offset) = run(SPACES.ignore_then(DIGIT), "  2");
+    assert_eq!(output, Some('2'));
+    assert_eq!(offset, 3);
+
+    let (output, offset) = run(DIGIT.padded_by(SPACES), " 7 ;");
+    assert_eq!(output, Some('7'));
+    assert_eq!(offset, 3);
+}
+
+#[test]
+fn sure_sequences_stay_sure() {
+    let (output, offset): (Sure<char>, _) = run(ANY.then_ignore(ANY), "ab");
+    assert_eq!(output.value(), 'a');
+    assert_eq!(offset, 2);
+
+    let (output, _): (Sure<char>, _) = run(SPACES.ignore_then(ANY), "  b");
+    assert_eq!(output.value(), 'b');
+
+    let (output, offset): (Sure<char>, _) = run(ANY.delimited_by(ANY, ANY), "[x]");
+    assert_eq!(output.value(), 'x');
+    assert_eq!(offset, 3);
+}
+
+#[test]
+fn failures_keep_their_response_type() {
+    assert_eq!(run(DIGIT.then_ignore(DIGIT), "1a").0, None);
+    assert_eq!(run(DIGIT.ignore_then(ANY), "a1").0, None);
+    assert_eq!(run(OPEN.ignore_then(WORD), "(w").0, Ok('w'));
+    assert_eq!(run(OPEN.ignore_then(WORD), "(x").0, Err('x'));
+    assert_eq!(run(ANY.ignore_then(WORD), "?w").0, Ok('w'));
+}
+
+#[test]
+fn delimited_reports_unclosed_delimiters() {
+    let (output, offset) = run(WORD.delimited_by(OPEN, CLOSE), "(w)");
+    assert_eq!(output, Ok('w'));
+    assert_eq!(offset, 3);
+
+    let (output, offset) = run(WORD.delimited_by(OPEN, CLOSE), "(w;");
+    assert_eq!(
+        output,
+        Err(DelimitedError::Unclosed {
+            open: (0, 1),
+            error: ';'
+        })
+    );
+    assert_eq!(offset, 2);
+
+    let (output, _) = run(WORD.delimited_by(OPEN, CLOSE), "w)");
+    assert_eq!(output, Err(DelimitedError::Open('w')));
+
+    let (output, offset) = run(WORD.delimited_by(OPEN, CLOSE), "(x)");
+    assert_eq!(output, Err(DelimitedError::Body('x')));
+    assert_eq!(offset, 1);
+}
+
+#[test]
+fn delimited_options_stay_options() {
+    let (output, offset) = run(DIGIT.delimited_by(DIGIT, DIGIT), "123");
+    assert_eq!(output, Some('2'));
+    assert_eq!(offset, 3);
+
+    let (output, offset) = run(DIGIT.delimited_by(DIGIT, DIGIT), "12a");
+    assert_eq!(output, None);
+    assert_eq!(offset, 2);
+}
+
+#[test]
+fn failed_sequences_leave_rewinding_to_non_terminal() {
+    let (_, delimited) = run(DIGIT.delimited_by(DIGIT, DIGIT), "12a");
+    let (_, chained) = run(DIGIT.ignore_then(DIGIT).then_ignore(DIGIT), "12a");
+    assert_eq!(delimited, chained);
+
+    let parser = DIGIT.delimited_by(DIGIT, DIGIT).non_terminal();
+    assert_eq!(run(parser, "12a"), (None, 0));
+}
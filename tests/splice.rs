@@ -0,0 +1,67 @@
+use lavan::stream::source_map::{MemoryFiles, NotFound, SourceMap};
+use lavan::stream::splice::Spliced;
+use lavan::stream::traits::Stream;
+
+fn spliced(root: &str) -> Spliced {
+    let mut map = SourceMap::new();
+    let root = map.add("main.cfg", root);
+    Spliced::new(map, root)
+}
+
+fn location(stream: &Spliced, offset: usize) -> String {
+    stream.location(offset).to_string()
+}
+
+#[test]
+fn included_text_reports_its_own_file_and_line() {
+    let mut files: MemoryFiles = [("inc.cfg", "x\ny\n")].into_iter().collect();
+    let mut stream = spliced("one\n@\ntwo\n");
+
+    stream.advance(5);
+    stream.include(&mut files, "inc.cfg").unwrap();
+
+    let mut rest = stream.clone();
+    let text: String = std::iter::from_fn(|| {
+        let ch = rest.next()?;
+        rest.skip();
+        Some(ch)
+    })
+    .collect();
+    assert_eq!(text, "x\ny\n\ntwo\n");
+
+    assert_eq!(location(&stream, 0), "main.cfg:1:1");
+    assert_eq!(location(&stream, 5), "inc.cfg:1:1");
+    assert_eq!(location(&stream, 7), "inc.cfg:2:1");
+    assert_eq!(location(&stream, 9), "main.cfg:2:2");
+    assert_eq!(location(&stream, 10), "main.cfg:3:1");
+}
+
+#[test]
+fn spans_across_an_include_keep_the_start_file() {
+    let mut files: MemoryFiles = [("inc.cfg", "abc")].into_iter().collect();
+    let mut stream = spliced("12");
+    stream.advance(1);
+    stream.include(&mut files, "inc.cfg").unwrap();
+
+    let span = stream.span(1, 4);
+    assert_eq!(stream.source_map().name(span.file), "inc.cfg");
+    assert_eq!(stream.source_map().snippet(&span), "abc");
+    assert_eq!(stream.slice(0, 5), "1abc2");
+}
+
+#[test]
+fn missing_files_are_reported_and_loaded_files_reused() {
+    let mut files = MemoryFiles::new();
+    files.insert("inc.cfg", "x");
+    let mut stream = spliced("");
+
+    assert_eq!(
+        stream.include(&mut files, "nope.cfg"),
+        Err(NotFound("nope.cfg".to_owned()))
+    );
+    let first = stream.include(&mut files, "inc.cfg").unwrap();
+    let second = stream.include(&mut files, "inc.cfg").unwrap();
+    assert_eq!(first, second);
+    assert_eq!(stream.source_map().len(), 2);
+    assert_eq!(stream.len(), 2);
+}
@@ -0,0 +1,144 @@
+mod common;
+
+use std::borrow::Cow;
+
+use common::run;
+use lavan::parser::sources::string::{
+    string_literal, Escapes, StringError, StringErrorKind, StringSyntax,
+};
+
+fn error(kind: StringErrorKind, span: (usize, usize)) -> StringError<(usize, usize)> {
+    StringError { kind, span }
+}
+
+type Output = (
+    Result<Cow<'static, str>, StringError<(usize, usize)>>,
+    usize,
+);
+
+fn rust(source: &'static str) -> Output {
+    run(string_literal(StringSyntax::RUST), source)
+}
+
+fn json(source: &'static str) -> Output {
+    run(string_literal(StringSyntax::JSON), source)
+}
+
+fn c(source: &'static str) -> Output {
+    run(string_literal(StringSyntax::C), source)
+}
+
+#[test]
+fn plain_strings_are_borrowed() {
+    let (value, offset) = rust(r#""abc" rest"#);
+    assert!(matches!(value, Ok(Cow::Borrowed("abc"))));
+    assert_eq!(offset, 5);
+
+    let (value, _) = rust(r#""a\tb""#);
+    assert!(matches!(value, Ok(Cow::Owned(owned)) if owned == "a\tb"));
+}
+
+#[test]
+fn rust_escapes() {
+    assert_eq!(rust(r#""\x41\0\'""#).0.as_deref(), Ok("A\0'"));
+    assert_eq!(rust(r#""\u{1F600}""#).0.as_deref(), Ok("😀"));
+    assert_eq!(rust("\"a\\\n    b\"").0.as_deref(), Ok("ab"));
+    assert_eq!(rust("\"a\nb\"").0.as_deref(), Ok("a\nb"));
+
+    use StringErrorKind::{InvalidCodePoint, InvalidEscape};
+    assert_eq!(rust(r#""\x80""#), (Err(error(InvalidCodePoint, (1, 5))), 0));
+    assert_eq!(
+        rust(r#""\u{D800}""#),
+        (Err(error(InvalidCodePoint, (1, 9))), 0)
+    );
+    assert_eq!(rust(r#""\u{}""#), (Err(error(InvalidEscape, (1, 4))), 0));
+    assert_eq!(rust(r#""\u41""#), (Err(error(InvalidEscape, (1, 3))), 0));
+}
+
+#[test]
+fn invalid_escapes_span_whole_characters() {
+    let (value, offset) = rust(r#""a\éb""#);
+    assert_eq!(value, Err(error(StringErrorKind::InvalidEscape, (2, 5))));
+    assert_eq!(offset, 0);
+
+    let (value, _) = rust(r#""\"#);
+    assert_eq!(value, Err(error(StringErrorKind::InvalidEscape, (1, 2))));
+}
+
+#[test]
+fn raw_strings() {
+    let (value, offset) = rust(r#"r"a\n" rest"#);
+    assert!(matches!(value, Ok(Cow::Borrowed(r"a\n"))));
+    assert_eq!(offset, 6);
+
+    let (value, offset) = rust(r##"r#"a"b"#"##);
+    assert!(matches!(value, Ok(Cow::Borrowed(r#"a"b"#))));
+    assert_eq!(offset, 8);
+
+    assert_eq!(
+        rust(r##"r#"ab""##),
+        (Err(error(StringErrorKind::Unterminated, (0, 3))), 0)
+    );
+
+    // Only Rust has raw strings.
+    assert_eq!(
+        json(r#"r"a""#),
+        (Err(error(StringErrorKind::Missing, (0, 0))), 0)
+    );
+}
+
+#[test]
+fn json_escapes() {
+    assert_eq!(json(r#""\/\b\f""#).0.as_deref(), Ok("/\u{8}\u{c}"));
+    assert_eq!(json(r#""é""#).0.as_deref(), Ok("é"));
+    assert_eq!(json(r#""😀!""#).0.as_deref(), Ok("😀!"));
+
+    use StringErrorKind::{InvalidCodePoint, InvalidEscape};
+    assert_eq!(
+        json(r#""\ud83d""#),
+        (Err(error(InvalidCodePoint, (1, 7))), 0)
+    );
+    assert_eq!(
+        json(r#""\ud83dA""#),
+        (Err(error(InvalidCodePoint, (1, 7))), 0)
+    );
+    assert_eq!(json(r#""\'""#), (Err(error(InvalidEscape, (1, 3))), 0));
+    assert_eq!(json(r#""\u12""#), (Err(error(InvalidEscape, (1, 3))), 0));
+}
+
+#[test]
+fn json_rejects_raw_control_characters() {
+    use StringErrorKind::ControlCharacter;
+    assert_eq!(json("\"a\nb\""), (Err(error(ControlCharacter, (2, 3))), 0));
+    assert_eq!(json("\"a\tb\""), (Err(error(ControlCharacter, (2, 3))), 0));
+}
+
+#[test]
+fn c_escapes() {
+    assert_eq!(c(r#""\101\0\7""#).0.as_deref(), Ok("A\0\u{7}"));
+    assert_eq!(c(r#""\1012""#).0.as_deref(), Ok("A2"));
+    assert_eq!(c(r#""\x41\a\v\?""#).0.as_deref(), Ok("A\u{7}\u{b}?"));
+    assert_eq!(c(r#""é\U0001F600""#).0.as_deref(), Ok("é😀"));
+
+    use StringErrorKind::{InvalidCodePoint, InvalidEscape, Unterminated};
+    assert_eq!(c(r#""\x100""#), (Err(error(InvalidCodePoint, (1, 6))), 0));
+    assert_eq!(c(r#""\xg""#), (Err(error(InvalidEscape, (1, 3))), 0));
+    assert_eq!(c("\"a\nb\""), (Err(error(Unterminated, (0, 1))), 0));
+}
+
+#[test]
+fn missing_and_unterminated_strings() {
+    use StringErrorKind::{Missing, Unterminated};
+    assert_eq!(rust("abc"), (Err(error(Missing, (0, 0))), 0));
+    assert_eq!(rust(r#""abc"#), (Err(error(Unterminated, (0, 1))), 0));
+
+    let syntax = StringSyntax {
+        quotes: &['\''],
+        escapes: Escapes::None,
+        raw: false,
+        newlines: true,
+    };
+    let (value, offset) = run(string_literal(syntax), r"'a\' b");
+    assert!(matches!(value, Ok(Cow::Borrowed(r"a\"))));
+    assert_eq!(offset, 4);
+}
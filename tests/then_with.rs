@@ -0,0 +1,51 @@
+mod common;
+
+use common::{run, S};
+use lavan::parser::traits::ParserOnce;
+use lavan::stream::traits::Stream;
+
+const LENGTH: fn(&mut S) -> Option<usize> = |input| {
+    let length = input.peek()?.to_digit(10)?;
+    input.skip();
+    Some(length as usize)
+};
+
+const LETTER: fn(&mut S) -> Option<char> = |input| {
+    let ch = input.peek().filter(|ch| ch.is_alphabetic())?;
+    input.skip();
+    Some(ch)
+};
+
+fn body(length: &usize) -> impl ParserOnce<S, Output = Option<Vec<char>>> {
+    LETTER.repeat_exact(*length).to_vec()
+}
+
+#[test]
+fn the_length_picks_the_body() {
+    let (output, offset) = run(LENGTH.then_with(body), "3abcd");
+    assert_eq!(output, Some((3, vec!['a', 'b', 'c'])));
+    assert_eq!(offset, 4);
+
+    assert_eq!(run(LENGTH.then_with(body), "0a"), (Some((0, vec![])), 1));
+    assert_eq!(run(LENGTH.then_with(body), "x"), (None, 0));
+}
+
+#[test]
+fn repeated_frames_rebuild_the_body() {
+    let (frames, offset) = run(LENGTH.then_with(body).repeat().to_vec(), "1a2bc0;");
+    assert_eq!(
+        frames.value(),
+        [(1, vec!['a']), (2, vec!['b', 'c']), (0, vec![])]
+    );
+    assert_eq!(offset, 6);
+}
+
+#[test]
+fn failed_bodies_rewind_under_non_terminal() {
+    let (output, offset) = run(LENGTH.then_with(body), "3ab;");
+    assert_eq!(output, None);
+    assert_eq!(offset, 3);
+
+    let parser = ParserOnce::<S>::non_terminal(LENGTH.then_with(body));
+    assert_eq!(run(parser, "3ab;"), (None, 0));
+}
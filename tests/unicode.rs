@@ -0,0 +1,82 @@
+#![cfg(feature = "unicode")]
+
+mod common;
+
+use common::run;
+use lavan::parser::sources::unicode::{Categories, GeneralCategory, Script, Scripts};
+
+#[test]
+fn general_categories_of_known_code_points() {
+    use GeneralCategory::*;
+    let known = [
+        ('A', Lu),
+        ('a', Ll),
+        ('ǅ', Lt),
+        ('ʰ', Lm),
+        ('中', Lo),
+        ('\u{301}', Mn),
+        ('5', Nd),
+        ('Ⅳ', Nl),
+        ('½', No),
+        ('_', Pc),
+        ('-', Pd),
+        ('(', Ps),
+        ('+', Sm),
+        ('€', Sc),
+        ('😀', So),
+        (' ', Zs),
+        ('\u{2028}', Zl),
+        ('\n', Cc),
+        ('\u{200B}', Cf),
+        ('\u{E000}', Co),
+        ('\u{378}', Cn),
+    ];
+    for (ch, category) in known {
+        assert_eq!(GeneralCategory::of(ch), category, "{ch:?}");
+    }
+    assert_eq!(GeneralCategory::from_abbr("Lu"), Some(Lu));
+    assert_eq!(Lo.abbr(), "Lo");
+    assert_eq!(GeneralCategory::from_abbr("L"), None);
+}
+
+#[test]
+fn scripts_of_known_code_points() {
+    let known = [
+        ('a', Script::Latin),
+        ('é', Script::Latin),
+        ('λ', Script::Greek),
+        ('ж', Script::Cyrillic),
+        ('中', Script::Han),
+        ('1', Script::Common),
+        ('\u{378}', Script::Unknown),
+    ];
+    for (ch, script) in known {
+        assert_eq!(Script::of(ch), script, "{ch:?}");
+    }
+    assert_eq!(Script::from_name("Greek"), Some(Script::Greek));
+    assert_eq!(Script::Han.name(), "Han");
+    assert_eq!(Script::from_name("Klingon"), None);
+}
+
+#[test]
+fn sets_of_categories_and_scripts() {
+    assert!(Categories::LETTER.contains('中') && !Categories::LETTER.contains('5'));
+    let digits_or_signs = GeneralCategory::Nd | GeneralCategory::Sm;
+    assert!(digits_or_signs.contains('٣') && digits_or_signs.contains('+'));
+
+    let greek_or_cyrillic = Script::Greek | Script::Cyrillic;
+    assert!(greek_or_cyrillic.contains('λ') && !greek_or_cyrillic.contains('a'));
+    assert!(Scripts::EMPTY.with(Script::Latin).contains('é'));
+
+    let lowercase = GeneralCategory::Ll.char_set();
+    assert!(lowercase.contains('ß') && !lowercase.contains('A'));
+    assert!(Script::Greek.char_set().contains('Ω'));
+}
+
+#[test]
+fn categories_and_scripts_parse_characters() {
+    assert_eq!(run(GeneralCategory::Lu, "Éa"), (Some('É'), 2));
+    assert_eq!(run(GeneralCategory::Lu, "a"), (None, 0));
+    assert_eq!(run(Script::Greek, "λx"), (Some('λ'), 2));
+    assert_eq!(run(Categories::NUMBER, "Ⅳ"), (Some('Ⅳ'), 3));
+}
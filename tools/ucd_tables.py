@@ -0,0 +1,194 @@
+#!/usr/bin/env python3
+# Regenerates the Unicode tables under src/parser/sources from the
+# ucd-generate output that regex-syntax ships in src/unicode_tables.
+#
+#   python3 tools/ucd_tables.py path/to/regex-syntax-0.8.11
+#
+# regex-syntax 0.8.11 was generated with ucd-generate 0.3.1 from
+# Unicode 16.0.0. Its source can be fetched with
+#
+#   curl -L https://static.crates.io/crates/regex-syntax/regex-syntax-0.8.11.crate | tar xz
+#
+# When moving to a newer release, update the pins below and check that
+# the General_Category and Script enums in src/parser/sources/unicode.rs
+# still cover every value.
+
+import os
+import re
+import sys
+
+REGEX_SYNTAX = "0.8.11"
+UNICODE = "16.0.0"
+
+ROOT = os.path.join(os.path.dirname(os.path.abspath(__file__)), "..")
+SOURCES = os.path.join(ROOT, "src", "parser", "sources")
+
+HEADER = """\
+// Unicode {unicode} {what}.
+// Generated by tools/ucd_tables.py from regex-syntax {crate}, do not edit by hand.
+// Unicode data is subject to the Unicode License v3,
+// see https://www.unicode.org/license.txt.
+"""
+
+GENERAL_CATEGORY = {
+    "Uppercase_Letter": "Lu",
+    "Lowercase_Letter": "Ll",
+    "Titlecase_Letter": "Lt",
+    "Modifier_Letter": "Lm",
+    "Other_Letter": "Lo",
+    "Nonspacing_Mark": "Mn",
+    "Spacing_Mark": "Mc",
+    "Enclosing_Mark": "Me",
+    "Decimal_Number": "Nd",
+    "Letter_Number": "Nl",
+    "Other_Number": "No",
+    "Connector_Punctuation": "Pc",
+    "Dash_Punctuation": "Pd",
+    "Open_Punctuation": "Ps",
+    "Close_Punctuation": "Pe",
+    "Initial_Punctuation": "Pi",
+    "Final_Punctuation": "Pf",
+    "Other_Punctuation": "Po",
+    "Math_Symbol": "Sm",
+    "Currency_Symbol": "Sc",
+    "Modifier_Symbol": "Sk",
+    "Other_Symbol": "So",
+    "Space_Separator": "Zs",
+    "Line_Separator": "Zl",
+    "Paragraph_Separator": "Zp",
+    "Control": "Cc",
+    "Format": "Cf",
+    "Private_Use": "Co",
+}
+
+ESCAPES = {"\\t": "\t", "\\n": "\n", "\\r": "\r", "\\'": "'", "\\\\": "\\", "\\0": "\0"}
+
+
+def unescape(literal):
+    literal = literal[1:-1]
+    if literal.startswith("\\u{"):
+        return int(literal[3:-1], 16)
+    return ord(ESCAPES.get(literal, literal))
+
+
+# Returns the range tables of a ucd-generate file by constant name, and the
+# (value name, constant name) pairs of its BY_NAME index.
+def parse(path):
+    with open(path) as file:
+        text = file.read()
+
+    tables = {}
+    for found in re.finditer(
+        r"pub const (\w+): &'static \[\(char, char\)\] =\s*&\[(.*?)\];", text, re.S
+    ):
+        pairs = re.findall(
+            r"\(('(?:[^'\\]|\\.[^']*)'), ('(?:[^'\\]|\\.[^']*)')\)", found.group(2)
+        )
+        tables[found.group(1)] = [(unescape(a), unescape(b)) for a, b in pairs]
+
+    names = re.findall(r'\("(\w+)", (\w+)\)', text.split("];")[0])
+    return tables, names
+
+
+def char(code):
+    if code == 0x20 or (0x21 <= code < 0x7F and chr(code) not in "'\\"):
+        return "'%s'" % chr(code)
+    return "'\\u{%x}'" % code
+
+
+# Merges per-value tables into one sorted (start, end, value) table, joining
+# adjacent ranges that share a value.
+def combine(tables, values):
+    rows = sorted(
+        [start, end, value]
+        for value, constant in values
+        for start, end in tables[constant]
+    )
+
+    merged = []
+    for row in rows:
+        if merged and merged[-1][2] == row[2] and merged[-1][1] + 1 == row[0]:
+            merged[-1][1] = row[1]
+        else:
+            merged.append(row)
+    for left, right in zip(merged, merged[1:]):
+        assert left[1] < right[0], (left, right)
+    return merged
+
+
+def header(what):
+    return HEADER.format(crate=REGEX_SYNTAX, unicode=UNICODE, what=what)
+
+
+def write(path, lines):
+    with open(os.path.join(SOURCES, path), "w") as file:
+        file.write("\n".join(lines) + "\n")
+
+
+def properties(source):
+    tables, _ = parse(os.path.join(source, "property_bool.rs"))
+    lines = [header("White_Space, XID_Start, XID_Continue")]
+    for index, name in enumerate(["WHITE_SPACE", "XID_START", "XID_CONTINUE"]):
+        if index > 0:
+            lines.append("")
+        lines.append("pub(crate) const %s: &[(char, char)] = &[" % name)
+        lines += ["    (%s, %s)," % (char(a), char(b)) for a, b in tables[name]]
+        lines.append("];")
+    write("properties.rs", lines)
+
+
+def general_category(source):
+    tables, names = parse(os.path.join(source, "general_category.rs"))
+    values = [(GENERAL_CATEGORY[name], const) for name, const in names if name in GENERAL_CATEGORY]
+    assert len(values) == len(GENERAL_CATEGORY), "missing General_Category values"
+
+    lines = [
+        header("General_Category"),
+        "use crate::parser::sources::unicode::GeneralCategory::{self, *};",
+        "",
+        "pub(crate) const GENERAL_CATEGORY: &[(char, char, GeneralCategory)] = &[",
+    ]
+    lines += ["    (%s, %s, %s)," % (char(a), char(b), c) for a, b, c in combine(tables, values)]
+    lines.append("];")
+    write(os.path.join("tables", "general_category.rs"), lines)
+
+
+def script(source):
+    tables, names = parse(os.path.join(source, "script.rs"))
+    variants = [name.replace("_", "") for name, _ in names]
+    if "Unknown" not in variants:
+        names.append(("Unknown", None))
+        variants.append("Unknown")
+    values = [(name.replace("_", ""), const) for name, const in names if const]
+
+    lines = [
+        header("Script"),
+        "#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]",
+        "pub enum Script {",
+    ]
+    lines += ["    %s," % variant for variant in variants]
+    lines += ["}", "", "pub(crate) const SCRIPT_NAMES: &[(&str, Script)] = &["]
+    lines += [
+        '    ("%s", Script::%s),' % (name, name.replace("_", ""))
+        for name in sorted(name for name, _ in names)
+    ]
+    lines += ["];", "", "pub(crate) const SCRIPT: &[(char, char, Script)] = &["]
+    lines += [
+        "    (%s, %s, Script::%s)," % (char(a), char(b), c) for a, b, c in combine(tables, values)
+    ]
+    lines.append("];")
+    write(os.path.join("tables", "script.rs"), lines)
+
+
+def main():
+    if len(sys.argv) != 2:
+        sys.exit("usage: ucd_tables.py path/to/regex-syntax-%s" % REGEX_SYNTAX)
+    source = os.path.join(sys.argv[1], "src", "unicode_tables")
+
+    properties(source)
+    general_category(source)
+    script(source)
+
+
+if __name__ == "__main__":
+    main()